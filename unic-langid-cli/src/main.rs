@@ -0,0 +1,111 @@
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+const USAGE: &str = "Usage: unic-langid-cli <command> [tags...]
+
+Commands:
+    canonicalize     Print the canonical form of each tag
+    maximize         Print each tag with likely subtags added
+    minimize         Print each tag with likely subtags removed
+    match <range>    Print the tags matching the range
+    direction        Print the character direction of each tag
+
+If no tags are given, they are read from the standard input.";
+
+enum Command {
+    Canonicalize,
+    Maximize,
+    Minimize,
+    Match(LanguageIdentifier),
+    Direction,
+}
+
+impl Command {
+    fn run(&self, input: &str) -> Result<Option<String>, String> {
+        let mut langid: LanguageIdentifier = input
+            .parse()
+            .map_err(|e| format!("{}: {}", input, e))?;
+
+        let output = match self {
+            Command::Canonicalize => langid.to_string(),
+            Command::Maximize => {
+                langid.add_likely_subtags();
+                langid.to_string()
+            }
+            Command::Minimize => {
+                langid.remove_likely_subtags();
+                langid.to_string()
+            }
+            Command::Match(range) => {
+                if !range.matches(&langid, true, false) {
+                    return Ok(None);
+                }
+                langid.to_string()
+            }
+            Command::Direction => match langid.get_character_direction() {
+                CharacterDirection::LTR => String::from("ltr"),
+                CharacterDirection::RTL => String::from("rtl"),
+            },
+        };
+        Ok(Some(output))
+    }
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let command = match args.next().as_deref() {
+        Some("canonicalize") => Command::Canonicalize,
+        Some("maximize") => Command::Maximize,
+        Some("minimize") => Command::Minimize,
+        Some("match") => {
+            let range = args.next().unwrap_or_else(|| exit_with_usage());
+            match range.parse() {
+                Ok(range) => Command::Match(range),
+                Err(e) => {
+                    eprintln!("Error: {}: {}", range, e);
+                    process::exit(2);
+                }
+            }
+        }
+        Some("direction") => Command::Direction,
+        _ => exit_with_usage(),
+    };
+
+    let mut failed = false;
+    let mut handle = |input: &str| match command.run(input) {
+        Ok(Some(output)) => println!("{}", output),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            failed = true;
+        }
+    };
+
+    let tags: Vec<String> = args.collect();
+    if tags.is_empty() {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let line = line.expect("Failed to read from the standard input.");
+            for input in line.split_whitespace() {
+                handle(input);
+            }
+        }
+    } else {
+        for input in &tags {
+            handle(input);
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
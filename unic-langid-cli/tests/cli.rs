@@ -0,0 +1,67 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_unic-langid-cli"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run the binary.");
+    if let Some(input) = stdin {
+        child
+            .stdin
+            .as_mut()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], stdin: Option<&str>) -> String {
+    let output = run(args, stdin);
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn canonicalize() {
+    assert_eq!(
+        stdout(&["canonicalize", "en_us", "ZH-hant-tw"], None),
+        "en-US\nzh-Hant-TW\n"
+    );
+}
+
+#[test]
+fn likely_subtags() {
+    assert_eq!(
+        stdout(&["maximize"], Some("sr en-US\nzh-TW\n")),
+        "sr-Cyrl-RS\nen-Latn-US\nzh-Hant-TW\n"
+    );
+    assert_eq!(stdout(&["minimize", "en-Latn-US"], None), "en\n");
+}
+
+#[test]
+fn matches() {
+    assert_eq!(
+        stdout(&["match", "en", "en-US", "fr", "en-GB"], None),
+        "en-US\nen-GB\n"
+    );
+}
+
+#[test]
+fn direction() {
+    assert_eq!(stdout(&["direction", "ar", "en"], None), "rtl\nltr\n");
+}
+
+#[test]
+fn errors() {
+    let output = run(&["canonicalize", "en", "x"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "en\n");
+
+    let output = run(&["unknown"], None);
+    assert_eq!(output.status.code(), Some(2));
+}
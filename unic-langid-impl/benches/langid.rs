@@ -3,7 +3,6 @@ use criterion::criterion_main;
 use criterion::Criterion;
 use criterion::Fun;
 
-use tinystr::{TinyStr4, TinyStr8};
 use unic_langid_impl::LanguageIdentifier;
 
 static STRINGS: &[&str] = &[
@@ -67,18 +66,16 @@ fn language_identifier_construct_bench(c: &mut Criterion) {
             |b, langids: &Vec<LanguageIdentifier>| {
                 let entries = langids
                     .iter()
-                    .map(|langid| langid.clone().into_raw_parts())
+                    .map(|langid| langid.clone().into_tinystr_parts())
                     .collect::<Vec<_>>();
                 b.iter(|| {
                     for (language, script, region, variants) in &entries {
                         let _ = unsafe {
                             LanguageIdentifier::from_raw_parts_unchecked(
-                                language.map(|l| TinyStr8::new_unchecked(l)),
-                                script.map(|s| TinyStr4::new_unchecked(s)),
-                                region.map(|r| TinyStr4::new_unchecked(r)),
-                                variants.as_ref().map(|v| {
-                                    v.into_iter().map(|v| TinyStr8::new_unchecked(*v)).collect()
-                                }),
+                                *language,
+                                *script,
+                                *region,
+                                variants.clone(),
                             )
                         };
                     }
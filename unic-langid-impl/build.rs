@@ -0,0 +1,62 @@
+//! Optional regeneration of the embedded CLDR data tables.
+//!
+//! By default the crate uses the data tables bundled in `src/`.
+//!
+//! If the `cldr-build` feature is enabled and the `UNIC_LANGID_CLDR_PATH`
+//! environment variable points to a local checkout of the CLDR JSON data
+//! (with `cldr-core` and `cldr-misc-modern` packages), the tables are
+//! regenerated from it during the build instead, using the same generators
+//! as the `generate_*` binaries.
+
+#[cfg(feature = "cldr-build")]
+#[path = "src/bin/cldr/mod.rs"]
+mod cldr;
+
+use std::env;
+
+const CLDR_PATH_VAR: &str = "UNIC_LANGID_CLDR_PATH";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed={}", CLDR_PATH_VAR);
+    println!("cargo:rustc-check-cfg=cfg(unic_langid_cldr_build)");
+
+    if let Some(cldr_path) = env::var_os(CLDR_PATH_VAR) {
+        regenerate(cldr_path.into());
+    }
+}
+
+#[cfg(feature = "cldr-build")]
+fn regenerate(cldr_path: std::path::PathBuf) {
+    use std::fs;
+    use std::path::PathBuf;
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("Missing OUT_DIR"));
+
+    let likelysubtags_path = cldr_path.join("cldr-core/supplemental/likelySubtags.json");
+    let layout_path = cldr_path.join("cldr-misc-modern/main");
+    println!("cargo:rerun-if-changed={}", likelysubtags_path.display());
+    println!("cargo:rerun-if-changed={}", layout_path.display());
+
+    let likelysubtags = fs::read_to_string(&likelysubtags_path)
+        .expect("Something went wrong reading the file");
+    fs::write(
+        out_dir.join("likelysubtags_tables.rs"),
+        cldr::generate_likelysubtags(&likelysubtags),
+    )
+    .expect("Something went wrong writing the file");
+    fs::write(
+        out_dir.join("layout_table.rs"),
+        cldr::generate_layout(&layout_path),
+    )
+    .expect("Something went wrong writing the file");
+
+    println!("cargo:rustc-cfg=unic_langid_cldr_build");
+}
+
+#[cfg(not(feature = "cldr-build"))]
+fn regenerate(_: std::path::PathBuf) {
+    println!(
+        "cargo:warning={} is ignored without the `cldr-build` feature",
+        CLDR_PATH_VAR
+    );
+}
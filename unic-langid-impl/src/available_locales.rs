@@ -0,0 +1,40 @@
+use crate::available_locales_table;
+use crate::LanguageIdentifier;
+
+/// Level of CLDR data coverage a locale is expected to have.
+///
+/// At the moment only the list of locales with modern coverage is embedded in the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CldrCoverage {
+    /// Locales with data suitable for all modern software.
+    Modern,
+}
+
+impl CldrCoverage {
+    pub(crate) fn locales(self) -> &'static [&'static str] {
+        match self {
+            CldrCoverage::Modern => &available_locales_table::MODERN,
+        }
+    }
+}
+
+/// Returns an iterator over the locales available in CLDR with the given coverage.
+///
+/// The CLDR `root` locale is not included.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{available_locales, CldrCoverage};
+///
+/// let mut locales = available_locales(CldrCoverage::Modern);
+///
+/// assert!(locales.any(|l| l.to_string() == "ca-ES-valencia"));
+/// ```
+pub fn available_locales(coverage: CldrCoverage) -> impl Iterator<Item = LanguageIdentifier> {
+    coverage
+        .locales()
+        .iter()
+        .map(|l| l.parse().expect("Invalid CLDR locale."))
+}
@@ -0,0 +1,180 @@
+//! Generators of the embedded data tables from CLDR JSON data.
+//!
+//! Shared between the `generate_*` binaries and the build script, so it
+//! only depends on `serde_json`.
+
+use serde_json::Value;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+type Subtags<'a> = (Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+/// Encodes a subtag the same way `TinyStr` stores it.
+fn encode(subtag: &str) -> u64 {
+    subtag
+        .bytes()
+        .rev()
+        .fold(0, |acc, b| (acc << 8) | u64::from(b))
+}
+
+fn split_tag(tag: &str) -> Subtags<'_> {
+    let mut subtags = tag.split(['-', '_']);
+    let lang = subtags.next().filter(|l| *l != "und" && *l != "root");
+    let mut script = None;
+    let mut region = None;
+    for subtag in subtags {
+        if subtag.len() == 4 {
+            script = Some(subtag);
+        } else {
+            region = Some(subtag);
+        }
+    }
+    (lang, script, region)
+}
+
+fn serialize_option(subtag: Option<&str>) -> String {
+    subtag
+        .map(|s| format!("Some({})", encode(s)))
+        .unwrap_or_else(|| String::from("None"))
+}
+
+fn write_table(
+    out: &mut String,
+    name: &str,
+    key_types: &str,
+    by_ref: bool,
+    mut rows: Vec<(Vec<u64>, String)>,
+) {
+    rows.sort();
+    let amp = if by_ref { "&" } else { "" };
+    writeln!(
+        out,
+        "pub const {}: {}[({}, (Option<u64>, Option<u32>, Option<u32>)); {}] = {}[",
+        name,
+        amp,
+        key_types,
+        rows.len(),
+        amp
+    )
+    .unwrap();
+    for (keys, value) in rows {
+        let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        writeln!(out, "    ({}, {}),", keys.join(", "), value).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Generates the likely subtags tables from the contents of `likelySubtags.json`.
+pub fn generate_likelysubtags(source: &str) -> String {
+    let v: Value = serde_json::from_str(source).unwrap();
+    let values = v["supplemental"]["likelySubtags"].as_object().unwrap();
+
+    let mut lang_only = vec![];
+    let mut lang_region = vec![];
+    let mut lang_script = vec![];
+    let mut script_region = vec![];
+    let mut script_only = vec![];
+    let mut region_only = vec![];
+
+    for (key, value) in values {
+        let (val_lang, val_script, val_region) = split_tag(value.as_str().unwrap());
+        let value = format!(
+            "({}, {}, {})",
+            serialize_option(val_lang),
+            serialize_option(val_script),
+            serialize_option(val_region.filter(|r| *r != "ZZ"))
+        );
+
+        match split_tag(key) {
+            (Some(l), None, None) => lang_only.push((vec![encode(l)], value)),
+            (Some(l), None, Some(r)) => lang_region.push((vec![encode(l), encode(r)], value)),
+            (Some(l), Some(s), None) => lang_script.push((vec![encode(l), encode(s)], value)),
+            (None, Some(s), Some(r)) => script_region.push((vec![encode(s), encode(r)], value)),
+            (None, Some(s), None) => script_only.push((vec![encode(s)], value)),
+            (None, None, Some(r)) => region_only.push((vec![encode(r)], value)),
+            (None, None, None) => {}
+            _ => panic!("Unexpected key: {}", key),
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "pub const CLDR_VERSION: &str = \"{}\";",
+        v["supplemental"]["version"]["_cldrVersion"]
+            .as_str()
+            .unwrap()
+    )
+    .unwrap();
+    write_table(&mut out, "LANG_ONLY", "u64", true, lang_only);
+    write_table(&mut out, "LANG_REGION", "u64, u32", false, lang_region);
+    write_table(&mut out, "LANG_SCRIPT", "u64, u32", false, lang_script);
+    write_table(&mut out, "SCRIPT_REGION", "u32, u32", false, script_region);
+    write_table(&mut out, "SCRIPT_ONLY", "u32", false, script_only);
+    write_table(&mut out, "REGION_ONLY", "u32", false, region_only);
+    out
+}
+
+/// Generates the layout table from the `main` directory of `cldr-misc-modern`.
+pub fn generate_layout(path: &Path) -> String {
+    let mut version = None;
+    let mut rtl = vec![];
+    let mut ltr = vec![];
+
+    for entry in fs::read_dir(path).expect("Something went wrong reading the directory") {
+        let entry = entry.unwrap();
+        let contents = fs::read_to_string(entry.path().join("layout.json"))
+            .expect("Something went wrong reading the file");
+        let v: Value = serde_json::from_str(&contents).unwrap();
+
+        let locale = v["main"].as_object().unwrap().keys().next().unwrap();
+        if locale == "root" {
+            version = v["main"]["root"]["identity"]["version"]["_cldrVersion"]
+                .as_str()
+                .map(String::from);
+            continue;
+        }
+
+        if let (Some(lang), _, _) = split_tag(locale) {
+            let lang = encode(lang);
+            let list = match v["main"][locale]["layout"]["orientation"]["characterOrder"]
+                .as_str()
+                .unwrap()
+            {
+                "right-to-left" => &mut rtl,
+                "left-to-right" => &mut ltr,
+                _ => unimplemented!("Encountered unknown directionality!"),
+            };
+            if !list.contains(&lang) {
+                list.push(lang);
+            }
+        }
+    }
+    assert!(
+        rtl.iter().all(|lang| !ltr.contains(lang)),
+        "We didn't expect a language with two directionalities!"
+    );
+    // Sorted, so that the table can be binary searched.
+    ltr.sort_unstable();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "pub const CLDR_VERSION: &str = \"{}\";",
+        version.expect("Missing root locale")
+    )
+    .unwrap();
+    for (name, list) in &[("RTL", rtl), ("LTR", ltr)] {
+        let values: Vec<String> = list.iter().map(|l| l.to_string()).collect();
+        writeln!(
+            out,
+            "pub const CHARACTER_DIRECTION_{}: [u64; {}] = [{}];",
+            name,
+            list.len(),
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    out
+}
@@ -0,0 +1,44 @@
+use serde_json::Value;
+use std::fs;
+use unic_langid_impl::LanguageIdentifier;
+
+fn get_available_locales(path: &str) -> Vec<String> {
+    let mut result = vec![];
+    for entry in fs::read_dir(path).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name == "root" {
+            continue;
+        }
+        let langid: LanguageIdentifier = name.parse().unwrap();
+        result.push(langid.to_string());
+    }
+    result.sort();
+    result
+}
+
+fn get_cldr_version(path: &str) -> String {
+    let contents = fs::read_to_string(format!("{}/root/layout.json", path))
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    v["main"]["root"]["identity"]["version"]["_cldrVersion"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn main() {
+    let path = "./data/cldr-misc-modern/main/";
+    let locales = get_available_locales(path);
+
+    println!(
+        "pub const CLDR_VERSION: &str = \"{}\";",
+        get_cldr_version(path)
+    );
+    println!("pub const MODERN: [&str; {}] = [", locales.len());
+    for locale in locales {
+        println!("    \"{}\",", locale);
+    }
+    println!("];");
+}
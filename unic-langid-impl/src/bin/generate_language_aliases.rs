@@ -0,0 +1,51 @@
+use serde_json::Value;
+use std::fs;
+
+/// Alias reasons which only change the form of the language code, such as
+/// the ISO 639-2/T `deu` and the ISO 639-2/B `ger`, both replaced with `de`.
+const REASONS: [&str; 3] = ["overlong", "bibliographic", "legacy"];
+
+fn is_language_subtag(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+fn main() {
+    let contents =
+        fs::read_to_string("./data/aliases.json").expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let aliases = v["supplemental"]["metadata"]["alias"]["languageAlias"]
+        .as_object()
+        .unwrap();
+
+    // Aliases replaced with more than a language subtag, such as `sh` with
+    // `sr-Latn`, are left out.
+    let mut result: Vec<(&str, &str)> = aliases
+        .iter()
+        .filter(|(_, alias)| REASONS.contains(&alias["_reason"].as_str().unwrap()))
+        .map(|(code, alias)| (code.as_str(), alias["_replacement"].as_str().unwrap()))
+        .filter(|(code, replacement)| is_language_subtag(code) && is_language_subtag(replacement))
+        .collect();
+    result.sort_unstable();
+
+    println!(
+        "//! Language code aliases, based on the CLDR `languageAlias` data of `supplemental/aliases.json`.\n"
+    );
+    println!("/// Version of CLDR the table is based on.");
+    println!(
+        "pub const CLDR_VERSION: &str = \"{}\";\n",
+        v["supplemental"]["version"]["_cldrVersion"]
+            .as_str()
+            .unwrap()
+    );
+    println!(
+        "/// Overlong, bibliographic and legacy language codes with their replacements, sorted."
+    );
+    println!(
+        "pub const LANGUAGE_ALIASES: [(&str, &str); {}] = [",
+        result.len()
+    );
+    for (code, replacement) in result {
+        println!("    (\"{}\", \"{}\"),", code, replacement);
+    }
+    println!("];");
+}
@@ -0,0 +1,9 @@
+use std::fs;
+use unic_langid_impl::likelysubtags::runtime::LikelySubtagsData;
+
+fn main() {
+    let data = LikelySubtagsData::from_path("./data/likelySubtags.json")
+        .expect("Something went wrong reading the file");
+    fs::write("./data/likelySubtags.bin", data.to_bytes())
+        .expect("Something went wrong writing the file");
+}
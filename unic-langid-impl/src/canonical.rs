@@ -0,0 +1,91 @@
+use crate::language::canonicalize_language_subtag;
+use crate::script::canonicalize_script_subtag;
+use crate::{LanguageIdentifier, LanguageIdentifierError};
+use std::ops::Deref;
+
+/// A `LanguageIdentifier` which is guaranteed to be in its canonical form.
+///
+/// It can only be constructed through canonicalization, which allows APIs to
+/// require canonical identifiers in their signatures.
+///
+/// Since both values are canonical, comparing two `CanonicalLanguageIdentifier`s
+/// is a plain comparison of their subtags.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::CanonicalLanguageIdentifier;
+///
+/// let li = CanonicalLanguageIdentifier::canonicalize("pL_latn_pl")
+///     .expect("Canonicalization failed.");
+///
+/// assert_eq!(li.get_language(), "pl");
+/// assert_eq!(li.to_string(), "pl-Latn-PL");
+///
+/// let li = CanonicalLanguageIdentifier::canonicalize("deu-AT")
+///     .expect("Canonicalization failed.");
+///
+/// assert_eq!(li.to_string(), "de-AT");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct CanonicalLanguageIdentifier(LanguageIdentifier);
+
+impl CanonicalLanguageIdentifier {
+    /// Parses and canonicalizes the input.
+    pub fn canonicalize(input: &str) -> Result<Self, LanguageIdentifierError> {
+        let langid: LanguageIdentifier = input.parse()?;
+        Ok(langid.into())
+    }
+
+    /// Returns the inner `LanguageIdentifier`.
+    pub fn into_inner(self) -> LanguageIdentifier {
+        self.0
+    }
+}
+
+impl From<LanguageIdentifier> for CanonicalLanguageIdentifier {
+    /// Canonicalizes the `LanguageIdentifier`.
+    fn from(langid: LanguageIdentifier) -> Self {
+        let LanguageIdentifier {
+            language,
+            script,
+            region,
+            variants,
+        } = langid;
+        let language = language.map(canonicalize_language_subtag);
+        let script = script.map(canonicalize_script_subtag);
+        // `from_raw_parts_unchecked` allows unsorted variants.
+        let variants = variants.map(|variants| {
+            let mut variants = variants.into_vec();
+            variants.sort();
+            variants.dedup();
+            variants.into_boxed_slice()
+        });
+        Self(LanguageIdentifier {
+            language,
+            script,
+            region,
+            variants,
+        })
+    }
+}
+
+impl Deref for CanonicalLanguageIdentifier {
+    type Target = LanguageIdentifier;
+
+    fn deref(&self) -> &LanguageIdentifier {
+        &self.0
+    }
+}
+
+impl AsRef<LanguageIdentifier> for CanonicalLanguageIdentifier {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for CanonicalLanguageIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
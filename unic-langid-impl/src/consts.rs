@@ -0,0 +1,198 @@
+//! Well-known `LanguageIdentifier` constants.
+//!
+//! The constants cover the locales with modern coverage in CLDR, together with
+//! the most commonly used regional and script variants. Unlike parsed identifiers,
+//! they can be used in `const` and `static` contexts.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::consts::{EN_US, ZH_HANS_CN};
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! static FALLBACK: LanguageIdentifier = EN_US;
+//!
+//! assert_eq!(FALLBACK.to_string(), "en-US");
+//! assert_eq!(ZH_HANS_CN.get_script(), Some("Hans"));
+//!
+//! let li: LanguageIdentifier = "zh-Hans-CN".parse()
+//!     .expect("Parsing failed.");
+//!
+//! match li {
+//!     ref li if *li == ZH_HANS_CN => {}
+//!     _ => unreachable!(),
+//! }
+//! ```
+use crate::LanguageIdentifier;
+use tinystr::{TinyStr4, TinyStr8};
+
+const fn tinystr4(subtag: &str) -> TinyStr4 {
+    let bytes = subtag.as_bytes();
+    let mut value = 0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        value |= (bytes[i] as u32) << (i * 8);
+        i += 1;
+    }
+    unsafe { TinyStr4::new_unchecked(value) }
+}
+
+const fn tinystr8(subtag: &str) -> TinyStr8 {
+    let bytes = subtag.as_bytes();
+    let mut value = 0u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        value |= (bytes[i] as u64) << (i * 8);
+        i += 1;
+    }
+    unsafe { TinyStr8::new_unchecked(value) }
+}
+
+macro_rules! langid_consts {
+    ($($name:ident = $tag:literal: $lang:literal, $script:expr, $region:expr;)*) => {
+        $(
+            #[doc = concat!("`", $tag, "`")]
+            pub const $name: LanguageIdentifier = unsafe {
+                LanguageIdentifier::from_raw_parts_unchecked(
+                    Some(tinystr8($lang)),
+                    match $script {
+                        Some(script) => Some(tinystr4(script)),
+                        None => None,
+                    },
+                    match $region {
+                        Some(region) => Some(tinystr4(region)),
+                        None => None,
+                    },
+                    None,
+                )
+            };
+        )*
+
+        /// All constants defined in this module.
+        pub const ALL: &[LanguageIdentifier] = &[$($name),*];
+    };
+}
+
+langid_consts! {
+    AF = "af": "af", None, None;
+    AM = "am": "am", None, None;
+    AR = "ar": "ar", None, None;
+    AR_EG = "ar-EG": "ar", None, Some("EG");
+    AR_SA = "ar-SA": "ar", None, Some("SA");
+    AS = "as": "as", None, None;
+    AZ = "az": "az", None, None;
+    BE = "be": "be", None, None;
+    BG = "bg": "bg", None, None;
+    BN = "bn": "bn", None, None;
+    BS = "bs": "bs", None, None;
+    CA = "ca": "ca", None, None;
+    CS = "cs": "cs", None, None;
+    CY = "cy": "cy", None, None;
+    DA = "da": "da", None, None;
+    DE = "de": "de", None, None;
+    DE_AT = "de-AT": "de", None, Some("AT");
+    DE_CH = "de-CH": "de", None, Some("CH");
+    DE_DE = "de-DE": "de", None, Some("DE");
+    EL = "el": "el", None, None;
+    EN = "en": "en", None, None;
+    EN_AU = "en-AU": "en", None, Some("AU");
+    EN_CA = "en-CA": "en", None, Some("CA");
+    EN_GB = "en-GB": "en", None, Some("GB");
+    EN_IE = "en-IE": "en", None, Some("IE");
+    EN_IN = "en-IN": "en", None, Some("IN");
+    EN_NZ = "en-NZ": "en", None, Some("NZ");
+    EN_US = "en-US": "en", None, Some("US");
+    EN_ZA = "en-ZA": "en", None, Some("ZA");
+    ES = "es": "es", None, None;
+    ES_419 = "es-419": "es", None, Some("419");
+    ES_AR = "es-AR": "es", None, Some("AR");
+    ES_ES = "es-ES": "es", None, Some("ES");
+    ES_MX = "es-MX": "es", None, Some("MX");
+    ES_US = "es-US": "es", None, Some("US");
+    ET = "et": "et", None, None;
+    EU = "eu": "eu", None, None;
+    FA = "fa": "fa", None, None;
+    FI = "fi": "fi", None, None;
+    FIL = "fil": "fil", None, None;
+    FR = "fr": "fr", None, None;
+    FR_BE = "fr-BE": "fr", None, Some("BE");
+    FR_CA = "fr-CA": "fr", None, Some("CA");
+    FR_CH = "fr-CH": "fr", None, Some("CH");
+    FR_FR = "fr-FR": "fr", None, Some("FR");
+    GA = "ga": "ga", None, None;
+    GL = "gl": "gl", None, None;
+    GU = "gu": "gu", None, None;
+    HE = "he": "he", None, None;
+    HI = "hi": "hi", None, None;
+    HR = "hr": "hr", None, None;
+    HU = "hu": "hu", None, None;
+    HY = "hy": "hy", None, None;
+    ID = "id": "id", None, None;
+    IS = "is": "is", None, None;
+    IT = "it": "it", None, None;
+    IT_CH = "it-CH": "it", None, Some("CH");
+    IT_IT = "it-IT": "it", None, Some("IT");
+    JA = "ja": "ja", None, None;
+    JA_JP = "ja-JP": "ja", None, Some("JP");
+    KA = "ka": "ka", None, None;
+    KK = "kk": "kk", None, None;
+    KM = "km": "km", None, None;
+    KN = "kn": "kn", None, None;
+    KO = "ko": "ko", None, None;
+    KO_KR = "ko-KR": "ko", None, Some("KR");
+    KY = "ky": "ky", None, None;
+    LO = "lo": "lo", None, None;
+    LT = "lt": "lt", None, None;
+    LV = "lv": "lv", None, None;
+    MK = "mk": "mk", None, None;
+    ML = "ml": "ml", None, None;
+    MN = "mn": "mn", None, None;
+    MR = "mr": "mr", None, None;
+    MS = "ms": "ms", None, None;
+    MY = "my": "my", None, None;
+    NB = "nb": "nb", None, None;
+    NE = "ne": "ne", None, None;
+    NL = "nl": "nl", None, None;
+    NL_BE = "nl-BE": "nl", None, Some("BE");
+    NL_NL = "nl-NL": "nl", None, Some("NL");
+    OR = "or": "or", None, None;
+    PA = "pa": "pa", None, None;
+    PL = "pl": "pl", None, None;
+    PS = "ps": "ps", None, None;
+    PT = "pt": "pt", None, None;
+    PT_BR = "pt-BR": "pt", None, Some("BR");
+    PT_PT = "pt-PT": "pt", None, Some("PT");
+    RO = "ro": "ro", None, None;
+    RU = "ru": "ru", None, None;
+    RU_RU = "ru-RU": "ru", None, Some("RU");
+    SD = "sd": "sd", None, None;
+    SI = "si": "si", None, None;
+    SK = "sk": "sk", None, None;
+    SL = "sl": "sl", None, None;
+    SO = "so": "so", None, None;
+    SQ = "sq": "sq", None, None;
+    SR = "sr": "sr", None, None;
+    SR_CYRL = "sr-Cyrl": "sr", Some("Cyrl"), None;
+    SR_LATN = "sr-Latn": "sr", Some("Latn"), None;
+    SV = "sv": "sv", None, None;
+    SV_SE = "sv-SE": "sv", None, Some("SE");
+    SW = "sw": "sw", None, None;
+    TA = "ta": "ta", None, None;
+    TE = "te": "te", None, None;
+    TH = "th": "th", None, None;
+    TK = "tk": "tk", None, None;
+    TR = "tr": "tr", None, None;
+    UK = "uk": "uk", None, None;
+    UR = "ur": "ur", None, None;
+    UZ = "uz": "uz", None, None;
+    VI = "vi": "vi", None, None;
+    YUE = "yue": "yue", None, None;
+    ZH = "zh": "zh", None, None;
+    ZH_HANS = "zh-Hans": "zh", Some("Hans"), None;
+    ZH_HANS_CN = "zh-Hans-CN": "zh", Some("Hans"), Some("CN");
+    ZH_HANS_SG = "zh-Hans-SG": "zh", Some("Hans"), Some("SG");
+    ZH_HANT = "zh-Hant": "zh", Some("Hant"), None;
+    ZH_HANT_HK = "zh-Hant-HK": "zh", Some("Hant"), Some("HK");
+    ZH_HANT_TW = "zh-Hant-TW": "zh", Some("Hant"), Some("TW");
+    ZU = "zu": "zu", None, None;
+}
@@ -0,0 +1,161 @@
+use crate::parser::ParserError;
+use crate::{is_option_empty, LanguageIdentifier, LanguageIdentifierError};
+use std::convert::TryFrom;
+use std::str::FromStr;
+use tinystr::{TinyStr4, TinyStr8};
+
+/// A `LanguageIdentifier` without variants, consisting of the language,
+/// script and region subtags only.
+///
+/// Unlike `LanguageIdentifier`, it is `Copy` and takes 16 bytes, which makes
+/// it a cheap key for routing and negotiation code which never looks at
+/// the variants.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{CoreLanguageIdentifier, LanguageIdentifier};
+///
+/// let core: CoreLanguageIdentifier = "sr-Cyrl-RS".parse()
+///     .expect("Parsing failed.");
+/// let copy = core;
+///
+/// assert_eq!(core.get_script(), Some("Cyrl"));
+/// assert_eq!(copy.to_string(), "sr-Cyrl-RS");
+///
+/// let li: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(li.to_core(), core);
+/// assert_eq!(LanguageIdentifier::from(core).to_string(), "sr-Cyrl-RS");
+/// ```
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct CoreLanguageIdentifier {
+    language: Option<TinyStr8>,
+    script: Option<TinyStr4>,
+    region: Option<TinyStr4>,
+}
+
+impl CoreLanguageIdentifier {
+    /// A `CoreLanguageIdentifier` with all subtags empty, serialized as `und`.
+    pub const UND: Self = Self {
+        language: None,
+        script: None,
+        region: None,
+    };
+
+    /// A constructor which takes optional subtags as `&str`, parses them and
+    /// produces a well-formed `CoreLanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::CoreLanguageIdentifier;
+    ///
+    /// let core = CoreLanguageIdentifier::from_parts(Some("fr"), None, Some("CA"))
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(core.to_string(), "fr-CA");
+    /// ```
+    pub fn from_parts<S: AsRef<str>>(
+        language: Option<S>,
+        script: Option<S>,
+        region: Option<S>,
+    ) -> Result<Self, LanguageIdentifierError> {
+        LanguageIdentifier::from_parts(language, script, region, &[]).map(|langid| langid.to_core())
+    }
+
+    pub fn get_language(&self) -> &str {
+        self.language.as_deref().unwrap_or("und")
+    }
+
+    pub fn get_script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    pub fn get_region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// Compares two `CoreLanguageIdentifier`s, allowing for either side to
+    /// use the missing subtags as wildcards.
+    ///
+    /// See `LanguageIdentifier::matches` for details.
+    pub fn matches(&self, other: &Self, self_as_range: bool, other_as_range: bool) -> bool {
+        LanguageIdentifier::from(*self).matches(
+            &LanguageIdentifier::from(*other),
+            self_as_range,
+            other_as_range,
+        )
+    }
+}
+
+impl LanguageIdentifier {
+    /// Returns the language, script and region of the `LanguageIdentifier`
+    /// as a `CoreLanguageIdentifier`, dropping the variants.
+    ///
+    /// Use `CoreLanguageIdentifier::try_from` to reject identifiers with variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "de-CH-1996".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_core().to_string(), "de-CH");
+    /// ```
+    pub fn to_core(&self) -> CoreLanguageIdentifier {
+        CoreLanguageIdentifier {
+            language: self.language,
+            script: self.script,
+            region: self.region,
+        }
+    }
+}
+
+impl From<CoreLanguageIdentifier> for LanguageIdentifier {
+    fn from(core: CoreLanguageIdentifier) -> Self {
+        LanguageIdentifier {
+            language: core.language,
+            script: core.script,
+            region: core.region,
+            variants: None,
+        }
+    }
+}
+
+impl TryFrom<&LanguageIdentifier> for CoreLanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    /// Fails with `ParserError::InvalidSubtag` if the identifier has variants.
+    fn try_from(langid: &LanguageIdentifier) -> Result<Self, Self::Error> {
+        if !is_option_empty(&langid.variants) {
+            return Err(ParserError::InvalidSubtag.into());
+        }
+        Ok(langid.to_core())
+    }
+}
+
+impl TryFrom<LanguageIdentifier> for CoreLanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(langid: LanguageIdentifier) -> Result<Self, Self::Error> {
+        CoreLanguageIdentifier::try_from(&langid)
+    }
+}
+
+impl FromStr for CoreLanguageIdentifier {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let langid: LanguageIdentifier = source.parse()?;
+        CoreLanguageIdentifier::try_from(&langid)
+    }
+}
+
+impl std::fmt::Display for CoreLanguageIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        LanguageIdentifier::from(*self).fmt(f)
+    }
+}
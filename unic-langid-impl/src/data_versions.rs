@@ -0,0 +1,55 @@
+#[cfg(feature = "available-locales")]
+use crate::available_locales_table;
+use crate::language_alias_table;
+#[cfg(feature = "layout")]
+use crate::layout_table;
+#[cfg(feature = "likelysubtags-add")]
+use crate::likelysubtags;
+
+/// Versions of the CLDR data embedded in the crate.
+///
+/// Each field reports the CLDR version of a single data table.
+/// Tables which are behind a disabled feature are reported as `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataVersions {
+    /// Version of the layout data backing `get_character_direction`,
+    /// if the `layout` feature is enabled.
+    pub layout: Option<&'static str>,
+    /// Version of the likely subtags data, if the `likelysubtags-add` feature is enabled.
+    pub likelysubtags: Option<&'static str>,
+    /// Version of the available locales list, if the `available-locales` feature is enabled.
+    pub available_locales: Option<&'static str>,
+    /// Version of the language code aliases used by canonicalization.
+    pub language_aliases: &'static str,
+}
+
+/// Returns the CLDR versions of all data tables embedded in the crate.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::data_versions;
+///
+/// let versions = data_versions();
+///
+/// if let Some(version) = versions.layout {
+///     println!("Layout data from CLDR {}", version);
+/// }
+/// ```
+pub fn data_versions() -> DataVersions {
+    DataVersions {
+        #[cfg(feature = "layout")]
+        layout: Some(layout_table::CLDR_VERSION),
+        #[cfg(not(feature = "layout"))]
+        layout: None,
+        #[cfg(feature = "likelysubtags-add")]
+        likelysubtags: Some(likelysubtags::CLDR_VERSION),
+        #[cfg(not(feature = "likelysubtags-add"))]
+        likelysubtags: None,
+        #[cfg(feature = "available-locales")]
+        available_locales: Some(available_locales_table::CLDR_VERSION),
+        #[cfg(not(feature = "available-locales"))]
+        available_locales: None,
+        language_aliases: language_alias_table::CLDR_VERSION,
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// A report of the subtags which differ between two `LanguageIdentifier`s.
+///
+/// Each field is `None` if the given subtag is the same on both sides, or holds
+/// the pair of differing values, where `None` stands for a missing subtag.
+///
+/// The `Display` implementation produces a human readable description
+/// such as `script mismatch: Latn vs Cyrl`.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct SubtagDiff<'a> {
+    pub language: Option<(&'a str, &'a str)>,
+    pub script: Option<(Option<&'a str>, Option<&'a str>)>,
+    pub region: Option<(Option<&'a str>, Option<&'a str>)>,
+    pub variants: Option<(Vec<&'a str>, Vec<&'a str>)>,
+}
+
+impl<'a> SubtagDiff<'a> {
+    /// Returns `true` if no subtags differ.
+    pub fn is_empty(&self) -> bool {
+        self.language.is_none()
+            && self.script.is_none()
+            && self.region.is_none()
+            && self.variants.is_none()
+    }
+}
+
+fn fmt_subtag(subtag: Option<&str>) -> &str {
+    subtag.unwrap_or("none")
+}
+
+fn fmt_variants(variants: &[&str]) -> String {
+    if variants.is_empty() {
+        String::from("none")
+    } else {
+        variants.join("-")
+    }
+}
+
+impl<'a> fmt::Display for SubtagDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut mismatches = vec![];
+        if let Some((a, b)) = self.language {
+            mismatches.push(format!("language mismatch: {} vs {}", a, b));
+        }
+        if let Some((a, b)) = self.script {
+            mismatches.push(format!(
+                "script mismatch: {} vs {}",
+                fmt_subtag(a),
+                fmt_subtag(b)
+            ));
+        }
+        if let Some((a, b)) = self.region {
+            mismatches.push(format!(
+                "region mismatch: {} vs {}",
+                fmt_subtag(a),
+                fmt_subtag(b)
+            ));
+        }
+        if let Some((a, b)) = &self.variants {
+            mismatches.push(format!(
+                "variants mismatch: {} vs {}",
+                fmt_variants(a),
+                fmt_variants(b)
+            ));
+        }
+
+        if mismatches.is_empty() {
+            f.write_str("no mismatch")
+        } else {
+            f.write_str(&mismatches.join(", "))
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::language::get_language_codes;
+use crate::script::get_static_script;
+use crate::subtags;
+use crate::LanguageIdentifier;
+use std::ops::Range;
+
+/// Returns the byte ranges of the ASCII alphanumeric runs of the input.
+fn tokens(input: &str) -> Vec<Range<usize>> {
+    let mut result = vec![];
+    let mut start = None;
+    for (idx, b) in input.bytes().enumerate() {
+        match (b.is_ascii_alphanumeric(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(s)) => {
+                result.push(s..idx);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        result.push(s..input.len());
+    }
+    result
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtags::parse_script_subtag(subtag)
+        .ok()
+        .and_then(get_static_script)
+        .is_some()
+}
+
+fn is_variant(subtag: &str) -> bool {
+    subtag.as_bytes()[0].is_ascii_digit() && subtags::parse_variant_subtag(subtag).is_ok()
+}
+
+/// A language without any other subtags is only accepted if it is an ISO 639-1 code
+/// which is not the file extension of the input.
+fn is_bare_language(input: &str, token: &Range<usize>) -> bool {
+    let language = input[token.clone()].to_ascii_lowercase();
+    let is_extension = token.end == input.len() && input[..token.start].ends_with('.');
+    language.len() == 2 && get_language_codes(&language).is_some() && !is_extension
+}
+
+/// Finds a language identifier embedded in an arbitrary string, such as a file name.
+///
+/// Returns the identifier together with the byte range of the input it was found at.
+///
+/// This is a heuristic. Subtags have to be separated by `-` or `_`, and the identifier
+/// has to be delimited from the rest of the input by other non-alphanumeric characters.
+/// The language has to be a two or three letter code, and a language without a script
+/// or region has to be a known ISO 639-1 code. Only scripts known to ISO 15924 and variants
+/// starting with a digit are recognized, since longer alphabetic variants are
+/// indistinguishable from ordinary words.
+///
+/// If there is more than one candidate, the one with the most subtags wins,
+/// and among those the last one.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::extract;
+///
+/// let (li, range) = extract("messages_en_US.properties")
+///     .expect("No identifier found.");
+/// assert_eq!(li.to_string(), "en-US");
+/// assert_eq!(range, 9..14);
+///
+/// let (li, _) = extract("strings.fr-CA.json")
+///     .expect("No identifier found.");
+/// assert_eq!(li.to_string(), "fr-CA");
+///
+/// assert_eq!(extract("README.md"), None);
+/// ```
+pub fn extract(input: &str) -> Option<(LanguageIdentifier, Range<usize>)> {
+    let tokens = tokens(input);
+    let bytes = input.as_bytes();
+    let mut best: Option<(usize, Range<usize>)> = None;
+
+    for (idx, token) in tokens.iter().enumerate() {
+        let language = &input[token.clone()];
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            continue;
+        }
+
+        let mut end = token.end;
+        let mut count = 1;
+        let mut position = 1;
+        for next in &tokens[idx + 1..] {
+            if next.start != end + 1 || !matches!(bytes[end], b'-' | b'_') {
+                break;
+            }
+            let subtag = &input[next.clone()];
+            if position < 2 && is_script(subtag) {
+                position = 2;
+            } else if position < 3 && subtags::parse_region_subtag(subtag).is_ok() {
+                position = 3;
+            } else if is_variant(subtag) {
+                position = 4;
+            } else {
+                break;
+            }
+            end = next.end;
+            count += 1;
+        }
+
+        if count == 1 && !is_bare_language(input, token) {
+            continue;
+        }
+        let is_better = match &best {
+            Some((score, _)) => count >= *score,
+            None => true,
+        };
+        if is_better {
+            best = Some((count, token.start..end));
+        }
+    }
+
+    let (_, range) = best?;
+    let langid = input[range.clone()].parse().ok()?;
+    Some((langid, range))
+}
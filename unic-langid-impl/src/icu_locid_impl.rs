@@ -0,0 +1,72 @@
+//! Conversions between `LanguageIdentifier` and `icu_locid::LanguageIdentifier`.
+//!
+//! The conversions operate on the individual subtags, without serializing
+//! the identifiers. ICU4X only supports language subtags of 2 to 3 letters,
+//! so converting an identifier with a longer language subtag fails.
+use crate::LanguageIdentifier;
+use icu_locid::subtags;
+use icu_locid::ParserError;
+use std::convert::TryFrom;
+
+impl TryFrom<&LanguageIdentifier> for icu_locid::LanguageIdentifier {
+    type Error = ParserError;
+
+    fn try_from(langid: &LanguageIdentifier) -> Result<Self, Self::Error> {
+        let language = match langid.language {
+            Some(language) => subtags::Language::try_from_bytes(language.as_bytes())?,
+            None => subtags::Language::UND,
+        };
+        let script = langid
+            .script
+            .map(|script| subtags::Script::try_from_bytes(script.as_bytes()))
+            .transpose()?;
+        let region = langid
+            .region
+            .map(|region| subtags::Region::try_from_bytes(region.as_bytes()))
+            .transpose()?;
+        let mut variants = langid
+            .variants
+            .iter()
+            .flat_map(|variants| variants.iter())
+            .map(|variant| subtags::Variant::try_from_bytes(variant.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+        // ICU4X requires the variants to be sorted, which they may not be
+        // if the identifier was parsed with `preserve_variant_order`.
+        variants.sort();
+        variants.dedup();
+
+        Ok(icu_locid::LanguageIdentifier {
+            language,
+            script,
+            region,
+            variants: subtags::Variants::from_vec_unchecked(variants),
+        })
+    }
+}
+
+impl TryFrom<LanguageIdentifier> for icu_locid::LanguageIdentifier {
+    type Error = ParserError;
+
+    fn try_from(langid: LanguageIdentifier) -> Result<Self, Self::Error> {
+        icu_locid::LanguageIdentifier::try_from(&langid)
+    }
+}
+
+impl From<&icu_locid::LanguageIdentifier> for LanguageIdentifier {
+    fn from(langid: &icu_locid::LanguageIdentifier) -> Self {
+        let variants: Vec<&str> = langid.variants.iter().map(|v| v.as_str()).collect();
+        LanguageIdentifier::from_parts(
+            Some(langid.language.as_str()),
+            langid.script.as_ref().map(|s| s.as_str()),
+            langid.region.as_ref().map(|r| r.as_str()),
+            &variants,
+        )
+        .expect("ICU4X subtags are well-formed.")
+    }
+}
+
+impl From<icu_locid::LanguageIdentifier> for LanguageIdentifier {
+    fn from(langid: icu_locid::LanguageIdentifier) -> Self {
+        LanguageIdentifier::from(&langid)
+    }
+}
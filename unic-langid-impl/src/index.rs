@@ -0,0 +1,177 @@
+use crate::LanguageIdentifier;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use tinystr::{TinyStr4, TinyStr8};
+
+type Regions = HashMap<Option<TinyStr4>, Vec<usize>>;
+type Scripts = HashMap<Option<TinyStr4>, Regions>;
+type Languages = HashMap<Option<TinyStr8>, Scripts>;
+
+/// An index over a large set of available `LanguageIdentifier`s.
+///
+/// The identifiers are arranged in a language → script → region tree, so
+/// matching a requested identifier against the set takes a number of lookups
+/// proportional to the number of subtags, rather than a `matches` call for
+/// every available identifier.
+///
+/// The index is meant to be built once, for example from the locales an
+/// application ships, and queried for every request.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LanguageIdentifier, LocaleIndex};
+///
+/// let index: LocaleIndex = ["en-US", "en-GB", "en", "de-AT"].iter()
+///     .map(|s| s.parse().expect("Parsing failed."))
+///     .collect();
+///
+/// let requested: LanguageIdentifier = "en".parse()
+///     .expect("Parsing failed.");
+///
+/// let matches: Vec<String> = index.matches(&requested, false, true)
+///     .map(ToString::to_string)
+///     .collect();
+/// assert_eq!(matches, vec!["en-US", "en-GB", "en"]);
+///
+/// assert_eq!(index.position(&requested), Some(2));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct LocaleIndex {
+    locales: Vec<LanguageIdentifier>,
+    tree: Languages,
+}
+
+impl LocaleIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `LanguageIdentifier` to the index and returns its position.
+    pub fn insert(&mut self, langid: LanguageIdentifier) -> usize {
+        let idx = self.locales.len();
+        self.tree
+            .entry(langid.language)
+            .or_default()
+            .entry(langid.script)
+            .or_default()
+            .entry(langid.region)
+            .or_default()
+            .push(idx);
+        self.locales.push(langid);
+        idx
+    }
+
+    /// Returns the number of identifiers in the index.
+    pub fn len(&self) -> usize {
+        self.locales.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locales.is_empty()
+    }
+
+    /// Returns the identifier at the given position.
+    pub fn get(&self, idx: usize) -> Option<&LanguageIdentifier> {
+        self.locales.get(idx)
+    }
+
+    /// Returns all identifiers in the order they were added.
+    pub fn as_slice(&self) -> &[LanguageIdentifier] {
+        &self.locales
+    }
+
+    /// Returns the position of the first identifier equal to `langid`.
+    pub fn position(&self, langid: &LanguageIdentifier) -> Option<usize> {
+        self.tree
+            .get(&langid.language)?
+            .get(&langid.script)?
+            .get(&langid.region)?
+            .iter()
+            .copied()
+            .find(|idx| self.locales[*idx] == *langid)
+    }
+
+    pub fn contains(&self, langid: &LanguageIdentifier) -> bool {
+        self.position(langid).is_some()
+    }
+
+    /// Returns the identifiers matching `requested`, in the order they were added.
+    ///
+    /// The result is the same as filtering all identifiers with
+    /// `available.matches(requested, available_as_range, requested_as_range)`.
+    pub fn matches<'a, O: AsRef<LanguageIdentifier>>(
+        &'a self,
+        requested: &O,
+        available_as_range: bool,
+        requested_as_range: bool,
+    ) -> impl Iterator<Item = &'a LanguageIdentifier> {
+        let requested = requested.as_ref();
+
+        let mut indices = vec![];
+        for scripts in lookup(
+            &self.tree,
+            requested.language,
+            available_as_range,
+            requested_as_range,
+        ) {
+            for regions in lookup(
+                scripts,
+                requested.script,
+                available_as_range,
+                requested_as_range,
+            ) {
+                for idxs in lookup(
+                    regions,
+                    requested.region,
+                    available_as_range,
+                    requested_as_range,
+                ) {
+                    indices.extend(idxs.iter().copied().filter(|idx| {
+                        self.locales[*idx].matches(
+                            requested,
+                            available_as_range,
+                            requested_as_range,
+                        )
+                    }));
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.into_iter().map(move |idx| &self.locales[idx])
+    }
+}
+
+/// Returns the entries of one level of the tree which can match the requested subtag.
+fn lookup<K: Copy + Eq + Hash, V>(
+    map: &HashMap<Option<K>, V>,
+    requested: Option<K>,
+    available_as_range: bool,
+    requested_as_range: bool,
+) -> Vec<&V> {
+    if requested.is_none() && requested_as_range {
+        return map.values().collect();
+    }
+    let mut result: Vec<&V> = map.get(&requested).into_iter().collect();
+    if available_as_range && requested.is_some() {
+        result.extend(map.get(&None));
+    }
+    result
+}
+
+impl FromIterator<LanguageIdentifier> for LocaleIndex {
+    fn from_iter<I: IntoIterator<Item = LanguageIdentifier>>(iter: I) -> Self {
+        let mut index = Self::new();
+        for langid in iter {
+            index.insert(langid);
+        }
+        index
+    }
+}
+
+impl From<Vec<LanguageIdentifier>> for LocaleIndex {
+    fn from(locales: Vec<LanguageIdentifier>) -> Self {
+        locales.into_iter().collect()
+    }
+}
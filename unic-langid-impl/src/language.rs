@@ -0,0 +1,180 @@
+use crate::language_alias_table::LANGUAGE_ALIASES;
+use crate::language_table::{COLLECTIONS, DEPRECATED, LANGUAGE_CODES, MACROLANGUAGES, SPECIAL};
+use crate::parser::ParserError;
+use crate::subtags;
+use crate::LanguageIdentifierError;
+use std::str::FromStr;
+use tinystr::TinyStr8;
+
+/// Scope of a language code, as defined in ISO 639.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LanguageScope {
+    /// A single language, such as `de` or `haw`.
+    Individual,
+    /// A cluster of closely related individual languages, such as `zh` or `ar`.
+    Macrolanguage,
+    /// A group of languages, such as `sla` (Slavic languages).
+    Collection,
+    /// A code which does not identify a language, such as `mul` or `und`.
+    Special,
+    /// A code from the private use range `qaa`..`qtz`.
+    PrivateUse,
+}
+
+/// Returns the scope of a language code.
+///
+/// Returns `None` if the input is not a valid language subtag.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{language_scope, LanguageScope};
+///
+/// assert_eq!(language_scope("zh"), Some(LanguageScope::Macrolanguage));
+/// assert_eq!(language_scope("yue"), Some(LanguageScope::Individual));
+/// assert_eq!(language_scope("und"), Some(LanguageScope::Special));
+/// assert_eq!(language_scope("z1"), None);
+/// ```
+pub fn language_scope(language: &str) -> Option<LanguageScope> {
+    match subtags::parse_language_subtag(language).ok()? {
+        Some(language) => Some(get_language_scope(&language)),
+        None => Some(LanguageScope::Special),
+    }
+}
+
+fn get_language_scope(code: &str) -> LanguageScope {
+    if MACROLANGUAGES.binary_search(&code).is_ok() {
+        LanguageScope::Macrolanguage
+    } else if COLLECTIONS.binary_search(&code).is_ok() {
+        LanguageScope::Collection
+    } else if SPECIAL.binary_search(&code).is_ok() {
+        LanguageScope::Special
+    } else if code.len() == 3 && ("qaa"..="qtz").contains(&code) {
+        LanguageScope::PrivateUse
+    } else {
+        LanguageScope::Individual
+    }
+}
+
+/// A language subtag, such as `de` or `haw`.
+///
+/// `Language` allows converting between ISO 639-1 codes and the three letter
+/// ISO 639-2/T, ISO 639-2/B and ISO 639-3 codes used by library and bibliographic systems.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::Language;
+///
+/// let language: Language = "ger".parse().expect("Parsing failed.");
+/// assert_eq!(language.as_str(), "ger");
+///
+/// let language = language.canonicalize();
+/// assert_eq!(language.as_str(), "de");
+/// assert_eq!(language.to_alpha3_t(), "deu");
+/// assert_eq!(language.to_alpha3_b(), "ger");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Language(TinyStr8);
+
+impl Language {
+    /// Returns the canonical form of the language code.
+    ///
+    /// Overlong, bibliographic and legacy codes are replaced following the CLDR
+    /// language aliases, so `deu` and `ger` become `de`, while `tgl` and `tl` become `fil`.
+    /// Deprecated codes and macrolanguage members are kept.
+    pub fn canonicalize(&self) -> Self {
+        Self(canonicalize_language_subtag(self.0))
+    }
+
+    /// Returns the ISO 639-1 code of the language, if it has one.
+    pub fn to_alpha2(&self) -> Option<&'static str> {
+        self.get_codes().map(|(alpha2, _, _)| alpha2)
+    }
+
+    /// Returns the ISO 639-2/T code of the language.
+    ///
+    /// This is also the ISO 639-3 code. Codes which are not in ISO 639-1 are returned as is.
+    pub fn to_alpha3_t(&self) -> &str {
+        match self.get_codes() {
+            Some((_, terminology, _)) => terminology,
+            None => self.as_str(),
+        }
+    }
+
+    /// Returns the ISO 639-2/B code of the language.
+    ///
+    /// Codes which are not in ISO 639-1 are returned as is.
+    pub fn to_alpha3_b(&self) -> &str {
+        match self.get_codes() {
+            Some((_, _, bibliographic)) => bibliographic,
+            None => self.as_str(),
+        }
+    }
+
+    /// Returns the scope of the language.
+    ///
+    /// Codes which are not known to be macrolanguages, collections, special or
+    /// private use codes are considered individual languages.
+    pub fn get_scope(&self) -> LanguageScope {
+        get_language_scope(self.as_str())
+    }
+
+    /// Returns `true` if the language code is deprecated, such as `iw` which was replaced with `he`.
+    pub fn is_deprecated(&self) -> bool {
+        DEPRECATED.binary_search(&self.as_str()).is_ok()
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    fn get_codes(&self) -> Option<(&'static str, &'static str, &'static str)> {
+        get_language_codes(self.as_str())
+    }
+}
+
+pub(crate) fn get_language_codes(code: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    match code.len() {
+        2 => LANGUAGE_CODES
+            .binary_search_by(|(alpha2, _, _)| (*alpha2).cmp(code))
+            .ok()
+            .map(|idx| LANGUAGE_CODES[idx]),
+        3 => LANGUAGE_CODES
+            .iter()
+            .find(|(_, terminology, bibliographic)| *terminology == code || *bibliographic == code)
+            .copied(),
+        _ => None,
+    }
+}
+
+/// Replaces overlong, bibliographic and legacy language codes following the CLDR aliases.
+pub(crate) fn canonicalize_language_subtag(language: TinyStr8) -> TinyStr8 {
+    match LANGUAGE_ALIASES.binary_search_by(|(code, _)| (*code).cmp(language.as_str())) {
+        Ok(idx) => LANGUAGE_ALIASES[idx].1.parse().unwrap(),
+        Err(_) => language,
+    }
+}
+
+impl FromStr for Language {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match subtags::parse_language_subtag(source)? {
+            Some(language) => Ok(Self(language)),
+            None => Err(ParserError::InvalidLanguage.into()),
+        }
+    }
+}
+
+impl From<Language> for TinyStr8 {
+    fn from(language: Language) -> Self {
+        language.0
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
@@ -0,0 +1,220 @@
+/// ISO 639-1 language codes with their ISO 639-2/T and ISO 639-2/B equivalents,
+/// as `(alpha-2, terminology, bibliographic)`, sorted by alpha-2.
+///
+/// The ISO 639-3 code of each of these languages is the same as its ISO 639-2/T code.
+pub const LANGUAGE_CODES: [(&str, &str, &str); 183] = [
+    ("aa", "aar", "aar"),
+    ("ab", "abk", "abk"),
+    ("ae", "ave", "ave"),
+    ("af", "afr", "afr"),
+    ("ak", "aka", "aka"),
+    ("am", "amh", "amh"),
+    ("an", "arg", "arg"),
+    ("ar", "ara", "ara"),
+    ("as", "asm", "asm"),
+    ("av", "ava", "ava"),
+    ("ay", "aym", "aym"),
+    ("az", "aze", "aze"),
+    ("ba", "bak", "bak"),
+    ("be", "bel", "bel"),
+    ("bg", "bul", "bul"),
+    ("bi", "bis", "bis"),
+    ("bm", "bam", "bam"),
+    ("bn", "ben", "ben"),
+    ("bo", "bod", "tib"),
+    ("br", "bre", "bre"),
+    ("bs", "bos", "bos"),
+    ("ca", "cat", "cat"),
+    ("ce", "che", "che"),
+    ("ch", "cha", "cha"),
+    ("co", "cos", "cos"),
+    ("cr", "cre", "cre"),
+    ("cs", "ces", "cze"),
+    ("cu", "chu", "chu"),
+    ("cv", "chv", "chv"),
+    ("cy", "cym", "wel"),
+    ("da", "dan", "dan"),
+    ("de", "deu", "ger"),
+    ("dv", "div", "div"),
+    ("dz", "dzo", "dzo"),
+    ("ee", "ewe", "ewe"),
+    ("el", "ell", "gre"),
+    ("en", "eng", "eng"),
+    ("eo", "epo", "epo"),
+    ("es", "spa", "spa"),
+    ("et", "est", "est"),
+    ("eu", "eus", "baq"),
+    ("fa", "fas", "per"),
+    ("ff", "ful", "ful"),
+    ("fi", "fin", "fin"),
+    ("fj", "fij", "fij"),
+    ("fo", "fao", "fao"),
+    ("fr", "fra", "fre"),
+    ("fy", "fry", "fry"),
+    ("ga", "gle", "gle"),
+    ("gd", "gla", "gla"),
+    ("gl", "glg", "glg"),
+    ("gn", "grn", "grn"),
+    ("gu", "guj", "guj"),
+    ("gv", "glv", "glv"),
+    ("ha", "hau", "hau"),
+    ("he", "heb", "heb"),
+    ("hi", "hin", "hin"),
+    ("ho", "hmo", "hmo"),
+    ("hr", "hrv", "hrv"),
+    ("ht", "hat", "hat"),
+    ("hu", "hun", "hun"),
+    ("hy", "hye", "arm"),
+    ("hz", "her", "her"),
+    ("ia", "ina", "ina"),
+    ("id", "ind", "ind"),
+    ("ie", "ile", "ile"),
+    ("ig", "ibo", "ibo"),
+    ("ii", "iii", "iii"),
+    ("ik", "ipk", "ipk"),
+    ("io", "ido", "ido"),
+    ("is", "isl", "ice"),
+    ("it", "ita", "ita"),
+    ("iu", "iku", "iku"),
+    ("ja", "jpn", "jpn"),
+    ("jv", "jav", "jav"),
+    ("ka", "kat", "geo"),
+    ("kg", "kon", "kon"),
+    ("ki", "kik", "kik"),
+    ("kj", "kua", "kua"),
+    ("kk", "kaz", "kaz"),
+    ("kl", "kal", "kal"),
+    ("km", "khm", "khm"),
+    ("kn", "kan", "kan"),
+    ("ko", "kor", "kor"),
+    ("kr", "kau", "kau"),
+    ("ks", "kas", "kas"),
+    ("ku", "kur", "kur"),
+    ("kv", "kom", "kom"),
+    ("kw", "cor", "cor"),
+    ("ky", "kir", "kir"),
+    ("la", "lat", "lat"),
+    ("lb", "ltz", "ltz"),
+    ("lg", "lug", "lug"),
+    ("li", "lim", "lim"),
+    ("ln", "lin", "lin"),
+    ("lo", "lao", "lao"),
+    ("lt", "lit", "lit"),
+    ("lu", "lub", "lub"),
+    ("lv", "lav", "lav"),
+    ("mg", "mlg", "mlg"),
+    ("mh", "mah", "mah"),
+    ("mi", "mri", "mao"),
+    ("mk", "mkd", "mac"),
+    ("ml", "mal", "mal"),
+    ("mn", "mon", "mon"),
+    ("mr", "mar", "mar"),
+    ("ms", "msa", "may"),
+    ("mt", "mlt", "mlt"),
+    ("my", "mya", "bur"),
+    ("na", "nau", "nau"),
+    ("nb", "nob", "nob"),
+    ("nd", "nde", "nde"),
+    ("ne", "nep", "nep"),
+    ("ng", "ndo", "ndo"),
+    ("nl", "nld", "dut"),
+    ("nn", "nno", "nno"),
+    ("no", "nor", "nor"),
+    ("nr", "nbl", "nbl"),
+    ("nv", "nav", "nav"),
+    ("ny", "nya", "nya"),
+    ("oc", "oci", "oci"),
+    ("oj", "oji", "oji"),
+    ("om", "orm", "orm"),
+    ("or", "ori", "ori"),
+    ("os", "oss", "oss"),
+    ("pa", "pan", "pan"),
+    ("pi", "pli", "pli"),
+    ("pl", "pol", "pol"),
+    ("ps", "pus", "pus"),
+    ("pt", "por", "por"),
+    ("qu", "que", "que"),
+    ("rm", "roh", "roh"),
+    ("rn", "run", "run"),
+    ("ro", "ron", "rum"),
+    ("ru", "rus", "rus"),
+    ("rw", "kin", "kin"),
+    ("sa", "san", "san"),
+    ("sc", "srd", "srd"),
+    ("sd", "snd", "snd"),
+    ("se", "sme", "sme"),
+    ("sg", "sag", "sag"),
+    ("si", "sin", "sin"),
+    ("sk", "slk", "slo"),
+    ("sl", "slv", "slv"),
+    ("sm", "smo", "smo"),
+    ("sn", "sna", "sna"),
+    ("so", "som", "som"),
+    ("sq", "sqi", "alb"),
+    ("sr", "srp", "srp"),
+    ("ss", "ssw", "ssw"),
+    ("st", "sot", "sot"),
+    ("su", "sun", "sun"),
+    ("sv", "swe", "swe"),
+    ("sw", "swa", "swa"),
+    ("ta", "tam", "tam"),
+    ("te", "tel", "tel"),
+    ("tg", "tgk", "tgk"),
+    ("th", "tha", "tha"),
+    ("ti", "tir", "tir"),
+    ("tk", "tuk", "tuk"),
+    ("tl", "tgl", "tgl"),
+    ("tn", "tsn", "tsn"),
+    ("to", "ton", "ton"),
+    ("tr", "tur", "tur"),
+    ("ts", "tso", "tso"),
+    ("tt", "tat", "tat"),
+    ("tw", "twi", "twi"),
+    ("ty", "tah", "tah"),
+    ("ug", "uig", "uig"),
+    ("uk", "ukr", "ukr"),
+    ("ur", "urd", "urd"),
+    ("uz", "uzb", "uzb"),
+    ("ve", "ven", "ven"),
+    ("vi", "vie", "vie"),
+    ("vo", "vol", "vol"),
+    ("wa", "wln", "wln"),
+    ("wo", "wol", "wol"),
+    ("xh", "xho", "xho"),
+    ("yi", "yid", "yid"),
+    ("yo", "yor", "yor"),
+    ("za", "zha", "zha"),
+    ("zh", "zho", "chi"),
+    ("zu", "zul", "zul"),
+];
+
+/// Macrolanguages, sorted.
+pub const MACROLANGUAGES: [&str; 62] = [
+    "ak", "ar", "ay", "az", "bal", "bik", "bnc", "bua", "chm", "cr", "del", "den", "din", "doi",
+    "et", "fa", "ff", "gba", "gn", "gon", "grb", "hai", "hmn", "ik", "iu", "jrb", "kg", "kln",
+    "kok", "kpe", "kr", "ku", "kv", "lah", "luy", "lv", "man", "mg", "mn", "ms", "mwr", "ne", "no",
+    "oj", "om", "or", "ps", "qu", "raj", "rom", "sc", "sq", "sva", "sw", "syr", "tmh", "uz", "yi",
+    "za", "zap", "zh", "zza",
+];
+
+/// ISO 639-2 and ISO 639-5 collections of languages, sorted.
+pub const COLLECTIONS: [&str; 111] = [
+    "aav", "afa", "alg", "alv", "apa", "aqa", "aql", "art", "ath", "auf", "aus", "awd", "azc",
+    "bad", "bai", "bat", "ber", "bih", "bnt", "btk", "cai", "cau", "cba", "ccn", "ccs", "cdc",
+    "cdd", "cel", "cmc", "crp", "csu", "cus", "day", "dmn", "dra", "egx", "esx", "euq", "fiu",
+    "fox", "gem", "gme", "gmq", "gmw", "grk", "hmx", "hok", "hyx", "iir", "ijo", "inc", "ine",
+    "ira", "iro", "itc", "kar", "kdo", "khi", "kro", "map", "mkh", "mno", "mun", "myn", "nah",
+    "nai", "ngf", "nic", "nub", "omq", "omv", "oto", "paa", "phi", "plf", "poz", "pqe", "pqw",
+    "pra", "qwe", "roa", "sai", "sal", "sdv", "sem", "sgn", "sio", "sit", "sla", "smi", "son",
+    "sqj", "ssa", "syd", "tai", "tbq", "trk", "tup", "tut", "tuw", "urj", "wak", "wen", "xgn",
+    "xnd", "ypk", "zhx", "zle", "zls", "zlw", "znd",
+];
+
+/// Special codes, which do not identify a language, sorted.
+pub const SPECIAL: [&str; 4] = ["mis", "mul", "und", "zxx"];
+
+/// Deprecated language codes, sorted.
+pub const DEPRECATED: [&str; 27] = [
+    "ayx", "bgm", "bjd", "ccq", "cmk", "drh", "drw", "gav", "gfx", "in", "iw", "ji", "jw", "kgh",
+    "koj", "krm", "kzj", "kzt", "mo", "mst", "myt", "tie", "tkk", "tlw", "tnf", "ybd", "yma",
+];
@@ -0,0 +1,45 @@
+//! Conversions between `LanguageIdentifier` and `language_tags::LanguageTag`.
+//!
+//! HTTP libraries commonly hand out BCP 47 tags as `language_tags::LanguageTag`.
+//! Every `LanguageIdentifier` is a well-formed language tag, while a language
+//! tag can only be converted if it has no extended language, extension or
+//! private use subtags and is not a grandfathered tag.
+use crate::parser::ParserError;
+use crate::{LanguageIdentifier, LanguageIdentifierError};
+use language_tags::LanguageTag;
+use std::convert::TryFrom;
+
+impl From<&LanguageIdentifier> for LanguageTag {
+    fn from(langid: &LanguageIdentifier) -> Self {
+        LanguageTag::parse(&langid.to_string())
+            .expect("A language identifier is a well-formed language tag.")
+    }
+}
+
+impl From<LanguageIdentifier> for LanguageTag {
+    fn from(langid: LanguageIdentifier) -> Self {
+        LanguageTag::from(&langid)
+    }
+}
+
+impl TryFrom<&LanguageTag> for LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(tag: &LanguageTag) -> Result<Self, Self::Error> {
+        // `language_tags` keeps grandfathered and private use tags whole in
+        // the primary language. Regular grandfathered tags such as
+        // `art-lojban` would otherwise parse as a language and a variant.
+        if tag.primary_language().contains('-') {
+            return Err(ParserError::InvalidLanguage.into());
+        }
+        tag.as_str().parse()
+    }
+}
+
+impl TryFrom<LanguageTag> for LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(tag: LanguageTag) -> Result<Self, Self::Error> {
+        LanguageIdentifier::try_from(&tag)
+    }
+}
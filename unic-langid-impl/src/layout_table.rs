@@ -1 +1,3 @@
+pub const CLDR_VERSION: &str = "35.1";
 pub const CHARACTER_DIRECTION_RTL: [u64; 6] = [29281, 29301, 29552, 24934, 25715, 25960];
+pub const CHARACTER_DIRECTION_LTR: [u64; 76] = [24931, 24932, 24935, 24938, 24939, 24944, 24948, 25198, 25705, 25954, 25956, 25966, 25972, 26209, 26466, 26740, 26746, 26982, 26984, 26995, 26998, 27499, 27501, 27507, 27508, 27509, 27749, 27751, 27757, 27758, 27760, 27763, 28001, 28011, 28258, 28261, 28267, 28269, 28523, 28524, 28530, 28531, 29043, 29286, 29288, 29293, 29295, 29299, 29300, 29537, 29538, 29539, 29541, 29545, 29549, 29797, 29801, 29804, 29808, 30053, 30055, 30056, 30066, 30074, 30314, 30316, 30323, 30579, 31075, 31080, 31083, 31085, 31329, 31349, 6649209, 7104870];
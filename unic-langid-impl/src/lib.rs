@@ -1,18 +1,80 @@
+#[cfg(feature = "available-locales")]
+mod available_locales;
+#[cfg(feature = "available-locales")]
+mod available_locales_table;
+mod canonical;
+mod core_langid;
+pub mod consts;
+mod data_versions;
+mod diff;
 mod errors;
+mod extract;
+#[cfg(feature = "icu_locid")]
+mod icu_locid_impl;
+mod index;
+mod language;
+mod language_alias_table;
+mod language_table;
+#[cfg(feature = "language-tags")]
+mod language_tags_impl;
+#[cfg(all(feature = "layout", not(unic_langid_cldr_build)))]
 mod layout_table;
-#[cfg(feature = "likelysubtags")]
+#[cfg(all(feature = "layout", unic_langid_cldr_build))]
+mod layout_table {
+    include!(concat!(env!("OUT_DIR"), "/layout_table.rs"));
+}
+#[cfg(feature = "likelysubtags-add")]
 pub mod likelysubtags;
 #[doc(hidden)]
 pub mod parser;
+mod recovered;
+mod region;
+mod region_table;
+mod script;
+mod script_code_table;
+#[cfg(feature = "layout")]
+mod script_table;
+mod set;
+mod sourced;
 mod subtags;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod tinystr_ext;
 
+#[cfg(feature = "available-locales")]
+pub use crate::available_locales::{available_locales, CldrCoverage};
+pub use crate::canonical::CanonicalLanguageIdentifier;
+pub use crate::core_langid::CoreLanguageIdentifier;
+pub use crate::data_versions::{data_versions, DataVersions};
+pub use crate::diff::SubtagDiff;
 pub use crate::errors::LanguageIdentifierError;
-use layout_table::CHARACTER_DIRECTION_RTL;
+pub use crate::extract::extract;
+pub use crate::index::LocaleIndex;
+pub use crate::language::{language_scope, Language, LanguageScope};
+pub use crate::parser::ParserOptions;
+pub use crate::recovered::{RecoveredLanguageIdentifier, SkippedSubtag};
+pub use crate::region::Region;
+pub use crate::script::Script;
+pub use crate::set::LangIdSet;
+pub use crate::sourced::SourcedLanguageIdentifier;
+#[cfg(feature = "layout")]
+use layout_table::{CHARACTER_DIRECTION_LTR, CHARACTER_DIRECTION_RTL};
+#[cfg(feature = "layout")]
+use script_table::{LINE_ORIENTATION_MIXED, LINE_ORIENTATION_VERTICAL};
+use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::iter::Peekable;
 use std::str::FromStr;
 
 use tinystr::{TinyStr4, TinyStr8};
 
+type TinyStrParts = (
+    Option<TinyStr8>,
+    Option<TinyStr4>,
+    Option<TinyStr4>,
+    Option<Box<[TinyStr8]>>,
+);
+
 /// Enum representing available character direction orientations.
 #[derive(Debug, PartialEq)]
 pub enum CharacterDirection {
@@ -26,6 +88,46 @@ pub enum CharacterDirection {
     LTR,
 }
 
+/// Enum representing available line orientations.
+#[derive(Debug, PartialEq)]
+pub enum LineOrientation {
+    /// Lines are horizontal.
+    ///
+    /// Used in scripts such as Latin, Arabic, Cyrillic, Devanagari etc.
+    Horizontal,
+    /// Lines are either horizontal or vertical, with upright glyphs.
+    ///
+    /// Used in scripts such as Han, Hiragana, Katakana, Hangul etc.
+    Mixed,
+    /// Lines are vertical, with rotated glyphs.
+    ///
+    /// Used in scripts such as Mongolian and Phags-pa.
+    Vertical,
+}
+
+/// Enum describing how closely two language identifiers match.
+///
+/// See `LanguageIdentifier::match_quality`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatchQuality {
+    /// All subtags are the same.
+    Exact,
+    /// The language and script are the same, but the region or variants differ.
+    ///
+    /// For example `en-GB` and `en-CA`.
+    RegionDiffers,
+    /// The language and region are the same, but the script differs.
+    ///
+    /// For example `sr-Latn-RS` and `sr-Cyrl-RS`.
+    ScriptDiffers,
+    /// Only the language is the same.
+    ///
+    /// For example `sr-Latn-RS` and `sr-Cyrl-BA`.
+    LanguageOnly,
+    /// The languages differ.
+    None,
+}
+
 /// `LanguageIdentifier` is a core struct representing a Unicode Language Identifier.
 ///
 /// # Examples
@@ -56,6 +158,9 @@ pub enum CharacterDirection {
 /// Any bogus subtags will cause the parsing to fail with an error.
 /// No subtag validation is performed.
 ///
+/// The CLDR `root` language is accepted as a synonym for `und`.
+/// It can be emitted back using the alternate formatting flag `{:#}`.
+///
 /// # Eamples:
 ///
 /// ```
@@ -80,6 +185,42 @@ pub struct LanguageIdentifier {
 }
 
 impl LanguageIdentifier {
+    /// The empty `LanguageIdentifier`, serialized as `und`.
+    ///
+    /// Unlike `Default::default()`, it can be used in const and static contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// static ROOT: LanguageIdentifier = LanguageIdentifier::UND;
+    ///
+    /// assert_eq!(ROOT, LanguageIdentifier::default());
+    /// assert_eq!(ROOT.to_string(), "und");
+    /// ```
+    pub const UND: Self = Self {
+        language: None,
+        script: None,
+        region: None,
+        variants: None,
+    };
+
+    /// A const constructor of the empty `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// const LI: LanguageIdentifier = LanguageIdentifier::new();
+    ///
+    /// assert_eq!(LI, LanguageIdentifier::UND);
+    /// ```
+    pub const fn new() -> Self {
+        Self::UND
+    }
+
     /// A constructor which takes optional subtags as `&str`, parses them and
     /// produces a well-formed `LanguageIdentifier`.
     ///
@@ -135,6 +276,209 @@ impl LanguageIdentifier {
         })
     }
 
+    /// A constructor which parses the input according to the given `ParserOptions`.
+    ///
+    /// `FromStr` uses the default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let options = ParserOptions {
+    ///     lenient: true,
+    ///     ..ParserOptions::default()
+    /// };
+    ///
+    /// let li = LanguageIdentifier::from_str_with("en-US-x", &options)
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "en-US");
+    /// ```
+    pub fn from_str_with(
+        input: &str,
+        options: &ParserOptions,
+    ) -> Result<Self, LanguageIdentifierError> {
+        parser::parse_language_identifier_with_options(input, options)
+            .map_err(std::convert::Into::into)
+    }
+
+    /// A constructor for strict BCP 47 validation, which only accepts `-` separators
+    /// and canonical ASCII input.
+    ///
+    /// This is equivalent to calling `from_str_with` with `ParserOptions::strict()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// assert!(LanguageIdentifier::from_str_strict("en-Latn-US").is_ok());
+    /// assert!(LanguageIdentifier::from_str_strict("en_US").is_err());
+    /// ```
+    pub fn from_str_strict(input: &str) -> Result<Self, LanguageIdentifierError> {
+        Self::from_str_with(input, &ParserOptions::strict())
+    }
+
+    /// A constructor which parses the input according to the given `ParserOptions`,
+    /// reporting every problem found in the input instead of only the first one.
+    ///
+    /// Invalid subtags are reported even if `options.lenient` is set, which makes
+    /// it suitable for validators showing all the issues at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::parser::{ParserError, ParserOptions};
+    ///
+    /// let errors = LanguageIdentifier::validate("en-Lat$-US-ab", &ParserOptions::default())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(errors.errors(), &[
+    ///     (3, ParserError::InvalidSubtag),
+    ///     (11, ParserError::InvalidSubtag),
+    /// ]);
+    /// ```
+    pub fn validate(input: &str, options: &ParserOptions) -> Result<Self, parser::ParserErrors> {
+        parser::validate_language_identifier(input, options)
+    }
+
+    /// Parses all the inputs, reporting every failure instead of stopping at
+    /// the first one.
+    ///
+    /// If any input fails to parse, the errors are returned together with
+    /// the indices of the failed inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let langids = LanguageIdentifier::parse_all(&["en-US", "de", "fr-CA"])
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(langids.len(), 3);
+    ///
+    /// let errors = LanguageIdentifier::parse_all(&["en-US", "e", "fr-CA", "x-$"])
+    ///     .unwrap_err();
+    /// let indices: Vec<usize> = errors.iter().map(|(idx, _)| *idx).collect();
+    /// assert_eq!(indices, vec![1, 3]);
+    /// ```
+    pub fn parse_all<S: AsRef<str>>(
+        inputs: &[S],
+    ) -> Result<Vec<Self>, Vec<(usize, LanguageIdentifierError)>> {
+        let mut langids = Vec::with_capacity(inputs.len());
+        let mut errors = vec![];
+        for (idx, input) in inputs.iter().enumerate() {
+            match input.as_ref().parse() {
+                Ok(langid) => langids.push(langid),
+                Err(err) => errors.push((idx, err)),
+            }
+        }
+        if errors.is_empty() {
+            Ok(langids)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// A constructor which parses an `OsStr`, such as the value of an environment variable.
+    ///
+    /// The input has to be ASCII, so no lossy conversion is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::from_os_str(OsStr::new("en_US"))
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "en-US");
+    /// ```
+    pub fn from_os_str(input: &OsStr) -> Result<Self, LanguageIdentifierError> {
+        match input.to_str() {
+            Some(s) if s.is_ascii() => s.parse(),
+            _ => Err(parser::errors::ParserError::InvalidLanguage.into()),
+        }
+    }
+
+    /// A constructor which parses an Android resource locale qualifier,
+    /// such as `en-rUS` or `b+sr+Latn+RS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li = LanguageIdentifier::from_android_qualifier("en-rUS")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_string(), "en-US");
+    ///
+    /// let li = LanguageIdentifier::from_android_qualifier("b+sr+Latn+RS")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_string(), "sr-Latn-RS");
+    /// ```
+    pub fn from_android_qualifier(input: &str) -> Result<Self, LanguageIdentifierError> {
+        if let Some(tag) = input.strip_prefix("b+") {
+            return parser::parse_language_identifier_from_iter(
+                &mut tag.split('+').peekable(),
+                false,
+            )
+            .map_err(std::convert::Into::into);
+        }
+
+        let mut parts = input.split('-');
+        let language = parts.next().filter(|l| l.len() == 2 || l.len() == 3);
+        let region = match parts.next() {
+            Some(region) => match region.strip_prefix('r') {
+                Some(region) if region.len() == 2 => Some(region),
+                _ => return Err(parser::errors::ParserError::InvalidSubtag.into()),
+            },
+            None => None,
+        };
+        if parts.next().is_some() {
+            return Err(parser::errors::ParserError::InvalidSubtag.into());
+        }
+        match language {
+            Some(language) => Self::from_parts(Some(language), None, region, &[]),
+            None => Err(parser::errors::ParserError::InvalidLanguage.into()),
+        }
+    }
+
+    /// Serializes the `LanguageIdentifier` into an Android resource locale qualifier.
+    ///
+    /// Identifiers which consist of a two letter language and an optional two letter
+    /// region use the legacy `en-rUS` form, all others use the BCP 47 `b+` form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_android_qualifier(), "en-rUS");
+    ///
+    /// let li: LanguageIdentifier = "es-419".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_android_qualifier(), "b+es+419");
+    /// ```
+    pub fn to_android_qualifier(&self) -> String {
+        let legacy = self.script.is_none()
+            && is_option_empty(&self.variants)
+            && matches!(self.language, Some(l) if l.len() == 2)
+            && !matches!(self.region, Some(r) if r.len() != 2);
+        if !legacy {
+            return format!("b+{}", self.to_string().replace('-', "+"));
+        }
+        match self.region {
+            Some(region) => format!("{}-r{}", self.get_language(), region),
+            None => self.get_language().to_string(),
+        }
+    }
+
     #[doc(hidden)]
     /// This method is used by `unic-locale` to handle partial
     /// subtag iterator.
@@ -184,6 +528,28 @@ impl LanguageIdentifier {
         )
     }
 
+    /// Consumes `LanguageIdentifier` and produces its subtags as `TinyStr` values,
+    /// in the form accepted by `from_raw_parts_unchecked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let (lang, script, region, variants) = li.into_tinystr_parts();
+    ///
+    /// assert_eq!(lang.as_deref(), Some("en"));
+    /// assert_eq!(script, None);
+    /// assert_eq!(region.as_deref(), Some("US"));
+    /// assert_eq!(variants, None);
+    /// ```
+    pub fn into_tinystr_parts(self) -> TinyStrParts {
+        (self.language, self.script, self.region, self.variants)
+    }
+
     /// Consumes raw representation of subtags generating new `LanguageIdentifier`
     /// without any checks.
     ///
@@ -193,19 +559,15 @@ impl LanguageIdentifier {
     ///
     /// ```
     /// use unic_langid_impl::LanguageIdentifier;
-    /// use tinystr::{TinyStr8, TinyStr4};
     ///
     /// let li: LanguageIdentifier = "en-US".parse()
     ///     .expect("Parsing failed.");
     ///
-    /// let (lang, script, region, variants) = li.into_raw_parts();
+    /// let (lang, script, region, variants) = li.into_tinystr_parts();
     ///
-    /// let li2 = unsafe { LanguageIdentifier::from_raw_parts_unchecked(
-    ///     lang.map(|l| TinyStr8::new_unchecked(l)),
-    ///     script.map(|s| TinyStr4::new_unchecked(s)),
-    ///     region.map(|r| TinyStr4::new_unchecked(r)),
-    ///     variants.map(|v| v.into_iter().map(|v| TinyStr8::new_unchecked(*v)).collect()),
-    /// ) };
+    /// let li2 = unsafe {
+    ///     LanguageIdentifier::from_raw_parts_unchecked(lang, script, region, variants)
+    /// };
     ///
     /// assert_eq!(li2.to_string(), "en-US");
     /// ```
@@ -248,26 +610,509 @@ impl LanguageIdentifier {
     /// assert_eq!(li1.matches(&li2, false, true), false); // "en" != "en-*-US-*"
     /// assert_eq!(li1.matches(&li2, true, true), true); // "en-*-*-*" == "en-*-US-*"
     /// ```
-    pub fn matches<O: AsRef<Self>>(
-        &self,
-        other: &O,
-        self_as_range: bool,
-        other_as_range: bool,
-    ) -> bool {
-        let other = other.as_ref();
-        subtag_matches(
-            &self.language,
-            &other.language,
-            self_as_range,
-            other_as_range,
-        ) && subtag_matches(&self.script, &other.script, self_as_range, other_as_range)
-            && subtag_matches(&self.region, &other.region, self_as_range, other_as_range)
-            && subtags_match(
-                &self.variants,
-                &other.variants,
-                self_as_range,
-                other_as_range,
-            )
+    pub fn matches<O: AsRef<Self>>(
+        &self,
+        other: &O,
+        self_as_range: bool,
+        other_as_range: bool,
+    ) -> bool {
+        let other = other.as_ref();
+        subtag_matches(
+            &self.language,
+            &other.language,
+            self_as_range,
+            other_as_range,
+        ) && subtag_matches(&self.script, &other.script, self_as_range, other_as_range)
+            && subtag_matches(&self.region, &other.region, self_as_range, other_as_range)
+            && subtags_match(
+                &self.variants,
+                &other.variants,
+                self_as_range,
+                other_as_range,
+            )
+    }
+
+    /// Matches the `LanguageIdentifier` against a textual language range
+    /// without parsing the range into another `LanguageIdentifier`.
+    ///
+    /// The result is the same as parsing the range and calling `matches` with
+    /// the range as the `other_as_range` side. Additionally, a `*` subtag in the
+    /// range matches any language, script, region or variants in its position.
+    ///
+    /// The `require_canonical` option is not applied to the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let li: LanguageIdentifier = "en-Latn-GB".parse()
+    ///     .expect("Parsing failed.");
+    /// let options = ParserOptions::default();
+    ///
+    /// assert_eq!(li.matches_str("en-*-GB", &options), Ok(true));
+    /// assert_eq!(li.matches_str("*-GB", &options), Ok(true));
+    /// assert_eq!(li.matches_str("en-US", &options), Ok(false));
+    /// assert!(li.matches_str("en-$", &options).is_err());
+    /// ```
+    pub fn matches_str(
+        &self,
+        range: &str,
+        options: &ParserOptions,
+    ) -> Result<bool, LanguageIdentifierError> {
+        parser::matches_language_range(self, range, options).map_err(std::convert::Into::into)
+    }
+
+    /// Compares the `LanguageIdentifier` to a string, ignoring the case of
+    /// the subtags, without parsing the string.
+    ///
+    /// Subtags can be separated by `-` or `_`, and the variants can be listed
+    /// in any order, so the result is the same as comparing to the parsed
+    /// string for well-formed input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(li.eq_str_ignore_case("EN_us"));
+    /// assert!(li.eq_str_ignore_case("en-us"));
+    /// assert!(!li.eq_str_ignore_case("en"));
+    /// assert!(!li.eq_str_ignore_case("en-US-posix"));
+    /// ```
+    pub fn eq_str_ignore_case(&self, input: &str) -> bool {
+        let mut subtags = input.split(['-', '_']);
+        let mut next_eq = |expected: &str| {
+            subtags
+                .next()
+                .is_some_and(|subtag| subtag.eq_ignore_ascii_case(expected))
+        };
+
+        if !next_eq(self.get_language()) {
+            return false;
+        }
+        if let Some(script) = self.get_script() {
+            if !next_eq(script) {
+                return false;
+            }
+        }
+        if let Some(region) = self.get_region() {
+            if !next_eq(region) {
+                return false;
+            }
+        }
+
+        // The parser sorts and deduplicates the variants, so each of them
+        // has to be present in any order, possibly more than once.
+        let variants = self.variants.as_deref().unwrap_or(&[]);
+        let mut matched: u64 = 0;
+        for subtag in subtags {
+            match variants
+                .iter()
+                .position(|variant| variant.eq_ignore_ascii_case(subtag))
+            {
+                Some(idx) if idx < 64 => matched |= 1 << idx,
+                _ => return false,
+            }
+        }
+        matched.count_ones() as usize == variants.len()
+    }
+
+    /// Compares two `LanguageIdentifier`s regardless of the order of their variants.
+    ///
+    /// `PartialEq` compares the variants in their stored order, which only differs
+    /// from the canonical order for identifiers parsed with the `preserve_variant_order`
+    /// option or constructed from raw parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let options = ParserOptions {
+    ///     preserve_variant_order: true,
+    ///     ..ParserOptions::default()
+    /// };
+    ///
+    /// let li1 = LanguageIdentifier::from_str_with("sl-rozaj-biske-1994", &options)
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "sl-rozaj-biske-1994".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.to_string(), "sl-rozaj-biske-1994");
+    /// assert_eq!(li2.to_string(), "sl-1994-biske-rozaj");
+    ///
+    /// assert_ne!(li1, li2);
+    /// assert!(li1.eq_canonical(&li2));
+    /// ```
+    pub fn eq_canonical<O: AsRef<Self>>(&self, other: &O) -> bool {
+        let other = other.as_ref();
+        let variants = self.variants.as_deref().unwrap_or(&[]);
+        let other_variants = other.variants.as_deref().unwrap_or(&[]);
+        self.language == other.language
+            && self.script == other.script
+            && self.region == other.region
+            && variants.len() == other_variants.len()
+            && variants.iter().all(|v| other_variants.contains(v))
+    }
+
+    /// Returns `true` if both `LanguageIdentifier`s share the same language,
+    /// ignoring script, region and variants.
+    ///
+    /// With the `likelysubtags-add` feature, an undetermined language is first
+    /// replaced with the likely language, so `und-TW` speaks the same language as `zh`.
+    /// Otherwise, an undetermined language never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "zh-TW".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "zh-Hans".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "ja".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.speaks_same_language(&li2), true);
+    /// assert_eq!(li1.speaks_same_language(&li3), false);
+    /// ```
+    pub fn speaks_same_language<O: AsRef<Self>>(&self, other: &O) -> bool {
+        match (self.likely_language(), other.as_ref().likely_language()) {
+            (Some(lang), Some(other_lang)) => lang == other_lang,
+            _ => false,
+        }
+    }
+
+    fn likely_language(&self) -> Option<TinyStr8> {
+        #[cfg(feature = "likelysubtags-add")]
+        {
+            if self.language.is_none() {
+                return likelysubtags::add_likely_subtags(None, self.script, self.region)
+                    .and_then(|(lang, _, _)| lang);
+            }
+        }
+        self.language
+    }
+
+    /// Returns `true` if all subtags defined in the `LanguageIdentifier` are also present
+    /// in `other`, meaning that `other` is the same or a more specific identifier.
+    ///
+    /// Missing language, script and region act as wildcards, and variants
+    /// have to be a subset of the variants of `other`.
+    ///
+    /// This is a building block for fallback trees. Unlike `matches`, variants
+    /// are compared as sets, so `en-macos` subsumes `en-macos-nedis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let en: LanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    /// let en_latn: LanguageIdentifier = "en-Latn".parse()
+    ///     .expect("Parsing failed.");
+    /// let en_latn_gb: LanguageIdentifier = "en-Latn-GB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(en.subsumes(&en_latn_gb), true);
+    /// assert_eq!(en_latn.subsumes(&en_latn_gb), true);
+    /// assert_eq!(en_latn_gb.subsumes(&en_latn), false);
+    /// ```
+    pub fn subsumes<O: AsRef<Self>>(&self, other: &O) -> bool {
+        let other = other.as_ref();
+        let other_variants = other.variants.as_ref().map(|v| &v[..]).unwrap_or(&[]);
+
+        subtag_matches(&self.language, &other.language, true, false)
+            && subtag_matches(&self.script, &other.script, true, false)
+            && subtag_matches(&self.region, &other.region, true, false)
+            && self
+                .variants
+                .iter()
+                .flat_map(|v| v.iter())
+                .all(|v| other_variants.contains(v))
+    }
+
+    /// Returns the most specific `LanguageIdentifier` shared by both identifiers.
+    ///
+    /// The result keeps all subtags which are the same on both sides and the variants
+    /// present on both sides, so it always [`subsumes`](#method.subsumes) both of them.
+    ///
+    /// This is useful for collapsing lists of locales and building shared fallback nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "en-Latn-GB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let ancestor = li1.common_ancestor(&li2);
+    ///
+    /// assert_eq!(ancestor.to_string(), "en-Latn");
+    /// assert_eq!(ancestor.subsumes(&li1), true);
+    /// assert_eq!(ancestor.subsumes(&li2), true);
+    /// ```
+    pub fn common_ancestor<O: AsRef<Self>>(&self, other: &O) -> Self {
+        let other = other.as_ref();
+        let other_variants = other.variants.as_ref().map(|v| &v[..]).unwrap_or(&[]);
+
+        let variants: Vec<TinyStr8> = self
+            .variants
+            .iter()
+            .flat_map(|v| v.iter())
+            .filter(|v| other_variants.contains(v))
+            .cloned()
+            .collect();
+
+        Self {
+            language: common_subtag(self.language, other.language),
+            script: common_subtag(self.script, other.script),
+            region: common_subtag(self.region, other.region),
+            variants: if variants.is_empty() {
+                None
+            } else {
+                Some(variants.into_boxed_slice())
+            },
+        }
+    }
+
+    /// Copies script, region and variants from `other` where `self` has none.
+    ///
+    /// The language is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "sr".parse()
+    ///     .expect("Parsing failed.");
+    /// let default: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// li.fill_missing_from(&default);
+    /// assert_eq!(li.to_string(), "sr-Cyrl-RS");
+    /// ```
+    pub fn fill_missing_from<O: AsRef<Self>>(&mut self, other: &O) {
+        let other = other.as_ref();
+        if self.script.is_none() {
+            self.script = other.script;
+        }
+        if self.region.is_none() {
+            self.region = other.region;
+        }
+        if is_option_empty(&self.variants) {
+            self.variants = other.variants.clone();
+        }
+    }
+
+    /// Reports which subtags differ between the `LanguageIdentifier` and another one.
+    ///
+    /// This is useful for error messages and debugging of language negotiation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "sr-Latn-RS".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let diff = li1.diff(&li2);
+    ///
+    /// assert_eq!(diff.script, Some((Some("Latn"), Some("Cyrl"))));
+    /// assert_eq!(diff.region, None);
+    /// assert_eq!(diff.to_string(), "script mismatch: Latn vs Cyrl");
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Self) -> SubtagDiff<'a> {
+        SubtagDiff {
+            language: if self.language != other.language {
+                Some((self.get_language(), other.get_language()))
+            } else {
+                None
+            },
+            script: if self.script != other.script {
+                Some((self.get_script(), other.get_script()))
+            } else {
+                None
+            },
+            region: if self.region != other.region {
+                Some((self.get_region(), other.get_region()))
+            } else {
+                None
+            },
+            variants: if self.get_variants() != other.get_variants() {
+                Some((self.get_variants(), other.get_variants()))
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Describes how closely another `LanguageIdentifier` matches this one.
+    ///
+    /// This allows fallback UIs to explain which part of a requested identifier
+    /// could not be satisfied. Missing subtags only match missing subtags, and
+    /// differences in variants only are reported as `MatchQuality::RegionDiffers`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, MatchQuality};
+    ///
+    /// let li: LanguageIdentifier = "en-CA".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let en_ca: LanguageIdentifier = "en-CA".parse()
+    ///     .expect("Parsing failed.");
+    /// let en_gb: LanguageIdentifier = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    /// let fr_ca: LanguageIdentifier = "fr-CA".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.match_quality(&en_ca), MatchQuality::Exact);
+    /// assert_eq!(li.match_quality(&en_gb), MatchQuality::RegionDiffers);
+    /// assert_eq!(li.match_quality(&fr_ca), MatchQuality::None);
+    /// ```
+    pub fn match_quality<O: AsRef<Self>>(&self, other: &O) -> MatchQuality {
+        let other = other.as_ref();
+        if self.language != other.language {
+            return MatchQuality::None;
+        }
+        let same_script = self.script == other.script;
+        let same_region = self.region == other.region
+            && self.variants.as_deref().unwrap_or(&[]) == other.variants.as_deref().unwrap_or(&[]);
+        match (same_script, same_region) {
+            (true, true) => MatchQuality::Exact,
+            (true, false) => MatchQuality::RegionDiffers,
+            (false, true) => MatchQuality::ScriptDiffers,
+            (false, false) => MatchQuality::LanguageOnly,
+        }
+    }
+
+    /// Returns the serialized `LanguageIdentifier`, borrowing it when possible.
+    ///
+    /// Identifiers consisting of the language subtag only, such as `en`,
+    /// are serialized without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(matches!(li1.to_cow(), Cow::Borrowed("en")));
+    /// assert_eq!(li2.to_cow(), "en-US");
+    /// ```
+    pub fn to_cow(&self) -> Cow<'_, str> {
+        if self.script.is_none() && self.region.is_none() && is_option_empty(&self.variants) {
+            Cow::Borrowed(self.get_language())
+        } else {
+            Cow::Owned(self.to_string())
+        }
+    }
+
+    /// Returns the length in bytes of the serialized `LanguageIdentifier`.
+    ///
+    /// The length is computed from the subtags, so it can be used to
+    /// preallocate a buffer before serializing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.byte_len(), 17);
+    /// assert_eq!(li.byte_len(), li.to_string().len());
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.get_language().len()
+            + self.script.map_or(0, |script| script.len() + 1)
+            + self.region.map_or(0, |region| region.len() + 1)
+            + self
+                .variants
+                .iter()
+                .flat_map(|variants| variants.iter())
+                .map(|variant| variant.len() + 1)
+                .sum::<usize>()
+    }
+
+    /// Returns a hash of the `LanguageIdentifier` which is stable across
+    /// platforms, processes and versions of this crate.
+    ///
+    /// Unlike the output of `std::hash::Hash`, it can be persisted and used
+    /// as a shard or cache key shared between machines.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the UTF-8 bytes of the serialized,
+    /// case-normalized subtags, so `EN_us` and `en-US` hash the same.
+    /// Variants are hashed in their canonical, sorted order, so identifiers
+    /// which are `eq_canonical` hash the same.
+    /// Aliases are not replaced, so `iw` and `he` hash differently, and
+    /// updates of the alias data do not change the hash.
+    /// This algorithm will not change in future versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "EN_us".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.stable_hash(), 14676792195390135641);
+    /// assert_eq!(li1.stable_hash(), li2.stable_hash());
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        struct Fnv1a(u64);
+
+        impl std::fmt::Write for Fnv1a {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                for byte in s.bytes() {
+                    self.0 ^= u64::from(byte);
+                    self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+                }
+                Ok(())
+            }
+        }
+
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        let variants = self.variants.as_deref().unwrap_or(&[]);
+        if variants.windows(2).all(|pair| pair[0] < pair[1]) {
+            self.write_subtags(&mut hasher)
+        } else {
+            // Variants parsed with `preserve_variant_order` are hashed in
+            // their canonical order, so that `eq_canonical` values hash the same.
+            let mut sorted = variants.to_vec();
+            sorted.sort();
+            Self {
+                variants: Some(sorted.into_boxed_slice()),
+                ..*self
+            }
+            .write_subtags(&mut hasher)
+        }
+        .expect("Hashing cannot fail.");
+        hasher.0
     }
 
     /// Returns the language subtag of the `LanguageIdentifier`.
@@ -460,6 +1305,65 @@ impl LanguageIdentifier {
         Ok(())
     }
 
+    /// Returns `true` if the `LanguageIdentifier` is a pseudo-locale used for testing.
+    ///
+    /// Recognizes the CLDR pseudo-locale regions `XA` (accented), `XB` (bidi) and
+    /// `XC` (long), such as `en-XA` or `ar-XB`, and the Windows `qps` pseudo-language,
+    /// such as `qps-ploc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "en-XA".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.is_pseudo_locale(), true);
+    /// assert_eq!(li2.is_pseudo_locale(), false);
+    /// ```
+    pub fn is_pseudo_locale(&self) -> bool {
+        matches!(self.get_region(), Some("XA") | Some("XB") | Some("XC"))
+            || self.get_language() == "qps"
+    }
+
+    /// Returns `true` if CLDR provides locale data with the given coverage
+    /// for the `LanguageIdentifier`.
+    ///
+    /// With the `likelysubtags-remove` feature, locales which CLDR covers through
+    /// their default content, such as `en-US` covered by `en`, are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{CldrCoverage, LanguageIdentifier};
+    ///
+    /// let li1: LanguageIdentifier = "fr-CA".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "tlh".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.is_cldr_locale(CldrCoverage::Modern), true);
+    /// assert_eq!(li2.is_cldr_locale(CldrCoverage::Modern), false);
+    /// ```
+    #[cfg(feature = "available-locales")]
+    pub fn is_cldr_locale(&self, coverage: CldrCoverage) -> bool {
+        let locales = coverage.locales();
+        if locales.binary_search(&self.to_string().as_str()).is_ok() {
+            return true;
+        }
+        #[cfg(feature = "likelysubtags-remove")]
+        {
+            let mut li = self.clone();
+            if li.remove_likely_subtags() && &li != self {
+                return locales.binary_search(&li.to_string().as_str()).is_ok();
+            }
+        }
+        false
+    }
+
     /// Extends the `LanguageIdentifier` adding likely subtags based
     /// on tables provided by CLDR.
     ///
@@ -474,7 +1378,7 @@ impl LanguageIdentifier {
     /// assert_eq!(li.add_likely_subtags(), true);
     /// assert_eq!(li.to_string(), "en-Latn-US");
     /// ```
-    #[cfg(feature = "likelysubtags")]
+    #[cfg(feature = "likelysubtags-add")]
     pub fn add_likely_subtags(&mut self) -> bool {
         if let Some(new_li) =
             likelysubtags::add_likely_subtags(self.language, self.script, self.region)
@@ -488,6 +1392,98 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Extends the `LanguageIdentifier` adding likely subtags, reusing
+    /// the results remembered by a `MaximizeCache`.
+    ///
+    /// The result is the same as the one of `add_likely_subtags`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::cache::MaximizeCache;
+    ///
+    /// let cache = MaximizeCache::new(32);
+    ///
+    /// let mut li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.add_likely_subtags_cached(&cache), true);
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags-add")]
+    pub fn add_likely_subtags_cached(
+        &mut self,
+        cache: &likelysubtags::cache::MaximizeCache,
+    ) -> bool {
+        if let Some(new_li) = cache.add_likely_subtags((self.language, self.script, self.region)) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the script of the `LanguageIdentifier`, or its likely script
+    /// based on tables provided by CLDR if the script is not specified.
+    ///
+    /// Unlike `add_likely_subtags`, this neither clones nor modifies the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "sr-ME".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "zh-TW".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.likely_script().as_deref(), Some("Latn"));
+    /// assert_eq!(li2.likely_script().as_deref(), Some("Hant"));
+    /// assert_eq!(li2.to_string(), "zh-TW");
+    /// ```
+    #[cfg(feature = "likelysubtags-add")]
+    pub fn likely_script(&self) -> Option<TinyStr4> {
+        if self.script.is_some() {
+            return self.script;
+        }
+        let (_, script, _) = likelysubtags::add_likely_subtags(self.language, None, self.region)?;
+        script
+    }
+
+    /// Returns the region of the `LanguageIdentifier`, or its likely region
+    /// based on tables provided by CLDR if the region is not specified.
+    ///
+    /// Unlike `add_likely_subtags`, this neither clones nor modifies the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "de".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "es-419".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "eo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.likely_region().as_deref(), Some("DE"));
+    /// assert_eq!(li2.likely_region().as_deref(), Some("419"));
+    /// assert_eq!(li3.likely_region().as_deref(), Some("001"));
+    /// ```
+    #[cfg(feature = "likelysubtags-add")]
+    pub fn likely_region(&self) -> Option<TinyStr4> {
+        if self.region.is_some() {
+            return self.region;
+        }
+        let (_, _, region) = likelysubtags::add_likely_subtags(self.language, self.script, None)?;
+        region
+    }
+
     /// Extends the `LanguageIdentifier` removing likely subtags based
     /// on tables provided by CLDR.
     ///
@@ -502,7 +1498,7 @@ impl LanguageIdentifier {
     /// assert_eq!(li.remove_likely_subtags(), true);
     /// assert_eq!(li.to_string(), "en");
     /// ```
-    #[cfg(feature = "likelysubtags")]
+    #[cfg(feature = "likelysubtags-remove")]
     pub fn remove_likely_subtags(&mut self) -> bool {
         if let Some(new_li) =
             likelysubtags::remove_likely_subtags(self.language, self.script, self.region)
@@ -516,8 +1512,97 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Extends the `LanguageIdentifier` adding likely subtags based
+    /// on the data loaded at runtime.
+    ///
+    /// See [`LikelySubtagsData`](likelysubtags/runtime/struct.LikelySubtagsData.html)
+    /// for how to load the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::runtime::LikelySubtagsData;
+    ///
+    /// let bytes = std::fs::read("./data/likelySubtags.bin")
+    ///     .expect("Reading data failed.");
+    /// let data = LikelySubtagsData::from_bytes(&bytes)
+    ///     .expect("Loading data failed.");
+    ///
+    /// let mut li: LanguageIdentifier = "sr".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.add_likely_subtags_with(&data), true);
+    /// assert_eq!(li.to_string(), "sr-Cyrl-RS");
+    /// ```
+    #[cfg(feature = "binary-data")]
+    pub fn add_likely_subtags_with(
+        &mut self,
+        data: &likelysubtags::runtime::LikelySubtagsData,
+    ) -> bool {
+        if let Some(new_li) = likelysubtags::add_likely_subtags_from(
+            &data.tables(),
+            self.language,
+            self.script,
+            self.region,
+        ) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on the data loaded at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::runtime::LikelySubtagsData;
+    ///
+    /// let bytes = std::fs::read("./data/likelySubtags.bin")
+    ///     .expect("Reading data failed.");
+    /// let data = LikelySubtagsData::from_bytes(&bytes)
+    ///     .expect("Loading data failed.");
+    ///
+    /// let mut li: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.remove_likely_subtags_with(&data), true);
+    /// assert_eq!(li.to_string(), "sr");
+    /// ```
+    #[cfg(feature = "binary-data")]
+    pub fn remove_likely_subtags_with(
+        &mut self,
+        data: &likelysubtags::runtime::LikelySubtagsData,
+    ) -> bool {
+        if let Some(new_li) = likelysubtags::remove_likely_subtags_from(
+            &data.tables(),
+            self.language,
+            self.script,
+            self.region,
+        ) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Returns character direction of the `LanguageIdentifier`.
     ///
+    /// This method is only available with `feature = "layout"`, which is enabled by default.
+    ///
+    /// If the language is not specified and `feature = "likelysubtags-add"` is enabled,
+    /// the likely language for the script and region is used, so `und-Arab` is RTL.
+    /// Otherwise the direction defaults to LTR.
+    ///
     /// # Examples
     ///
     /// ```
@@ -531,14 +1616,90 @@ impl LanguageIdentifier {
     /// assert_eq!(li1.get_character_direction(), CharacterDirection::LTR);
     /// assert_eq!(li2.get_character_direction(), CharacterDirection::RTL);
     /// ```
+    #[cfg(feature = "layout")]
     pub fn get_character_direction(&self) -> CharacterDirection {
-        match self.language {
+        match self.likely_language() {
             Some(lang) if CHARACTER_DIRECTION_RTL.contains(&(lang.into())) => {
                 CharacterDirection::RTL
             }
             _ => CharacterDirection::LTR,
         }
     }
+
+    /// Returns character direction of the `LanguageIdentifier`, or `None` if it is unknown.
+    ///
+    /// This method is only available with `feature = "layout"`, which is enabled by default.
+    ///
+    /// Unlike `get_character_direction`, which defaults to LTR, this method only returns
+    /// a direction for languages with layout data in CLDR. This allows applications
+    /// to fall back on the direction of the UI locale instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, CharacterDirection};
+    ///
+    /// let li1: LanguageIdentifier = "es-AR".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "fa".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "tlh".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.try_get_character_direction(), Some(CharacterDirection::LTR));
+    /// assert_eq!(li2.try_get_character_direction(), Some(CharacterDirection::RTL));
+    /// assert_eq!(li3.try_get_character_direction(), None);
+    /// ```
+    #[cfg(feature = "layout")]
+    pub fn try_get_character_direction(&self) -> Option<CharacterDirection> {
+        let lang: u64 = self.likely_language()?.into();
+        if CHARACTER_DIRECTION_RTL.contains(&lang) {
+            Some(CharacterDirection::RTL)
+        } else if CHARACTER_DIRECTION_LTR.binary_search(&lang).is_ok() {
+            Some(CharacterDirection::LTR)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the line orientation of the script of the `LanguageIdentifier`.
+    ///
+    /// This method is only available with `feature = "layout"`, which is enabled by default.
+    ///
+    /// If the script is not specified and `feature = "likelysubtags-add"` is enabled,
+    /// the likely script is used. Otherwise the orientation defaults to horizontal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, LineOrientation};
+    ///
+    /// let li1: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "ja-Jpan".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "mn-Mong".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.get_line_orientation(), LineOrientation::Horizontal);
+    /// assert_eq!(li2.get_line_orientation(), LineOrientation::Mixed);
+    /// assert_eq!(li3.get_line_orientation(), LineOrientation::Vertical);
+    /// ```
+    #[cfg(feature = "layout")]
+    pub fn get_line_orientation(&self) -> LineOrientation {
+        #[cfg(feature = "likelysubtags-add")]
+        let script = self.likely_script();
+        #[cfg(not(feature = "likelysubtags-add"))]
+        let script = self.script;
+
+        match script.map(Into::<u32>::into) {
+            Some(script) if LINE_ORIENTATION_VERTICAL.contains(&script) => {
+                LineOrientation::Vertical
+            }
+            Some(script) if LINE_ORIENTATION_MIXED.contains(&script) => LineOrientation::Mixed,
+            _ => LineOrientation::Horizontal,
+        }
+    }
 }
 
 impl FromStr for LanguageIdentifier {
@@ -549,6 +1710,18 @@ impl FromStr for LanguageIdentifier {
     }
 }
 
+impl From<&LanguageIdentifier> for String {
+    fn from(langid: &LanguageIdentifier) -> Self {
+        langid.to_string()
+    }
+}
+
+impl From<LanguageIdentifier> for String {
+    fn from(langid: LanguageIdentifier) -> Self {
+        langid.to_string()
+    }
+}
+
 impl AsRef<LanguageIdentifier> for LanguageIdentifier {
     #[inline(always)]
     fn as_ref(&self) -> &LanguageIdentifier {
@@ -556,22 +1729,57 @@ impl AsRef<LanguageIdentifier> for LanguageIdentifier {
     }
 }
 
+/// Formats the `LanguageIdentifier`.
+///
+/// With the alternate flag (`{:#}`), an empty identifier is formatted
+/// as the CLDR `root` instead of `und`.
+///
+/// Width, alignment and fill flags are respected.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let li: LanguageIdentifier = "root".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.to_string(), "und");
+/// assert_eq!(format!("{:#}", li), "root");
+/// assert_eq!(format!("[{:>6}]", li), "[   und]");
+/// ```
 impl std::fmt::Display for LanguageIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut subtags = vec![self.get_language()];
+        if f.alternate() && *self == Self::UND {
+            return f.pad("root");
+        }
+
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_subtags(f);
+        }
+
+        let mut s = String::with_capacity(self.byte_len());
+        self.write_subtags(&mut s)?;
+        f.pad(&s)
+    }
+}
+
+impl LanguageIdentifier {
+    fn write_subtags<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.get_language())?;
         if let Some(script) = self.get_script() {
-            subtags.push(script);
+            w.write_char('-')?;
+            w.write_str(script)?;
         }
         if let Some(region) = self.get_region() {
-            subtags.push(region);
+            w.write_char('-')?;
+            w.write_str(region)?;
         }
-        if let Some(variants) = &self.variants {
-            for variant in variants.iter() {
-                subtags.push(variant);
-            }
+        for variant in self.variants.iter().flat_map(|variants| variants.iter()) {
+            w.write_char('-')?;
+            w.write_str(variant)?;
         }
-
-        f.write_str(&subtags.join("-"))
+        Ok(())
     }
 }
 
@@ -584,6 +1792,14 @@ fn subtag_matches<P: PartialEq>(
     (as_range1 && subtag1.is_none()) || (as_range2 && subtag2.is_none()) || subtag1 == subtag2
 }
 
+fn common_subtag<P: PartialEq>(subtag1: Option<P>, subtag2: Option<P>) -> Option<P> {
+    if subtag1 == subtag2 {
+        subtag1
+    } else {
+        None
+    }
+}
+
 fn is_option_empty<P: PartialEq>(subtag: &Option<Box<[P]>>) -> bool {
     subtag.as_ref().map(|t| t.is_empty()).unwrap_or(true)
 }
@@ -602,8 +1818,10 @@ fn subtags_match<P: PartialEq>(
 
 /// This is a best-effort operation that performs all available levels of canonicalization.
 ///
-/// At the moment the operation will normalize casing and the separator, but in the future
-/// it may also validate and update from deprecated subtags to canonical ones.
+/// At the moment the operation will normalize casing and the separator, replace
+/// three letter ISO 639 language codes with their ISO 639-1 equivalents and replace
+/// deprecated script codes, but in the future it may also validate and update
+/// from other deprecated subtags to canonical ones.
 ///
 /// # Examples
 ///
@@ -611,8 +1829,107 @@ fn subtags_match<P: PartialEq>(
 /// use unic_langid_impl::canonicalize;
 ///
 /// assert_eq!(canonicalize("pL_latn_pl"), Ok("pl-Latn-PL".to_string()));
+/// assert_eq!(canonicalize("ger-DE"), Ok("de-DE".to_string()));
+/// assert_eq!(canonicalize("und-Qaai"), Ok("und-Zinh".to_string()));
 /// ```
 pub fn canonicalize(input: &str) -> Result<String, LanguageIdentifierError> {
-    let lang_id: LanguageIdentifier = input.parse()?;
+    let lang_id = CanonicalLanguageIdentifier::canonicalize(input)?;
     Ok(lang_id.to_string())
 }
+
+/// Canonicalizes the input like `canonicalize`, borrowing the input if it is
+/// already canonical.
+///
+/// Normalizing a large amount of mostly canonical data this way allocates
+/// only for the inputs which actually change.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use unic_langid_impl::canonicalize_cow;
+///
+/// assert!(matches!(canonicalize_cow("pl-Latn-PL"), Ok(Cow::Borrowed("pl-Latn-PL"))));
+/// assert_eq!(canonicalize_cow("pL_latn_pl"), Ok(Cow::Owned("pl-Latn-PL".to_string())));
+/// assert!(canonicalize_cow("e-US").is_err());
+/// ```
+pub fn canonicalize_cow(input: &str) -> Result<Cow<'_, str>, LanguageIdentifierError> {
+    let lang_id = CanonicalLanguageIdentifier::canonicalize(input)?;
+    let mut matcher = StrMatcher(input);
+    if lang_id.write_subtags(&mut matcher).is_ok() && matcher.0.is_empty() {
+        Ok(Cow::Borrowed(input))
+    } else {
+        Ok(Cow::Owned(lang_id.to_string()))
+    }
+}
+
+/// A writer which only accepts output equal to the remainder of the string.
+struct StrMatcher<'a>(&'a str);
+
+impl std::fmt::Write for StrMatcher<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(std::fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Parses a list of language identifiers separated by commas, semicolons,
+/// colons or whitespace.
+///
+/// This covers lists such as the `LANGUAGE` environment variable (`de:en_GB:en`)
+/// or the `Accept-Language` header. Parameters such as quality values (`q=0.8`)
+/// and the `*` wildcard are skipped, and the order of the input is kept.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::parse_list;
+///
+/// let list = parse_list("de:en_GB:en")
+///     .expect("Parsing failed.");
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list[1].to_string(), "en-GB");
+///
+/// let list = parse_list("fr-CH, fr;q=0.9, *;q=0.5")
+///     .expect("Parsing failed.");
+/// assert_eq!(list.len(), 2);
+///
+/// assert!(parse_list("en, $$").is_err());
+/// ```
+pub fn parse_list(input: &str) -> Result<Vec<LanguageIdentifier>, LanguageIdentifierError> {
+    input
+        .split(|c: char| c == ',' || c == ';' || c == ':' || c.is_ascii_whitespace())
+        .filter(|entry| !entry.is_empty() && *entry != "*" && !entry.contains('='))
+        .map(str::parse)
+        .collect()
+}
+
+/// Returns the most likely language, with its script, for the region based
+/// on tables provided by CLDR.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::likely_language_for_region;
+///
+/// let li = likely_language_for_region("DE")
+///     .expect("Unknown region.");
+/// assert_eq!(li.to_string(), "de-Latn-DE");
+///
+/// let li = likely_language_for_region("tw")
+///     .expect("Unknown region.");
+/// assert_eq!(li.to_string(), "zh-Hant-TW");
+///
+/// assert_eq!(likely_language_for_region("Deutschland"), None);
+/// ```
+#[cfg(feature = "likelysubtags-add")]
+pub fn likely_language_for_region(region: &str) -> Option<LanguageIdentifier> {
+    let region = subtags::parse_region_subtag(region).ok()?;
+    let (language, script, region) = likelysubtags::add_likely_subtags(None, None, Some(region))?;
+    Some(LanguageIdentifier {
+        language,
+        script,
+        region,
+        variants: None,
+    })
+}
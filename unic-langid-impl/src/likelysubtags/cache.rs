@@ -0,0 +1,80 @@
+use std::sync::Mutex;
+use tinystr::{TinyStr4, TinyStr8};
+
+type Subtags = (Option<TinyStr8>, Option<TinyStr4>, Option<TinyStr4>);
+
+/// A small least recently used cache in front of `add_likely_subtags`.
+///
+/// Language negotiation tends to maximize the same few dozen identifiers over
+/// and over. The cache remembers the results for the most recently maximized
+/// subtags, up to `capacity` entries, and can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::likelysubtags::cache::MaximizeCache;
+///
+/// let cache = MaximizeCache::new(32);
+///
+/// let mut li: LanguageIdentifier = "sr-ME".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.add_likely_subtags_cached(&cache), true);
+/// assert_eq!(li.to_string(), "sr-Latn-ME");
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct MaximizeCache {
+    capacity: usize,
+    // Ordered from the least to the most recently used.
+    entries: Mutex<Vec<(Subtags, Option<Subtags>)>>,
+}
+
+impl MaximizeCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    pub(crate) fn add_likely_subtags(&self, subtags: Subtags) -> Option<Subtags> {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return super::add_likely_subtags(subtags.0, subtags.1, subtags.2),
+        };
+
+        if let Some(idx) = entries.iter().position(|(key, _)| *key == subtags) {
+            let entry = entries.remove(idx);
+            let result = entry.1;
+            entries.push(entry);
+            return result;
+        }
+
+        let result = super::add_likely_subtags(subtags.0, subtags.1, subtags.2);
+        if self.capacity > 0 {
+            if entries.len() >= self.capacity {
+                entries.remove(0);
+            }
+            entries.push((subtags, result));
+        }
+        result
+    }
+}
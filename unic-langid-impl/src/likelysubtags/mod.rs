@@ -1,11 +1,48 @@
+//! Likely subtags data and algorithms.
+//!
+//! Minimizing is computed by maximizing against the same tables, so
+//! `feature = "likelysubtags-remove"` requires `feature = "likelysubtags-add"`.
+//! An application which only adds likely subtags can enable `feature = "likelysubtags-add"` alone.
+pub mod cache;
+#[cfg(feature = "binary-data")]
+pub mod runtime;
+#[cfg(not(unic_langid_cldr_build))]
 mod tables;
+#[cfg(unic_langid_cldr_build)]
+#[allow(clippy::type_complexity)]
+#[allow(clippy::unreadable_literal)]
+mod tables {
+    include!(concat!(env!("OUT_DIR"), "/likelysubtags_tables.rs"));
+}
 
 pub use tables::CLDR_VERSION;
 
 use tinystr::{TinyStr4, TinyStr8};
 
+type LangIdSubTags = (Option<u64>, Option<u32>, Option<u32>);
+
+/// A set of likely subtags tables, either embedded in the crate
+/// or loaded at runtime.
+pub(crate) struct LikelySubtagsTables<'a> {
+    pub lang_only: &'a [(u64, LangIdSubTags)],
+    pub lang_region: &'a [(u64, u32, LangIdSubTags)],
+    pub lang_script: &'a [(u64, u32, LangIdSubTags)],
+    pub script_region: &'a [(u32, u32, LangIdSubTags)],
+    pub script_only: &'a [(u32, LangIdSubTags)],
+    pub region_only: &'a [(u32, LangIdSubTags)],
+}
+
+const EMBEDDED_TABLES: LikelySubtagsTables<'static> = LikelySubtagsTables {
+    lang_only: tables::LANG_ONLY,
+    lang_region: &tables::LANG_REGION,
+    lang_script: &tables::LANG_SCRIPT,
+    script_region: &tables::SCRIPT_REGION,
+    script_only: &tables::SCRIPT_ONLY,
+    region_only: &tables::REGION_ONLY,
+};
+
 fn get_lang_from_parts(
-    input: (Option<u64>, Option<u32>, Option<u32>),
+    input: LangIdSubTags,
     lang: Option<TinyStr8>,
     script: Option<TinyStr4>,
     region: Option<TinyStr4>,
@@ -20,6 +57,15 @@ pub fn add_likely_subtags(
     lang: Option<TinyStr8>,
     script: Option<TinyStr4>,
     region: Option<TinyStr4>,
+) -> Option<(Option<TinyStr8>, Option<TinyStr4>, Option<TinyStr4>)> {
+    add_likely_subtags_from(&EMBEDDED_TABLES, lang, script, region)
+}
+
+pub(crate) fn add_likely_subtags_from(
+    data: &LikelySubtagsTables,
+    lang: Option<TinyStr8>,
+    script: Option<TinyStr4>,
+    region: Option<TinyStr4>,
 ) -> Option<(Option<TinyStr8>, Option<TinyStr4>, Option<TinyStr4>)> {
     if lang.is_some() && script.is_some() && region.is_some() {
         return None;
@@ -27,78 +73,100 @@ pub fn add_likely_subtags(
 
     if let Some(l) = lang {
         if let Some(r) = region {
-            let result = tables::LANG_REGION
+            let result = data
+                .lang_region
                 .binary_search_by(|(key_l, key_r, _)| {
                     key_l.cmp(&l.into()).then(key_r.cmp(&r.into()))
                 })
                 .ok();
             if let Some(r) = result {
-                return get_lang_from_parts(tables::LANG_REGION[r].2, None, None, None);
+                return get_lang_from_parts(data.lang_region[r].2, None, None, None);
             }
         }
 
         if let Some(s) = script {
-            let result = tables::LANG_SCRIPT
+            let result = data
+                .lang_script
                 .binary_search_by(|(key_l, key_s, _)| {
                     key_l.cmp(&l.into()).then(key_s.cmp(&s.into()))
                 })
                 .ok();
             if let Some(r) = result {
-                return get_lang_from_parts(tables::LANG_SCRIPT[r].2, None, None, None);
+                return get_lang_from_parts(data.lang_script[r].2, None, None, None);
             }
         }
 
-        let result = tables::LANG_ONLY
+        let result = data
+            .lang_only
             .binary_search_by(|(key_l, _)| key_l.cmp(&l.into()))
             .ok();
         if let Some(r) = result {
-            return get_lang_from_parts(tables::LANG_ONLY[r].1, None, script, region);
+            return get_lang_from_parts(data.lang_only[r].1, None, script, region);
         }
     } else if let Some(s) = script {
         if let Some(r) = region {
-            let result = tables::SCRIPT_REGION
+            let result = data
+                .script_region
                 .binary_search_by(|(key_s, key_r, _)| {
                     key_s.cmp(&s.into()).then(key_r.cmp(&r.into()))
                 })
                 .ok();
             if let Some(r) = result {
-                return get_lang_from_parts(tables::SCRIPT_REGION[r].2, None, None, None);
+                return get_lang_from_parts(data.script_region[r].2, None, None, None);
             }
         }
 
-        let result = tables::SCRIPT_ONLY
+        let result = data
+            .script_only
             .binary_search_by(|(key_s, _)| key_s.cmp(&s.into()))
             .ok();
         if let Some(r) = result {
-            return get_lang_from_parts(tables::SCRIPT_ONLY[r].1, None, None, region);
+            return get_lang_from_parts(data.script_only[r].1, None, None, region);
         }
     } else if let Some(r) = region {
-        let result = tables::REGION_ONLY
+        let result = data
+            .region_only
             .binary_search_by(|(key_r, _)| key_r.cmp(&r.into()))
             .ok();
         if let Some(r) = result {
-            return get_lang_from_parts(tables::REGION_ONLY[r].1, None, None, None);
+            return get_lang_from_parts(data.region_only[r].1, None, None, None);
         }
     }
 
     None
 }
 
+#[cfg(feature = "likelysubtags-remove")]
 pub fn remove_likely_subtags(
     lang: Option<TinyStr8>,
     script: Option<TinyStr4>,
     region: Option<TinyStr4>,
 ) -> Option<(Option<TinyStr8>, Option<TinyStr4>, Option<TinyStr4>)> {
-    let max_langid = add_likely_subtags(lang, script, region)?;
+    remove_likely_subtags_from(&EMBEDDED_TABLES, lang, script, region)
+}
+
+#[cfg(feature = "likelysubtags-remove")]
+pub(crate) fn remove_likely_subtags_from(
+    data: &LikelySubtagsTables,
+    lang: Option<TinyStr8>,
+    script: Option<TinyStr4>,
+    region: Option<TinyStr4>,
+) -> Option<(Option<TinyStr8>, Option<TinyStr4>, Option<TinyStr4>)> {
+    // A fully specified identifier is already maximized.
+    let max_langid = if lang.is_some() && script.is_some() && region.is_some() {
+        (lang, script, region)
+    } else {
+        add_likely_subtags_from(data, lang, script, region)?
+    };
 
-    if let Some(trial) = add_likely_subtags(max_langid.0, None, None) {
+    if let Some(trial) = add_likely_subtags_from(data, max_langid.0, None, None) {
         if trial == max_langid {
             return Some((max_langid.0, None, None));
         }
     }
 
     if max_langid.2.is_some() {
-        if let Some(trial) = add_likely_subtags(max_langid.0, None, max_langid.2) {
+        if let Some(trial) = add_likely_subtags_from(data, max_langid.0, None, max_langid.2) {
             if trial == max_langid {
                 return Some((max_langid.0, None, max_langid.2));
             }
@@ -106,7 +174,7 @@ pub fn remove_likely_subtags(
     }
 
     if max_langid.1.is_some() {
-        if let Some(trial) = add_likely_subtags(max_langid.0, max_langid.1, None) {
+        if let Some(trial) = add_likely_subtags_from(data, max_langid.0, max_langid.1, None) {
             if trial == max_langid {
                 return Some((max_langid.0, max_langid.1, None));
             }
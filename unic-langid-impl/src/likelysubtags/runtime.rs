@@ -0,0 +1,410 @@
+use super::{LangIdSubTags, LikelySubtagsTables};
+use crate::subtags;
+use crate::LanguageIdentifier;
+
+#[cfg(feature = "runtime-data")]
+use serde_json::Value;
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt::{self, Display};
+#[cfg(feature = "runtime-data")]
+use std::fs;
+#[cfg(feature = "runtime-data")]
+use std::io;
+#[cfg(feature = "runtime-data")]
+use std::path::Path;
+
+/// Magic bytes opening the binary data format.
+const BINARY_MAGIC: &[u8; 4] = b"ULSD";
+/// Version of the binary data format.
+const BINARY_FORMAT_VERSION: u8 = 1;
+
+/// Enum with errors that can be returned while loading CLDR data at runtime.
+#[derive(Debug)]
+pub enum DataError {
+    /// The data file could not be read.
+    #[cfg(feature = "runtime-data")]
+    Io(io::Error),
+    /// The data file is not a valid JSON.
+    #[cfg(feature = "runtime-data")]
+    Json(serde_json::Error),
+    /// The data does not follow the expected structure.
+    InvalidData(String),
+}
+
+#[cfg(feature = "runtime-data")]
+impl From<io::Error> for DataError {
+    fn from(error: io::Error) -> Self {
+        DataError::Io(error)
+    }
+}
+
+#[cfg(feature = "runtime-data")]
+impl From<serde_json::Error> for DataError {
+    fn from(error: serde_json::Error) -> Self {
+        DataError::Json(error)
+    }
+}
+
+impl Error for DataError {}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            #[cfg(feature = "runtime-data")]
+            DataError::Io(e) => write!(f, "IO error: {}", e),
+            #[cfg(feature = "runtime-data")]
+            DataError::Json(e) => write!(f, "JSON error: {}", e),
+            DataError::InvalidData(s) => write!(f, "Invalid data: {}", s),
+        }
+    }
+}
+
+/// Likely subtags data loaded at runtime.
+///
+/// The data can be loaded from the compact binary format, or, with `feature = "runtime-data"`,
+/// from a CLDR JSON file.
+///
+/// This allows long-lived applications to update the data without
+/// recompiling the binary.
+///
+/// # Binary format
+///
+/// The binary format is a little-endian sequence of:
+///
+///  * magic bytes `ULSD`,
+///  * format version as `u8`,
+///  * CLDR version as a `u8` length followed by UTF-8 bytes,
+///  * tables `lang_only`, `lang_region`, `lang_script`, `script_region`,
+///    `script_only` and `region_only`, each as a `u32` count followed by records.
+///
+/// Each record contains the key subtags (`u64` for language, `u32` for script and region)
+/// followed by the value subtags (`u64`, `u32`, `u32`), where `0` stands for a missing subtag.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::likelysubtags::runtime::LikelySubtagsData;
+///
+/// let bytes = std::fs::read("./data/likelySubtags.bin")
+///     .expect("Reading data failed.");
+/// let data = LikelySubtagsData::from_bytes(&bytes)
+///     .expect("Loading data failed.");
+///
+/// assert_eq!(data.cldr_version(), "35.1");
+///
+/// let mut li: LanguageIdentifier = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.add_likely_subtags_with(&data), true);
+/// assert_eq!(li.to_string(), "en-Latn-US");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct LikelySubtagsData {
+    version: String,
+    lang_only: Vec<(u64, LangIdSubTags)>,
+    lang_region: Vec<(u64, u32, LangIdSubTags)>,
+    lang_script: Vec<(u64, u32, LangIdSubTags)>,
+    script_region: Vec<(u32, u32, LangIdSubTags)>,
+    script_only: Vec<(u32, LangIdSubTags)>,
+    region_only: Vec<(u32, LangIdSubTags)>,
+}
+
+impl LikelySubtagsData {
+    /// Loads the data from a CLDR `likelySubtags.json` file.
+    #[cfg(feature = "runtime-data")]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, DataError> {
+        let contents = fs::read_to_string(path)?;
+        Self::from_json(&contents)
+    }
+
+    /// Loads the data from the contents of a CLDR `likelySubtags.json` file.
+    #[cfg(feature = "runtime-data")]
+    pub fn from_json(input: &str) -> Result<Self, DataError> {
+        let v: Value = serde_json::from_str(input)?;
+
+        let version = v["supplemental"]["version"]["_cldrVersion"]
+            .as_str()
+            .ok_or_else(|| DataError::InvalidData("Missing CLDR version".to_string()))?;
+        let values = v["supplemental"]["likelySubtags"]
+            .as_object()
+            .ok_or_else(|| DataError::InvalidData("Missing likelySubtags".to_string()))?;
+
+        let mut result = Self {
+            version: version.to_string(),
+            ..Self::default()
+        };
+
+        for (k, v) in values {
+            let key: LanguageIdentifier = k
+                .parse()
+                .map_err(|_| DataError::InvalidData(format!("Invalid key: {}", k)))?;
+            let mut value: LanguageIdentifier = v
+                .as_str()
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(|| DataError::InvalidData(format!("Invalid value for: {}", k)))?;
+            if let Some("ZZ") = value.get_region() {
+                value.region = None;
+            }
+            let val = (
+                value.language.map(Into::into),
+                value.script.map(Into::into),
+                value.region.map(Into::into),
+            );
+
+            match (key.language, key.script, key.region) {
+                (Some(l), None, None) => result.lang_only.push((l.into(), val)),
+                (Some(l), None, Some(r)) => result.lang_region.push((l.into(), r.into(), val)),
+                (Some(l), Some(s), None) => result.lang_script.push((l.into(), s.into(), val)),
+                (None, Some(s), Some(r)) => result.script_region.push((s.into(), r.into(), val)),
+                (None, Some(s), None) => result.script_only.push((s.into(), val)),
+                (None, None, Some(r)) => result.region_only.push((r.into(), val)),
+                // `und` on its own is never looked up.
+                (None, None, None) => {}
+                _ => return Err(DataError::InvalidData(format!("Unexpected key: {}", k))),
+            }
+        }
+
+        result.sort();
+        Ok(result)
+    }
+
+    /// Loads the data from the binary format.
+    ///
+    /// Every subtag in the input is validated, and `DataError::InvalidData`
+    /// is returned if any of them is malformed or not in canonical case.
+    ///
+    /// The input is usually either a memory-mapped file, or embedded
+    /// in the binary with `include_bytes!`:
+    ///
+    /// ``` ignore
+    /// use unic_langid_impl::likelysubtags::runtime::LikelySubtagsData;
+    ///
+    /// static DATA: &[u8] = include_bytes!("../data/likelySubtags.bin");
+    ///
+    /// let data = LikelySubtagsData::from_bytes(DATA)
+    ///     .expect("Loading data failed.");
+    /// ```
+    pub fn from_bytes(input: &[u8]) -> Result<Self, DataError> {
+        let mut reader = BinaryReader { input };
+
+        if reader.take(4)? != BINARY_MAGIC {
+            return Err(DataError::InvalidData("Invalid magic bytes".to_string()));
+        }
+        if reader.take(1)?[0] != BINARY_FORMAT_VERSION {
+            return Err(DataError::InvalidData(
+                "Unsupported binary format version".to_string(),
+            ));
+        }
+        let version_len = reader.take(1)?[0] as usize;
+        let version = std::str::from_utf8(reader.take(version_len)?)
+            .map_err(|_| DataError::InvalidData("Invalid CLDR version".to_string()))?;
+
+        let mut result = Self {
+            version: version.to_string(),
+            ..Self::default()
+        };
+
+        for _ in 0..reader.read_u32()? {
+            result
+                .lang_only
+                .push((reader.read_language()?, reader.read_value()?));
+        }
+        for _ in 0..reader.read_u32()? {
+            result.lang_region.push((
+                reader.read_language()?,
+                reader.read_region()?,
+                reader.read_value()?,
+            ));
+        }
+        for _ in 0..reader.read_u32()? {
+            result.lang_script.push((
+                reader.read_language()?,
+                reader.read_script()?,
+                reader.read_value()?,
+            ));
+        }
+        for _ in 0..reader.read_u32()? {
+            result.script_region.push((
+                reader.read_script()?,
+                reader.read_region()?,
+                reader.read_value()?,
+            ));
+        }
+        for _ in 0..reader.read_u32()? {
+            result
+                .script_only
+                .push((reader.read_script()?, reader.read_value()?));
+        }
+        for _ in 0..reader.read_u32()? {
+            result
+                .region_only
+                .push((reader.read_region()?, reader.read_value()?));
+        }
+
+        if !reader.input.is_empty() {
+            return Err(DataError::InvalidData(
+                "Unexpected trailing data".to_string(),
+            ));
+        }
+
+        result.sort();
+        Ok(result)
+    }
+
+    /// Serializes the data into the binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.extend_from_slice(BINARY_MAGIC);
+        result.push(BINARY_FORMAT_VERSION);
+        result.push(self.version.len() as u8);
+        result.extend_from_slice(self.version.as_bytes());
+
+        fn write_value(result: &mut Vec<u8>, value: &LangIdSubTags) {
+            result.extend_from_slice(&value.0.unwrap_or(0).to_le_bytes());
+            result.extend_from_slice(&value.1.unwrap_or(0).to_le_bytes());
+            result.extend_from_slice(&value.2.unwrap_or(0).to_le_bytes());
+        }
+
+        result.extend_from_slice(&(self.lang_only.len() as u32).to_le_bytes());
+        for (l, value) in &self.lang_only {
+            result.extend_from_slice(&l.to_le_bytes());
+            write_value(&mut result, value);
+        }
+        for table in &[&self.lang_region, &self.lang_script] {
+            result.extend_from_slice(&(table.len() as u32).to_le_bytes());
+            for (l, sr, value) in table.iter() {
+                result.extend_from_slice(&l.to_le_bytes());
+                result.extend_from_slice(&sr.to_le_bytes());
+                write_value(&mut result, value);
+            }
+        }
+        result.extend_from_slice(&(self.script_region.len() as u32).to_le_bytes());
+        for (s, r, value) in &self.script_region {
+            result.extend_from_slice(&s.to_le_bytes());
+            result.extend_from_slice(&r.to_le_bytes());
+            write_value(&mut result, value);
+        }
+        for table in &[&self.script_only, &self.region_only] {
+            result.extend_from_slice(&(table.len() as u32).to_le_bytes());
+            for (sr, value) in table.iter() {
+                result.extend_from_slice(&sr.to_le_bytes());
+                write_value(&mut result, value);
+            }
+        }
+        result
+    }
+
+    fn sort(&mut self) {
+        self.lang_only.sort_by_key(|e| e.0);
+        self.lang_region.sort_by_key(|e| (e.0, e.1));
+        self.lang_script.sort_by_key(|e| (e.0, e.1));
+        self.script_region.sort_by_key(|e| (e.0, e.1));
+        self.script_only.sort_by_key(|e| e.0);
+        self.region_only.sort_by_key(|e| e.0);
+    }
+
+    /// Returns the CLDR version of the loaded data.
+    pub fn cldr_version(&self) -> &str {
+        &self.version
+    }
+
+    pub(crate) fn tables(&self) -> LikelySubtagsTables<'_> {
+        LikelySubtagsTables {
+            lang_only: &self.lang_only,
+            lang_region: &self.lang_region,
+            lang_script: &self.lang_script,
+            script_region: &self.script_region,
+            script_only: &self.script_only,
+            region_only: &self.region_only,
+        }
+    }
+}
+
+struct BinaryReader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> BinaryReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DataError> {
+        if self.input.len() < len {
+            return Err(DataError::InvalidData("Unexpected end of data".to_string()));
+        }
+        let (result, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(result)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, DataError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, DataError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn read_language(&mut self) -> Result<u64, DataError> {
+        self.read_u64().and_then(validate_language)
+    }
+
+    fn read_script(&mut self) -> Result<u32, DataError> {
+        self.read_u32().and_then(validate_script)
+    }
+
+    fn read_region(&mut self) -> Result<u32, DataError> {
+        self.read_u32().and_then(validate_region)
+    }
+
+    fn read_value(&mut self) -> Result<LangIdSubTags, DataError> {
+        let non_zero_u64 = |v| if v == 0 { None } else { Some(v) };
+        let non_zero_u32 = |v| if v == 0 { None } else { Some(v) };
+        Ok((
+            non_zero_u64(self.read_u64()?)
+                .map(validate_language)
+                .transpose()?,
+            non_zero_u32(self.read_u32()?)
+                .map(validate_script)
+                .transpose()?,
+            non_zero_u32(self.read_u32()?)
+                .map(validate_region)
+                .transpose()?,
+        ))
+    }
+}
+
+// Lookups turn the stored subtags back into `TinyStr` values with
+// `new_unchecked`, so every subtag read from the binary data has to
+// round-trip through the parser.
+
+fn validate_language(value: u64) -> Result<u64, DataError> {
+    decode_subtag(&value.to_le_bytes())
+        .and_then(|s| subtags::parse_language_subtag(s).ok())
+        .flatten()
+        .map(Into::<u64>::into)
+        .filter(|&v| v == value)
+        .ok_or_else(|| DataError::InvalidData(format!("Invalid language subtag: {:#x}", value)))
+}
+
+fn validate_script(value: u32) -> Result<u32, DataError> {
+    decode_subtag(&value.to_le_bytes())
+        .and_then(|s| subtags::parse_script_subtag(s).ok())
+        .map(Into::<u32>::into)
+        .filter(|&v| v == value)
+        .ok_or_else(|| DataError::InvalidData(format!("Invalid script subtag: {:#x}", value)))
+}
+
+fn validate_region(value: u32) -> Result<u32, DataError> {
+    decode_subtag(&value.to_le_bytes())
+        .and_then(|s| subtags::parse_region_subtag(s).ok())
+        .map(Into::<u32>::into)
+        .filter(|&v| v == value)
+        .ok_or_else(|| DataError::InvalidData(format!("Invalid region subtag: {:#x}", value)))
+}
+
+/// Returns the string stored in the bytes of a `TinyStr`, which is
+/// padded with trailing zeros.
+fn decode_subtag(bytes: &[u8]) -> Option<&str> {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..len]).ok()
+}
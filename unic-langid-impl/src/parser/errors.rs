@@ -5,6 +5,12 @@ use std::fmt::{self, Display};
 pub enum ParserError {
     InvalidLanguage,
     InvalidSubtag,
+    InputTooLong,
+    NonCanonical,
+    /// Two separators follow each other, or the input starts with one.
+    EmptySubtag,
+    /// The input ends with a separator.
+    TrailingSeparator,
 }
 
 impl Error for ParserError {}
@@ -14,7 +20,53 @@ impl Display for ParserError {
         let value = match self {
             ParserError::InvalidLanguage => "The given language subtag is invalid",
             ParserError::InvalidSubtag => "Invalid subtag",
+            ParserError::InputTooLong => "The input exceeds the maximum length",
+            ParserError::NonCanonical => "The input is not in canonical form",
+            ParserError::EmptySubtag => "Empty subtag",
+            ParserError::TrailingSeparator => "The input ends with a separator",
         };
         write!(f, "{}", value)
     }
 }
+
+/// Every problem found in a single input, as returned by
+/// `LanguageIdentifier::validate`.
+///
+/// Each error is paired with the byte offset of the subtag which caused it.
+#[derive(Debug, PartialEq)]
+pub struct ParserErrors {
+    errors: Vec<(usize, ParserError)>,
+}
+
+impl ParserErrors {
+    pub(crate) fn new(errors: Vec<(usize, ParserError)>) -> Self {
+        debug_assert!(!errors.is_empty());
+        Self { errors }
+    }
+
+    /// Returns the errors with the byte offsets of the offending subtags.
+    ///
+    /// Errors concerning the whole input, such as `InputTooLong`, come first
+    /// with an offset of `0`, followed by the subtags in input order.
+    pub fn errors(&self) -> &[(usize, ParserError)] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<(usize, ParserError)> {
+        self.errors
+    }
+}
+
+impl Error for ParserErrors {}
+
+impl Display for ParserErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, (offset, error)) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{} at byte {}", error, offset)?;
+        }
+        Ok(())
+    }
+}
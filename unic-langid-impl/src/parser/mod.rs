@@ -1,54 +1,129 @@
 pub mod errors;
+mod options;
 
 use std::iter::Peekable;
 
-pub use self::errors::ParserError;
+pub use self::errors::{ParserError, ParserErrors};
+pub use self::options::ParserOptions;
 use crate::subtags;
-use crate::LanguageIdentifier;
+use crate::{LanguageIdentifier, SkippedSubtag};
+use tinystr::{TinyStr4, TinyStr8};
 
 static SEPARATORS: &[char] = &['-', '_'];
 
-pub fn parse_language_identifier_from_iter<'a>(
-    iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-    allow_extension: bool,
-) -> Result<LanguageIdentifier, ParserError> {
-    let mut position = 0;
+/// The subtags of a language identifier parsed so far.
+#[derive(Default)]
+struct SubtagsParser {
+    position: u8,
+    language: Option<TinyStr8>,
+    script: Option<TinyStr4>,
+    region: Option<TinyStr4>,
+    variants: Vec<TinyStr8>,
+}
 
-    let mut language = None;
-    let mut script = None;
-    let mut region = None;
-    let mut variants = vec![];
+impl SubtagsParser {
+    fn parse_variant(&mut self, subtag: &str) -> Result<(), ParserError> {
+        self.variants.push(subtags::parse_variant_subtag(subtag)?);
+        self.position = 3;
+        Ok(())
+    }
 
-    while let Some(subtag) = iter.next() {
-        if position == 0 {
+    /// Moves past a subtag rejected by `push`.
+    ///
+    /// The lenient mode treats it as a variant, so no script or region is
+    /// expected after it. Otherwise the subtags which may follow it are
+    /// still accepted, so that a misspelled script does not turn a valid
+    /// region into an error.
+    fn skip(&mut self, options: &ParserOptions) {
+        if self.position == 0 {
+            self.position = 1;
+        } else if options.lenient {
+            self.position = 3;
+        }
+    }
+
+    fn push(&mut self, subtag: &str) -> Result<(), ParserError> {
+        if self.position == 0 {
             // Language
-            language = subtags::parse_language_subtag(subtag)?;
-            position = 1;
-        } else if position == 1 {
+            self.language = subtags::parse_language_subtag(subtag)?;
+            self.position = 1;
+        } else if self.position == 1 {
             if let Ok(s) = subtags::parse_script_subtag(subtag) {
-                script = Some(s);
-                position = 2;
+                self.script = Some(s);
+                self.position = 2;
             } else if let Ok(s) = subtags::parse_region_subtag(subtag) {
-                region = Some(s);
-                position = 3;
+                self.region = Some(s);
+                self.position = 3;
             } else {
-                variants.push(subtags::parse_variant_subtag(subtag)?);
-                position = 3;
+                self.parse_variant(subtag)?;
             }
-        } else if position == 2 {
+        } else if self.position == 2 {
             if let Ok(s) = subtags::parse_region_subtag(subtag) {
-                region = Some(s);
-                position = 3;
+                self.region = Some(s);
+                self.position = 3;
             } else {
-                variants.push(subtags::parse_variant_subtag(subtag)?);
-                position = 3;
+                self.parse_variant(subtag)?;
             }
         } else {
             // Variants
-            variants.push(subtags::parse_variant_subtag(subtag)?);
+            self.parse_variant(subtag)?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self, options: &ParserOptions) -> LanguageIdentifier {
+        let variants = if self.variants.is_empty() {
+            None
+        } else if options.preserve_variant_order {
+            let mut variants = Vec::with_capacity(self.variants.len());
+            for variant in self.variants {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+            Some(variants.into_boxed_slice())
+        } else {
+            self.variants.sort();
+            self.variants.dedup();
+            Some(self.variants.into_boxed_slice())
+        };
+
+        LanguageIdentifier {
+            language: self.language,
+            script: self.script,
+            region: self.region,
+            variants,
         }
+    }
+}
 
-        if allow_extension {
+pub fn parse_language_identifier_from_iter<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a str>>,
+    allow_extension: bool,
+) -> Result<LanguageIdentifier, ParserError> {
+    let options = ParserOptions {
+        allow_extension,
+        ..ParserOptions::default()
+    };
+    parse_language_identifier_from_iter_with_options(iter, &options)
+}
+
+pub fn parse_language_identifier_from_iter_with_options<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a str>>,
+    options: &ParserOptions,
+) -> Result<LanguageIdentifier, ParserError> {
+    let mut parser = SubtagsParser::default();
+
+    while let Some(subtag) = iter.next() {
+        if let Err(err) = parser.push(subtag) {
+            // The lenient mode only skips the subtags following the language.
+            if !options.lenient || parser.position == 0 {
+                return Err(err);
+            }
+            parser.skip(options);
+        }
+
+        if options.allow_extension {
             if let Some(st_peek) = iter.peek() {
                 if st_peek.len() == 1 {
                     break;
@@ -57,15 +132,107 @@ pub fn parse_language_identifier_from_iter<'a>(
         }
     }
 
+    Ok(parser.finish(options))
+}
+
+/// Parses a language identifier from the subtags of the input.
+///
+/// Errors are passed to `reject` together with the byte offset of the subtag
+/// and the subtag itself.
+/// If it returns `Ok`, the subtag is skipped and parsing continues.
+fn parse_language_identifier_from_subtags(
+    t: &str,
+    options: &ParserOptions,
+    mut reject: impl FnMut(usize, &str, ParserError) -> Result<(), ParserError>,
+) -> Result<LanguageIdentifier, ParserError> {
+    let separators: &[char] = if options.allow_underscore {
+        SEPARATORS
+    } else {
+        &['-']
+    };
+    let mut parser = SubtagsParser::default();
+
+    let mut offset = 0;
+    let mut iter = t.split(separators).peekable();
+    while let Some(subtag) = iter.next() {
+        let subtag_offset = offset;
+        offset += subtag.len() + 1;
+
+        if options.allow_extension && parser.position > 0 && subtag.len() == 1 {
+            break;
+        }
+        if subtag.is_empty() && !t.is_empty() {
+            // Skipping an empty subtag does not affect the position, so the
+            // lenient mode repairs `-en` and `en--US`.
+            let err = if iter.peek().is_none() {
+                ParserError::TrailingSeparator
+            } else {
+                ParserError::EmptySubtag
+            };
+            reject(subtag_offset, subtag, err)?;
+        } else if let Err(err) = parser.push(subtag) {
+            reject(subtag_offset, subtag, err)?;
+            parser.skip(options);
+        }
+    }
+
+    Ok(parser.finish(options))
+}
+
+/// Attempts to parse an input which is already in its canonical form.
+///
+/// Most of the identifiers we encounter come from CLDR or other sources which
+/// already use `-` separators, correct casing and sorted variants.
+/// For those we can skip the case transformations and variant sorting.
+///
+/// Returns `None` if the input is not canonical, in which case the caller
+/// should fall back on the full parser which will also produce a precise error.
+fn parse_canonical_language_identifier(t: &str) -> Option<LanguageIdentifier> {
+    let mut iter = t.split('-');
+
+    let language = match iter.next()? {
+        "und" => None,
+        subtag => Some(subtags::parse_canonical_language_subtag(subtag)?),
+    };
+
+    let mut script = None;
+    let mut region = None;
+    let mut variants: Vec<TinyStr8> = vec![];
+
+    let mut position = 1;
+    for subtag in iter {
+        if position == 1 {
+            if let Some(s) = subtags::parse_canonical_script_subtag(subtag) {
+                script = Some(s);
+                position = 2;
+                continue;
+            }
+        }
+        if position <= 2 {
+            if let Some(r) = subtags::parse_canonical_region_subtag(subtag) {
+                region = Some(r);
+                position = 3;
+                continue;
+            }
+        }
+        let variant = subtags::parse_canonical_variant_subtag(subtag)?;
+        // Variants have to be sorted and deduplicated in canonical form.
+        if let Some(last) = variants.last() {
+            if last >= &variant {
+                return None;
+            }
+        }
+        variants.push(variant);
+        position = 3;
+    }
+
     let variants = if variants.is_empty() {
         None
     } else {
-        variants.sort();
-        variants.dedup();
         Some(variants.into_boxed_slice())
     };
 
-    Ok(LanguageIdentifier {
+    Some(LanguageIdentifier {
         language,
         script,
         region,
@@ -74,6 +241,198 @@ pub fn parse_language_identifier_from_iter<'a>(
 }
 
 pub fn parse_language_identifier(t: &str) -> Result<LanguageIdentifier, ParserError> {
-    let mut iter = t.split(|c| SEPARATORS.contains(&c)).peekable();
-    parse_language_identifier_from_iter(&mut iter, false)
+    parse_language_identifier_with_options(t, &ParserOptions::default())
+}
+
+pub fn parse_language_identifier_with_options(
+    t: &str,
+    options: &ParserOptions,
+) -> Result<LanguageIdentifier, ParserError> {
+    if let Some(max_length) = options.max_length {
+        if t.len() > max_length {
+            return Err(ParserError::InputTooLong);
+        }
+    }
+
+    if let Some(langid) = parse_canonical_language_identifier(t) {
+        return Ok(langid);
+    }
+
+    let langid = parse_language_identifier_from_subtags(t, options, |_, _, err| {
+        // The lenient mode skips anything but an invalid language subtag.
+        if options.lenient && err != ParserError::InvalidLanguage {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    })?;
+
+    if options.require_canonical {
+        return Err(ParserError::NonCanonical);
+    }
+    Ok(langid)
+}
+
+/// Parses a language identifier, collecting every error instead of stopping
+/// at the first one.
+///
+/// Invalid subtags are reported even if `options.lenient` is set.
+pub fn validate_language_identifier(
+    t: &str,
+    options: &ParserOptions,
+) -> Result<LanguageIdentifier, ParserErrors> {
+    let mut errors = vec![];
+
+    if let Some(max_length) = options.max_length {
+        if t.len() > max_length {
+            errors.push((0, ParserError::InputTooLong));
+        }
+    }
+    if options.require_canonical && parse_canonical_language_identifier(t).is_none() {
+        errors.push((0, ParserError::NonCanonical));
+    }
+
+    let strict = ParserOptions {
+        lenient: false,
+        ..*options
+    };
+    let langid = parse_language_identifier_from_subtags(t, &strict, |offset, _, err| {
+        errors.push((offset, err));
+        Ok(())
+    })
+    .expect("Errors are collected.");
+
+    if errors.is_empty() {
+        Ok(langid)
+    } else {
+        Err(ParserErrors::new(errors))
+    }
+}
+
+/// Parses a language identifier in the lenient mode, returning the skipped
+/// subtags with their byte offsets and the errors they failed with.
+pub fn recover_language_identifier(
+    t: &str,
+    options: &ParserOptions,
+) -> Result<(LanguageIdentifier, Vec<SkippedSubtag>), ParserError> {
+    if let Some(max_length) = options.max_length {
+        if t.len() > max_length {
+            return Err(ParserError::InputTooLong);
+        }
+    }
+
+    let lenient = ParserOptions {
+        lenient: true,
+        ..*options
+    };
+    let mut skipped = vec![];
+    let langid = parse_language_identifier_from_subtags(t, &lenient, |offset, subtag, err| {
+        if err != ParserError::InvalidLanguage {
+            skipped.push(SkippedSubtag {
+                offset,
+                subtag: subtag.to_string(),
+                error: err,
+            });
+            Ok(())
+        } else {
+            Err(err)
+        }
+    })?;
+
+    if options.require_canonical && parse_canonical_language_identifier(t).is_none() {
+        return Err(ParserError::NonCanonical);
+    }
+    Ok((langid, skipped))
+}
+
+/// Matches a language identifier against a textual language range,
+/// comparing the subtags as they are parsed.
+///
+/// A `*` subtag, or a missing one, matches any value in its position.
+pub fn matches_language_range(
+    langid: &LanguageIdentifier,
+    range: &str,
+    options: &ParserOptions,
+) -> Result<bool, ParserError> {
+    if let Some(max_length) = options.max_length {
+        if range.len() > max_length {
+            return Err(ParserError::InputTooLong);
+        }
+    }
+
+    let separators: &[char] = if options.allow_underscore {
+        &['-', '_']
+    } else {
+        &['-']
+    };
+    let langid_variants = langid.variants.as_deref().unwrap_or(&[]);
+
+    let mut matches = true;
+    let mut position = 0;
+    let mut variants_offset = None;
+    let mut any_variant = false;
+
+    let mut offset = 0;
+    for subtag in range.split(separators) {
+        let subtag_offset = offset;
+        offset += subtag.len() + 1;
+
+        if subtag == "*" {
+            match position {
+                0 => position = 1,
+                1 => position = 2,
+                2 => position = 3,
+                _ => any_variant = true,
+            }
+            continue;
+        }
+
+        if position == 0 {
+            if let Some(language) = subtags::parse_language_subtag(subtag)? {
+                matches &= langid.language == Some(language);
+            }
+            position = 1;
+            continue;
+        }
+        if options.allow_extension && subtag.len() == 1 {
+            break;
+        }
+        if position == 1 {
+            if let Ok(script) = subtags::parse_script_subtag(subtag) {
+                matches &= langid.script == Some(script);
+                position = 2;
+                continue;
+            }
+        }
+        if position <= 2 {
+            if let Ok(region) = subtags::parse_region_subtag(subtag) {
+                matches &= langid.region == Some(region);
+                position = 3;
+                continue;
+            }
+        }
+        match subtags::parse_variant_subtag(subtag) {
+            Ok(variant) => {
+                matches &= langid_variants.contains(&variant);
+                variants_offset.get_or_insert(subtag_offset);
+            }
+            Err(_) if options.lenient => {}
+            Err(err) => return Err(err),
+        }
+        position = 3;
+    }
+
+    // Every variant of the range is present in the identifier, so the variants
+    // are equal as long as every variant of the identifier is in the range.
+    if matches && !any_variant {
+        if let Some(variants_offset) = variants_offset {
+            let range_variants = &range[variants_offset..];
+            matches = langid_variants.iter().all(|variant| {
+                range_variants.split(separators).any(|subtag| {
+                    subtags::parse_variant_subtag(subtag).ok().as_ref() == Some(variant)
+                })
+            });
+        }
+    }
+    Ok(matches)
 }
@@ -0,0 +1,93 @@
+/// Options controlling the parsing policy of a `LanguageIdentifier`.
+///
+/// The default options match the policy of `FromStr`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+///
+/// let options = ParserOptions {
+///     allow_extension: true,
+///     ..ParserOptions::default()
+/// };
+///
+/// let li = LanguageIdentifier::from_str_with("en-US-u-hc-h12", &options)
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.to_string(), "en-US");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Accept `_` as a subtag separator in addition to `-`.
+    ///
+    /// Default: `true`.
+    pub allow_underscore: bool,
+    /// Accept an input with extensions, ignoring everything starting
+    /// from the first extension singleton.
+    ///
+    /// Default: `false`.
+    pub allow_extension: bool,
+    /// Skip invalid script, region and variant subtags instead of failing.
+    ///
+    /// Empty subtags are skipped as well, which repairs inputs such as
+    /// `en--US` or `en-US-`.
+    ///
+    /// Default: `false`.
+    pub lenient: bool,
+    /// Only accept input which is already in its canonical form,
+    /// using canonical casing and variant order.
+    ///
+    /// Default: `false`.
+    pub require_canonical: bool,
+    /// Maximum length of the input in bytes.
+    ///
+    /// Default: `None`.
+    pub max_length: Option<usize>,
+    /// Keep the variants in the order of the input instead of sorting them.
+    /// Duplicate variants are still removed.
+    ///
+    /// Use `LanguageIdentifier::eq_canonical` to compare identifiers regardless
+    /// of their variant order.
+    ///
+    /// Default: `false`.
+    pub preserve_variant_order: bool,
+}
+
+impl ParserOptions {
+    /// Options for strict BCP 47 validation.
+    ///
+    /// Only `-` separators and canonical ASCII input are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let options = ParserOptions::strict();
+    ///
+    /// assert!(LanguageIdentifier::from_str_with("en-US", &options).is_ok());
+    /// assert!(LanguageIdentifier::from_str_with("en_US", &options).is_err());
+    /// assert!(LanguageIdentifier::from_str_with("en-us", &options).is_err());
+    /// ```
+    pub fn strict() -> Self {
+        Self {
+            allow_underscore: false,
+            require_canonical: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            allow_underscore: true,
+            allow_extension: false,
+            lenient: false,
+            require_canonical: false,
+            max_length: None,
+            preserve_variant_order: false,
+        }
+    }
+}
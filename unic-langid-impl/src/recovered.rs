@@ -0,0 +1,77 @@
+use crate::parser::{self, ParserError};
+use crate::{LanguageIdentifier, LanguageIdentifierError, ParserOptions};
+use std::fmt;
+
+/// A subtag which was skipped while parsing a `LanguageIdentifier` in the lenient mode.
+#[derive(Debug, PartialEq)]
+pub struct SkippedSubtag {
+    /// The byte offset of the subtag in the input.
+    pub offset: usize,
+    pub subtag: String,
+    /// The error the subtag failed to parse with.
+    pub error: ParserError,
+}
+
+impl fmt::Display for SkippedSubtag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at byte {}: {}",
+            self.subtag, self.offset, self.error
+        )
+    }
+}
+
+/// The result of parsing a `LanguageIdentifier` in the lenient mode,
+/// along with the subtags which were skipped.
+///
+/// It allows tools importing data of varying quality to log exactly what
+/// was discarded from each input.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{ParserOptions, RecoveredLanguageIdentifier};
+///
+/// let options = ParserOptions::default();
+/// let recovered = RecoveredLanguageIdentifier::from_str_with("en-US-x-valencia", &options)
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(recovered.langid.to_string(), "en-US-valencia");
+/// assert_eq!(recovered.skipped.len(), 1);
+/// assert_eq!(recovered.skipped[0].offset, 6);
+/// assert_eq!(recovered.skipped[0].subtag, "x");
+/// assert_eq!(recovered.skipped[0].to_string(), "`x` at byte 6: Invalid subtag");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct RecoveredLanguageIdentifier {
+    pub langid: LanguageIdentifier,
+    /// The skipped subtags, in input order.
+    pub skipped: Vec<SkippedSubtag>,
+}
+
+impl RecoveredLanguageIdentifier {
+    /// Parses the input according to the given `ParserOptions`, skipping
+    /// invalid script, region and variant subtags and empty subtags as
+    /// `lenient` does.
+    ///
+    /// The recovered identifier is the one `LanguageIdentifier::from_str_with`
+    /// returns with `lenient` set. An invalid language subtag still fails.
+    pub fn from_str_with(
+        input: &str,
+        options: &ParserOptions,
+    ) -> Result<Self, LanguageIdentifierError> {
+        let (langid, skipped) = parser::recover_language_identifier(input, options)?;
+        Ok(Self { langid, skipped })
+    }
+
+    /// Returns `true` if no subtags were skipped.
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+
+    /// Returns the recovered `LanguageIdentifier`.
+    pub fn into_inner(self) -> LanguageIdentifier {
+        self.langid
+    }
+}
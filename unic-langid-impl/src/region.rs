@@ -0,0 +1,95 @@
+use crate::region_table::REGION_CODES;
+use crate::subtags;
+use crate::LanguageIdentifierError;
+use std::str::FromStr;
+use tinystr::TinyStr4;
+
+/// A region subtag, either an ISO 3166-1 alpha-2 code or a UN M.49 numeric code.
+///
+/// `Region` allows converting between the alpha-2 form used by CLDR and
+/// the ISO 3166-1 alpha-3 and numeric codes used by other systems.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::Region;
+///
+/// let region = Region::from_alpha3("DEU").expect("Unknown region.");
+/// assert_eq!(region.as_str(), "DE");
+/// assert_eq!(region.to_alpha3(), Some("DEU"));
+/// assert_eq!(region.to_numeric(), Some(276));
+///
+/// assert_eq!(Region::from_numeric(276), Some(region));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Region(TinyStr4);
+
+impl Region {
+    /// Returns the region for an ISO 3166-1 alpha-3 code.
+    ///
+    /// The code is matched case-insensitively.
+    pub fn from_alpha3(code: &str) -> Option<Self> {
+        REGION_CODES
+            .iter()
+            .find(|(_, alpha3, _)| alpha3.eq_ignore_ascii_case(code))
+            .map(|(alpha2, _, _)| Self(alpha2.parse().unwrap()))
+    }
+
+    /// Returns the region for an ISO 3166-1 numeric code.
+    ///
+    /// Only codes assigned to countries are mapped, UN M.49 area codes such as
+    /// `419` should be parsed from their string form.
+    pub fn from_numeric(code: u16) -> Option<Self> {
+        REGION_CODES
+            .iter()
+            .find(|(_, _, numeric)| *numeric == code)
+            .map(|(alpha2, _, _)| Self(alpha2.parse().unwrap()))
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code of the region, if it has one.
+    pub fn to_alpha3(&self) -> Option<&'static str> {
+        self.get_codes().map(|(_, alpha3, _)| alpha3)
+    }
+
+    /// Returns the numeric code of the region.
+    ///
+    /// For countries this is the ISO 3166-1 numeric code, for UN M.49 areas
+    /// it is the value of the subtag itself.
+    pub fn to_numeric(&self) -> Option<u16> {
+        self.as_str()
+            .parse()
+            .ok()
+            .or_else(|| self.get_codes().map(|(_, _, numeric)| numeric))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    fn get_codes(&self) -> Option<(&'static str, &'static str, u16)> {
+        REGION_CODES
+            .binary_search_by(|(alpha2, _, _)| (*alpha2).cmp(self.as_str()))
+            .ok()
+            .map(|idx| REGION_CODES[idx])
+    }
+}
+
+impl FromStr for Region {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(Self(subtags::parse_region_subtag(source)?))
+    }
+}
+
+impl From<Region> for TinyStr4 {
+    fn from(region: Region) -> Self {
+        region.0
+    }
+}
+
+impl std::fmt::Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
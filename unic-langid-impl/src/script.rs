@@ -0,0 +1,138 @@
+use crate::script_code_table::{SCRIPT_ALIASES, SCRIPT_CODES};
+use crate::subtags;
+use crate::LanguageIdentifierError;
+use std::str::FromStr;
+use tinystr::TinyStr4;
+
+/// Numeric code of `Qaaa`, the first script of the private use range `Qaaa`..`Qabx`.
+const PRIVATE_USE_START: u16 = 900;
+/// Numeric code of `Qabx`, the last script of the private use range.
+const PRIVATE_USE_END: u16 = 949;
+
+/// A script subtag, such as `Latn` or `Cyrl`.
+///
+/// `Script` allows converting between the ISO 15924 four letter codes, their
+/// numeric codes and the Unicode property value aliases used by font and
+/// OpenType tooling.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::Script;
+///
+/// let script: Script = "latn".parse().expect("Parsing failed.");
+/// assert_eq!(script.as_str(), "Latn");
+/// assert_eq!(script.to_numeric(), Some(215));
+/// assert_eq!(script.to_alias(), Some("Latin"));
+///
+/// assert_eq!(Script::from_numeric(215), Some(script));
+/// assert_eq!(Script::from_alias("Latin"), Some(script));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Script(TinyStr4);
+
+impl Script {
+    /// Returns the script for an ISO 15924 numeric code.
+    pub fn from_numeric(code: u16) -> Option<Self> {
+        if (PRIVATE_USE_START..=PRIVATE_USE_END).contains(&code) {
+            let offset = (code - PRIVATE_USE_START) as u8;
+            let bytes = [b'Q', b'a', b'a' + offset / 26, b'a' + offset % 26];
+            let code = std::str::from_utf8(&bytes).unwrap();
+            return Some(Self(code.parse().unwrap()));
+        }
+        SCRIPT_CODES
+            .iter()
+            .find(|(_, numeric, _)| *numeric == code)
+            .map(|(script, _, _)| Self(script.parse().unwrap()))
+    }
+
+    /// Returns the script for a Unicode property value alias, such as `Cyrillic`.
+    ///
+    /// The alias is matched case-insensitively.
+    pub fn from_alias(alias: &str) -> Option<Self> {
+        SCRIPT_CODES
+            .iter()
+            .find(|(_, _, a)| a.map(|a| a.eq_ignore_ascii_case(alias)) == Some(true))
+            .map(|(script, _, _)| Self(script.parse().unwrap()))
+    }
+
+    /// Returns the canonical form of the script, replacing deprecated codes such as `Qaai`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::Script;
+    ///
+    /// let script: Script = "Qaai".parse().expect("Parsing failed.");
+    /// assert_eq!(script.canonicalize().as_str(), "Zinh");
+    /// ```
+    pub fn canonicalize(&self) -> Self {
+        Self(canonicalize_script_subtag(self.0))
+    }
+
+    /// Returns the ISO 15924 numeric code of the script.
+    pub fn to_numeric(&self) -> Option<u16> {
+        let bytes = self.as_str().as_bytes();
+        if bytes[0] == b'Q' && bytes[1] == b'a' {
+            let offset = u16::from(bytes[2] - b'a') * 26 + u16::from(bytes[3] - b'a');
+            if offset <= PRIVATE_USE_END - PRIVATE_USE_START {
+                return Some(PRIVATE_USE_START + offset);
+            }
+        }
+        self.get_codes().map(|(_, numeric, _)| numeric)
+    }
+
+    /// Returns the Unicode property value alias of the script, if it has one.
+    ///
+    /// Variants of scripts, such as `Hans` or `Latf`, and private use scripts have no alias.
+    pub fn to_alias(&self) -> Option<&'static str> {
+        self.get_codes().and_then(|(_, _, alias)| alias)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    fn get_codes(&self) -> Option<(&'static str, u16, Option<&'static str>)> {
+        SCRIPT_CODES
+            .binary_search_by(|(script, _, _)| (*script).cmp(self.as_str()))
+            .ok()
+            .map(|idx| SCRIPT_CODES[idx])
+    }
+}
+
+/// Returns the script code as a static string, if it is a known ISO 15924 code.
+pub(crate) fn get_static_script(script: TinyStr4) -> Option<&'static str> {
+    SCRIPT_CODES
+        .binary_search_by(|(code, _, _)| (*code).cmp(script.as_str()))
+        .ok()
+        .map(|idx| SCRIPT_CODES[idx].0)
+}
+
+/// Replaces deprecated script codes with their replacements.
+pub(crate) fn canonicalize_script_subtag(script: TinyStr4) -> TinyStr4 {
+    match SCRIPT_ALIASES.binary_search_by(|(alias, _)| (*alias).cmp(script.as_str())) {
+        Ok(idx) => SCRIPT_ALIASES[idx].1.parse().unwrap(),
+        Err(_) => script,
+    }
+}
+
+impl FromStr for Script {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(Self(subtags::parse_script_subtag(source)?))
+    }
+}
+
+impl From<Script> for TinyStr4 {
+    fn from(script: Script) -> Self {
+        script.0
+    }
+}
+
+impl std::fmt::Display for Script {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
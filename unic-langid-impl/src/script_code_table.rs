@@ -0,0 +1,212 @@
+/// ISO 15924 script codes as `(code, numeric, Unicode property value alias)`, sorted by code.
+///
+/// The private use range `Qaaa`..`Qabx` is not listed.
+pub const SCRIPT_CODES: [(&str, u16, Option<&str>); 204] = [
+    ("Adlm", 166, Some("Adlam")),
+    ("Afak", 439, None),
+    ("Aghb", 239, Some("Caucasian_Albanian")),
+    ("Ahom", 338, Some("Ahom")),
+    ("Arab", 160, Some("Arabic")),
+    ("Aran", 161, None),
+    ("Armi", 124, Some("Imperial_Aramaic")),
+    ("Armn", 230, Some("Armenian")),
+    ("Avst", 134, Some("Avestan")),
+    ("Bali", 360, Some("Balinese")),
+    ("Bamu", 435, Some("Bamum")),
+    ("Bass", 259, Some("Bassa_Vah")),
+    ("Batk", 365, Some("Batak")),
+    ("Beng", 325, Some("Bengali")),
+    ("Bhks", 334, Some("Bhaiksuki")),
+    ("Blis", 550, None),
+    ("Bopo", 285, Some("Bopomofo")),
+    ("Brah", 300, Some("Brahmi")),
+    ("Brai", 570, Some("Braille")),
+    ("Bugi", 367, Some("Buginese")),
+    ("Buhd", 372, Some("Buhid")),
+    ("Cakm", 349, Some("Chakma")),
+    ("Cans", 440, Some("Canadian_Aboriginal")),
+    ("Cari", 201, Some("Carian")),
+    ("Cham", 358, Some("Cham")),
+    ("Cher", 445, Some("Cherokee")),
+    ("Chrs", 109, Some("Chorasmian")),
+    ("Cirt", 291, None),
+    ("Copt", 204, Some("Coptic")),
+    ("Cpmn", 402, Some("Cypro_Minoan")),
+    ("Cprt", 403, Some("Cypriot")),
+    ("Cyrl", 220, Some("Cyrillic")),
+    ("Cyrs", 221, None),
+    ("Deva", 315, Some("Devanagari")),
+    ("Diak", 342, Some("Dives_Akuru")),
+    ("Dogr", 328, Some("Dogra")),
+    ("Dsrt", 250, Some("Deseret")),
+    ("Dupl", 755, Some("Duployan")),
+    ("Egyd", 70, None),
+    ("Egyh", 60, None),
+    ("Egyp", 50, Some("Egyptian_Hieroglyphs")),
+    ("Elba", 226, Some("Elbasan")),
+    ("Elym", 128, Some("Elymaic")),
+    ("Ethi", 430, Some("Ethiopic")),
+    ("Geok", 241, None),
+    ("Geor", 240, Some("Georgian")),
+    ("Glag", 225, Some("Glagolitic")),
+    ("Gong", 312, Some("Gunjala_Gondi")),
+    ("Gonm", 313, Some("Masaram_Gondi")),
+    ("Goth", 206, Some("Gothic")),
+    ("Gran", 343, Some("Grantha")),
+    ("Grek", 200, Some("Greek")),
+    ("Gujr", 320, Some("Gujarati")),
+    ("Guru", 310, Some("Gurmukhi")),
+    ("Hanb", 503, None),
+    ("Hang", 286, Some("Hangul")),
+    ("Hani", 500, Some("Han")),
+    ("Hano", 371, Some("Hanunoo")),
+    ("Hans", 501, None),
+    ("Hant", 502, None),
+    ("Hatr", 127, Some("Hatran")),
+    ("Hebr", 125, Some("Hebrew")),
+    ("Hira", 410, Some("Hiragana")),
+    ("Hluw", 80, Some("Anatolian_Hieroglyphs")),
+    ("Hmng", 450, Some("Pahawh_Hmong")),
+    ("Hmnp", 451, Some("Nyiakeng_Puachue_Hmong")),
+    ("Hrkt", 412, Some("Katakana_Or_Hiragana")),
+    ("Hung", 176, Some("Old_Hungarian")),
+    ("Inds", 610, None),
+    ("Ital", 210, Some("Old_Italic")),
+    ("Jamo", 284, None),
+    ("Java", 361, Some("Javanese")),
+    ("Jpan", 413, None),
+    ("Jurc", 510, None),
+    ("Kali", 357, Some("Kayah_Li")),
+    ("Kana", 411, Some("Katakana")),
+    ("Khar", 305, Some("Kharoshthi")),
+    ("Khmr", 355, Some("Khmer")),
+    ("Khoj", 322, Some("Khojki")),
+    ("Kitl", 505, None),
+    ("Kits", 288, Some("Khitan_Small_Script")),
+    ("Knda", 345, Some("Kannada")),
+    ("Kore", 287, None),
+    ("Kpel", 436, None),
+    ("Kthi", 317, Some("Kaithi")),
+    ("Lana", 351, Some("Tai_Tham")),
+    ("Laoo", 356, Some("Lao")),
+    ("Latf", 217, None),
+    ("Latg", 216, None),
+    ("Latn", 215, Some("Latin")),
+    ("Leke", 364, None),
+    ("Lepc", 335, Some("Lepcha")),
+    ("Limb", 336, Some("Limbu")),
+    ("Lina", 400, Some("Linear_A")),
+    ("Linb", 401, Some("Linear_B")),
+    ("Lisu", 399, Some("Lisu")),
+    ("Loma", 437, None),
+    ("Lyci", 202, Some("Lycian")),
+    ("Lydi", 116, Some("Lydian")),
+    ("Mahj", 314, Some("Mahajani")),
+    ("Maka", 366, Some("Makasar")),
+    ("Mand", 140, Some("Mandaic")),
+    ("Mani", 139, Some("Manichaean")),
+    ("Marc", 332, Some("Marchen")),
+    ("Maya", 90, None),
+    ("Medf", 265, Some("Medefaidrin")),
+    ("Mend", 438, Some("Mende_Kikakui")),
+    ("Merc", 101, Some("Meroitic_Cursive")),
+    ("Mero", 100, Some("Meroitic_Hieroglyphs")),
+    ("Mlym", 347, Some("Malayalam")),
+    ("Modi", 324, Some("Modi")),
+    ("Mong", 145, Some("Mongolian")),
+    ("Moon", 218, None),
+    ("Mroo", 264, Some("Mro")),
+    ("Mtei", 337, Some("Meetei_Mayek")),
+    ("Mult", 323, Some("Multani")),
+    ("Mymr", 350, Some("Myanmar")),
+    ("Nand", 311, Some("Nandinagari")),
+    ("Narb", 106, Some("Old_North_Arabian")),
+    ("Nbat", 159, Some("Nabataean")),
+    ("Newa", 333, Some("Newa")),
+    ("Nkdb", 85, None),
+    ("Nkgb", 420, None),
+    ("Nkoo", 165, Some("Nko")),
+    ("Nshu", 499, Some("Nushu")),
+    ("Ogam", 212, Some("Ogham")),
+    ("Olck", 261, Some("Ol_Chiki")),
+    ("Orkh", 175, Some("Old_Turkic")),
+    ("Orya", 327, Some("Oriya")),
+    ("Osge", 219, Some("Osage")),
+    ("Osma", 260, Some("Osmanya")),
+    ("Ougr", 143, Some("Old_Uyghur")),
+    ("Palm", 126, Some("Palmyrene")),
+    ("Pauc", 263, Some("Pau_Cin_Hau")),
+    ("Perm", 227, Some("Old_Permic")),
+    ("Phag", 331, Some("Phags_Pa")),
+    ("Phli", 131, Some("Inscriptional_Pahlavi")),
+    ("Phlp", 132, Some("Psalter_Pahlavi")),
+    ("Phlv", 133, None),
+    ("Phnx", 115, Some("Phoenician")),
+    ("Piqd", 293, None),
+    ("Plrd", 282, Some("Miao")),
+    ("Prti", 130, Some("Inscriptional_Parthian")),
+    ("Rjng", 363, Some("Rejang")),
+    ("Rohg", 167, Some("Hanifi_Rohingya")),
+    ("Roro", 620, None),
+    ("Runr", 211, Some("Runic")),
+    ("Samr", 123, Some("Samaritan")),
+    ("Sara", 292, None),
+    ("Sarb", 105, Some("Old_South_Arabian")),
+    ("Saur", 344, Some("Saurashtra")),
+    ("Sgnw", 95, Some("SignWriting")),
+    ("Shaw", 281, Some("Shavian")),
+    ("Shrd", 319, Some("Sharada")),
+    ("Shui", 530, None),
+    ("Sidd", 302, Some("Siddham")),
+    ("Sind", 318, Some("Khudawadi")),
+    ("Sinh", 348, Some("Sinhala")),
+    ("Sogd", 141, Some("Sogdian")),
+    ("Sogo", 142, Some("Old_Sogdian")),
+    ("Sora", 398, Some("Sora_Sompeng")),
+    ("Soyo", 329, Some("Soyombo")),
+    ("Sund", 362, Some("Sundanese")),
+    ("Sylo", 316, Some("Syloti_Nagri")),
+    ("Syrc", 135, Some("Syriac")),
+    ("Syre", 138, None),
+    ("Syrj", 137, None),
+    ("Syrn", 136, None),
+    ("Tagb", 373, Some("Tagbanwa")),
+    ("Takr", 321, Some("Takri")),
+    ("Tale", 353, Some("Tai_Le")),
+    ("Talu", 354, Some("New_Tai_Lue")),
+    ("Taml", 346, Some("Tamil")),
+    ("Tang", 520, Some("Tangut")),
+    ("Tavt", 359, Some("Tai_Viet")),
+    ("Telu", 340, Some("Telugu")),
+    ("Teng", 290, None),
+    ("Tfng", 120, Some("Tifinagh")),
+    ("Tglg", 370, Some("Tagalog")),
+    ("Thaa", 170, Some("Thaana")),
+    ("Thai", 352, Some("Thai")),
+    ("Tibt", 330, Some("Tibetan")),
+    ("Tirh", 326, Some("Tirhuta")),
+    ("Tnsa", 275, Some("Tangsa")),
+    ("Toto", 294, Some("Toto")),
+    ("Ugar", 40, Some("Ugaritic")),
+    ("Vaii", 470, Some("Vai")),
+    ("Visp", 280, None),
+    ("Vith", 228, Some("Vithkuqi")),
+    ("Wara", 262, Some("Warang_Citi")),
+    ("Wcho", 283, Some("Wancho")),
+    ("Wole", 480, None),
+    ("Xpeo", 30, Some("Old_Persian")),
+    ("Xsux", 20, Some("Cuneiform")),
+    ("Yezi", 192, Some("Yezidi")),
+    ("Yiii", 460, Some("Yi")),
+    ("Zanb", 339, Some("Zanabazar_Square")),
+    ("Zinh", 994, Some("Inherited")),
+    ("Zmth", 995, None),
+    ("Zsye", 993, None),
+    ("Zsym", 996, None),
+    ("Zxxx", 997, None),
+    ("Zyyy", 998, Some("Common")),
+    ("Zzzz", 999, Some("Unknown")),
+];
+
+/// Deprecated script codes with their replacements, from CLDR scriptAlias data, sorted.
+pub const SCRIPT_ALIASES: [(&str, &str); 2] = [("Qaac", "Copt"), ("Qaai", "Zinh")];
@@ -0,0 +1,10 @@
+//! Line orientation of scripts, based on the Unicode vertical text layout (UAX #50)
+//! and CLDR script metadata.
+
+/// Scripts which are laid out in vertical lines, with glyphs rotated.
+pub const LINE_ORIENTATION_VERTICAL: [u32; 2] = [1734436944, 1735290701];
+/// Scripts which may be laid out both in horizontal and vertical lines.
+pub const LINE_ORIENTATION_MIXED: [u32; 13] = [
+    1634623819, 1634888008, 1651401032, 1701998411, 1735287112, 1768515929, 1768841544,
+    1851879498, 1869439306, 1869639490, 1936613704, 1953198664, 1953390920,
+];
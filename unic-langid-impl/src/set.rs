@@ -0,0 +1,277 @@
+use crate::LanguageIdentifier;
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+use tinystr::{TinyStr4, TinyStr8};
+
+const LANGUAGE_MASK: u128 = (u64::MAX as u128) << 64;
+const SCRIPT_MASK: u128 = (u32::MAX as u128) << 32;
+const REGION_MASK: u128 = u32::MAX as u128;
+
+/// An identifier packed into integers, with `0` standing for a missing subtag.
+///
+/// The language occupies the most significant bits of `subtags`, so keys
+/// sharing a language are adjacent in a sorted set.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Key {
+    subtags: u128,
+    variants: Box<[u64]>,
+}
+
+impl Key {
+    fn new(langid: &LanguageIdentifier) -> Self {
+        let language: u64 = langid.language.map_or(0, Into::into);
+        let script: u32 = langid.script.map_or(0, Into::into);
+        let region: u32 = langid.region.map_or(0, Into::into);
+        Self {
+            subtags: u128::from(language) << 64 | u128::from(script) << 32 | u128::from(region),
+            variants: langid
+                .variants
+                .iter()
+                .flat_map(|variants| variants.iter())
+                .map(|variant| (*variant).into())
+                .collect(),
+        }
+    }
+
+    fn to_langid(&self) -> LanguageIdentifier {
+        let language = (self.subtags >> 64) as u64;
+        let script = (self.subtags >> 32) as u32;
+        let region = self.subtags as u32;
+        let variants = if self.variants.is_empty() {
+            None
+        } else {
+            Some(
+                self.variants
+                    .iter()
+                    .map(|variant| unsafe { TinyStr8::new_unchecked(*variant) })
+                    .collect(),
+            )
+        };
+        // The subtags were taken from a well-formed identifier in `Key::new`.
+        unsafe {
+            LanguageIdentifier::from_raw_parts_unchecked(
+                Some(language)
+                    .filter(|l| *l != 0)
+                    .map(|l| TinyStr8::new_unchecked(l)),
+                Some(script)
+                    .filter(|s| *s != 0)
+                    .map(|s| TinyStr4::new_unchecked(s)),
+                Some(region)
+                    .filter(|r| *r != 0)
+                    .map(|r| TinyStr4::new_unchecked(r)),
+                variants,
+            )
+        }
+    }
+
+    /// Equivalent of `LanguageIdentifier::matches(range, false, true)`.
+    fn matches(&self, range: &Self) -> bool {
+        [LANGUAGE_MASK, SCRIPT_MASK, REGION_MASK]
+            .iter()
+            .all(|mask| {
+                let subtag = range.subtags & mask;
+                subtag == 0 || subtag == self.subtags & mask
+            })
+            && (range.variants.is_empty() || range.variants == self.variants)
+    }
+}
+
+/// A set of `LanguageIdentifier`s, such as the locales supported by an application.
+///
+/// Identifiers are stored as packed integer keys, sorted by language, so
+/// checking whether a requested identifier is covered by the set only
+/// compares the keys sharing its language.
+///
+/// Set operations treat missing subtags as wildcards: `en` covers `en-US`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LangIdSet, LanguageIdentifier};
+///
+/// let supported: LangIdSet = ["en-US", "en-GB", "fr"].iter()
+///     .map(|s| s.parse().expect("Parsing failed."))
+///     .collect();
+///
+/// let en: LanguageIdentifier = "en".parse()
+///     .expect("Parsing failed.");
+/// let fr_ca: LanguageIdentifier = "fr-CA".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(supported.contains(&en), false);
+/// assert_eq!(supported.matches_any(&en), true);
+/// assert_eq!(supported.matches_any(&fr_ca), false);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LangIdSet {
+    keys: BTreeSet<Key>,
+}
+
+impl LangIdSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `LanguageIdentifier` to the set.
+    ///
+    /// Returns `false` if the set already contained it.
+    pub fn insert(&mut self, langid: &LanguageIdentifier) -> bool {
+        self.keys.insert(Key::new(langid))
+    }
+
+    /// Removes a `LanguageIdentifier` from the set.
+    ///
+    /// Returns `false` if the set did not contain it.
+    pub fn remove(&mut self, langid: &LanguageIdentifier) -> bool {
+        self.keys.remove(&Key::new(langid))
+    }
+
+    /// Returns `true` if the set contains an identifier equal to `langid`.
+    pub fn contains(&self, langid: &LanguageIdentifier) -> bool {
+        self.keys.contains(&Key::new(langid))
+    }
+
+    /// Returns `true` if any identifier in the set falls within `range`.
+    ///
+    /// Missing subtags of `range` are treated as wildcards, as in
+    /// `langid.matches(range, false, true)`.
+    pub fn matches_any<O: AsRef<LanguageIdentifier>>(&self, range: &O) -> bool {
+        self.matches_key(&Key::new(range.as_ref()))
+    }
+
+    /// Returns the number of identifiers in the set.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the identifiers in the set, sorted by their packed subtags.
+    pub fn iter(&self) -> impl Iterator<Item = LanguageIdentifier> + '_ {
+        self.keys.iter().map(Key::to_langid)
+    }
+
+    /// Returns the union of both sets.
+    ///
+    /// Identifiers covered by a broader identifier of the other set are
+    /// left out, so the result covers exactly what either set covers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LangIdSet;
+    ///
+    /// let set1: LangIdSet = ["en", "fr-FR"].iter()
+    ///     .map(|s| s.parse().expect("Parsing failed."))
+    ///     .collect();
+    /// let set2: LangIdSet = ["en-US", "de"].iter()
+    ///     .map(|s| s.parse().expect("Parsing failed."))
+    ///     .collect();
+    ///
+    /// let union: Vec<String> = set1.union(&set2).iter()
+    ///     .map(|langid| langid.to_string())
+    ///     .collect();
+    /// assert_eq!(union, vec!["de", "en", "fr-FR"]);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let keys = self
+            .keys
+            .iter()
+            .filter(|key| !other.covers(key, true))
+            .chain(other.keys.iter().filter(|key| !self.covers(key, false)))
+            .cloned()
+            .collect();
+        Self { keys }
+    }
+
+    /// Returns the intersection of both sets.
+    ///
+    /// The result contains the identifiers of either set which are covered
+    /// by an identifier of the other set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LangIdSet;
+    ///
+    /// let set1: LangIdSet = ["en", "fr-FR", "pl"].iter()
+    ///     .map(|s| s.parse().expect("Parsing failed."))
+    ///     .collect();
+    /// let set2: LangIdSet = ["en-US", "en-GB", "fr"].iter()
+    ///     .map(|s| s.parse().expect("Parsing failed."))
+    ///     .collect();
+    ///
+    /// let intersection: Vec<String> = set1.intersection(&set2).iter()
+    ///     .map(|langid| langid.to_string())
+    ///     .collect();
+    /// assert_eq!(intersection, vec!["en-GB", "en-US", "fr-FR"]);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let keys = self
+            .keys
+            .iter()
+            .filter(|key| other.covers(key, false))
+            .chain(other.keys.iter().filter(|key| self.covers(key, false)))
+            .cloned()
+            .collect();
+        Self { keys }
+    }
+
+    /// Returns the keys with the given packed language.
+    fn with_language(&self, language: u128) -> impl Iterator<Item = &Key> {
+        let start = Key {
+            subtags: language,
+            variants: Box::new([]),
+        };
+        self.keys
+            .range(start..)
+            .take_while(move |key| key.subtags & LANGUAGE_MASK == language)
+    }
+
+    fn matches_key(&self, range: &Key) -> bool {
+        let language = range.subtags & LANGUAGE_MASK;
+        if language == 0 {
+            self.keys.iter().any(|key| key.matches(range))
+        } else {
+            self.with_language(language).any(|key| key.matches(range))
+        }
+    }
+
+    /// Returns `true` if `key` falls within a key of the set, other than
+    /// `key` itself if `strictly` is set.
+    fn covers(&self, key: &Key, strictly: bool) -> bool {
+        let language = key.subtags & LANGUAGE_MASK;
+        let mut ranges = self.with_language(0);
+        if language == 0 {
+            ranges.any(|range| !(strictly && range == key) && key.matches(range))
+        } else {
+            ranges
+                .chain(self.with_language(language))
+                .any(|range| !(strictly && range == key) && key.matches(range))
+        }
+    }
+}
+
+impl FromIterator<LanguageIdentifier> for LangIdSet {
+    fn from_iter<I: IntoIterator<Item = LanguageIdentifier>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<LanguageIdentifier> for LangIdSet {
+    fn extend<I: IntoIterator<Item = LanguageIdentifier>>(&mut self, iter: I) {
+        for langid in iter {
+            self.insert(&langid);
+        }
+    }
+}
+
+impl From<Vec<LanguageIdentifier>> for LangIdSet {
+    fn from(langids: Vec<LanguageIdentifier>) -> Self {
+        langids.into_iter().collect()
+    }
+}
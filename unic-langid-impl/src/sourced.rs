@@ -0,0 +1,94 @@
+use crate::{LanguageIdentifier, LanguageIdentifierError, ParserOptions};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// A `LanguageIdentifier` which retains the original input it was parsed from.
+///
+/// Parsing normalizes separators and casing, so serializing a parsed identifier
+/// does not necessarily reproduce its input. `SourcedLanguageIdentifier` keeps the
+/// input around and serializes back into it, which allows tools rewriting files
+/// to leave the identifiers they don't change untouched.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::SourcedLanguageIdentifier;
+///
+/// let li: SourcedLanguageIdentifier = "en_us".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(li.get_region(), Some("US"));
+/// assert_eq!(li.source(), "en_us");
+/// assert_eq!(li.to_string(), "en_us");
+/// assert_eq!(li.is_canonical(), false);
+///
+/// assert_eq!(li.into_inner().to_string(), "en-US");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct SourcedLanguageIdentifier {
+    langid: LanguageIdentifier,
+    source: String,
+}
+
+impl SourcedLanguageIdentifier {
+    /// Parses the input according to the given `ParserOptions`, retaining the input.
+    pub fn from_str_with(
+        input: &str,
+        options: &ParserOptions,
+    ) -> Result<Self, LanguageIdentifierError> {
+        let langid = LanguageIdentifier::from_str_with(input, options)?;
+        Ok(Self {
+            langid,
+            source: input.to_string(),
+        })
+    }
+
+    /// Returns the original input.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` if the original input is the serialization of the parsed identifier.
+    pub fn is_canonical(&self) -> bool {
+        self.langid.to_string() == self.source
+    }
+
+    /// Returns the inner `LanguageIdentifier`.
+    pub fn into_inner(self) -> LanguageIdentifier {
+        self.langid
+    }
+
+    /// Returns the inner `LanguageIdentifier` and the original input.
+    pub fn into_parts(self) -> (LanguageIdentifier, String) {
+        (self.langid, self.source)
+    }
+}
+
+impl FromStr for SourcedLanguageIdentifier {
+    type Err = LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(source, &ParserOptions::default())
+    }
+}
+
+impl Deref for SourcedLanguageIdentifier {
+    type Target = LanguageIdentifier;
+
+    fn deref(&self) -> &LanguageIdentifier {
+        &self.langid
+    }
+}
+
+impl AsRef<LanguageIdentifier> for SourcedLanguageIdentifier {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        &self.langid
+    }
+}
+
+impl std::fmt::Display for SourcedLanguageIdentifier {
+    /// Writes the original input.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
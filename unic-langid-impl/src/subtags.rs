@@ -1,20 +1,27 @@
 use crate::parser::errors::ParserError;
+use crate::tinystr_ext::TinyStrExt;
 use tinystr::{TinyStr4, TinyStr8};
 
+// "und"
+const UND: TinyStr8 = unsafe { TinyStr8::new_unchecked(6_581_877) };
+// "root", which CLDR uses as a synonym for "und".
+const ROOT: TinyStr8 = unsafe { TinyStr8::new_unchecked(1_953_460_082) };
+
 pub fn parse_language_subtag(subtag: &str) -> Result<Option<TinyStr8>, ParserError> {
     let slen = subtag.len();
 
     let s: TinyStr8 = subtag.parse().map_err(|_| ParserError::InvalidLanguage)?;
+    if s.eq_ignore_ascii_case(&ROOT) {
+        return Ok(None);
+    }
     if slen < 2 || slen > 8 || slen == 4 || !s.is_ascii_alphabetic() {
         return Err(ParserError::InvalidLanguage);
     }
 
-    let value = s.to_ascii_lowercase();
-
-    if value == "und" {
+    if s.eq_ignore_ascii_case(&UND) {
         Ok(None)
     } else {
-        Ok(Some(value))
+        Ok(Some(s.to_ascii_lowercase()))
     }
 }
 
@@ -71,3 +78,52 @@ pub fn parse_variant_subtag(subtag: &str) -> Result<TinyStr8, ParserError> {
 
     Ok(s.to_ascii_lowercase())
 }
+
+pub fn parse_canonical_language_subtag(subtag: &str) -> Option<TinyStr8> {
+    let slen = subtag.len();
+    if !(2..=8).contains(&slen) || slen == 4 {
+        return None;
+    }
+    let s: TinyStr8 = subtag.parse().ok()?;
+    if s.is_ascii_alphabetic() && s.is_ascii_lowercase() {
+        Some(s)
+    } else {
+        None
+    }
+}
+
+pub fn parse_canonical_script_subtag(subtag: &str) -> Option<TinyStr4> {
+    if subtag.len() != 4 {
+        return None;
+    }
+    let s: TinyStr4 = subtag.parse().ok()?;
+    if s.is_ascii_alphabetic() && s.is_ascii_titlecase() {
+        Some(s)
+    } else {
+        None
+    }
+}
+
+pub fn parse_canonical_region_subtag(subtag: &str) -> Option<TinyStr4> {
+    let s: TinyStr4 = match subtag.len() {
+        2 => subtag.parse().ok().filter(|s: &TinyStr4| {
+            s.is_ascii_alphabetic() && s.is_ascii_uppercase()
+        })?,
+        3 if subtag.bytes().all(|b| b.is_ascii_digit()) => subtag.parse().ok()?,
+        _ => return None,
+    };
+    Some(s)
+}
+
+pub fn parse_canonical_variant_subtag(subtag: &str) -> Option<TinyStr8> {
+    let slen = subtag.len();
+    if !(4..=8).contains(&slen) || (slen == 4 && !subtag.as_bytes()[0].is_ascii_digit()) {
+        return None;
+    }
+    let s: TinyStr8 = subtag.parse().ok()?;
+    if s.is_ascii_alphanumeric() && s.is_ascii_lowercase() {
+        Some(s)
+    } else {
+        None
+    }
+}
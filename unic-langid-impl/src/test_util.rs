@@ -0,0 +1,146 @@
+//! Helpers for testing code which works with language identifiers.
+//!
+//! The module is available with `feature = "test-util"`, which is meant to be
+//! enabled in `[dev-dependencies]` only.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::{assert_langid_eq, assert_langid_matches};
+//! use unic_langid_impl::test_util::{langid, sample_langids};
+//!
+//! assert_langid_eq!(langid("en_us"), "en-US");
+//! assert_langid_matches!("en", "en-US");
+//! assert_langid_matches!("en-GB", "en", false, true);
+//!
+//! let samples = sample_langids(10, 7);
+//! assert_eq!(samples.len(), 10);
+//! assert_eq!(samples, sample_langids(10, 7));
+//! ```
+use crate::LanguageIdentifier;
+
+/// Values which can be turned into a `LanguageIdentifier` by the assertion macros.
+///
+/// Strings are parsed, panicking if they are not well-formed.
+pub trait ToLanguageIdentifier {
+    fn to_langid(&self) -> LanguageIdentifier;
+}
+
+impl ToLanguageIdentifier for LanguageIdentifier {
+    fn to_langid(&self) -> LanguageIdentifier {
+        self.clone()
+    }
+}
+
+impl ToLanguageIdentifier for str {
+    fn to_langid(&self) -> LanguageIdentifier {
+        langid(self)
+    }
+}
+
+impl ToLanguageIdentifier for String {
+    fn to_langid(&self) -> LanguageIdentifier {
+        langid(self)
+    }
+}
+
+impl<T: ToLanguageIdentifier + ?Sized> ToLanguageIdentifier for &T {
+    fn to_langid(&self) -> LanguageIdentifier {
+        (**self).to_langid()
+    }
+}
+
+/// Asserts that two language identifiers are equal.
+///
+/// Either side can be a `LanguageIdentifier` or a string, which is parsed first,
+/// so `"en_us"` is equal to `"en-US"`.
+#[macro_export]
+macro_rules! assert_langid_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        use $crate::test_util::ToLanguageIdentifier;
+        let left = (&$left).to_langid();
+        let right = (&$right).to_langid();
+        if left != right {
+            panic!("assertion failed: `{}` does not equal `{}`", left, right);
+        }
+    }};
+}
+
+/// Asserts that two language identifiers match, as in `LanguageIdentifier::matches`.
+///
+/// Either side can be a `LanguageIdentifier` or a string, which is parsed first.
+/// By default missing subtags on both sides are treated as wildcards,
+/// which can be changed by passing the two flags of `matches`.
+#[macro_export]
+macro_rules! assert_langid_matches {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_langid_matches!($left, $right, true, true)
+    };
+    ($left:expr, $right:expr, $left_as_range:expr, $right_as_range:expr $(,)?) => {{
+        use $crate::test_util::ToLanguageIdentifier;
+        let left = (&$left).to_langid();
+        let right = (&$right).to_langid();
+        if !left.matches(&right, $left_as_range, $right_as_range) {
+            panic!(
+                "assertion failed: `{}` does not match `{}` (left_as_range: {}, right_as_range: {})",
+                left, right, $left_as_range, $right_as_range
+            );
+        }
+    }};
+}
+
+/// Parses a `LanguageIdentifier`, panicking with the input if it is not well-formed.
+pub fn langid(input: &str) -> LanguageIdentifier {
+    input
+        .parse()
+        .unwrap_or_else(|err| panic!("Malformed Language Identifier {:?}: {:?}", input, err))
+}
+
+/// Parses a list of `LanguageIdentifier`s, panicking if any of them is not well-formed.
+pub fn langids(input: &[&str]) -> Vec<LanguageIdentifier> {
+    input.iter().map(|s| langid(s)).collect()
+}
+
+const SAMPLE_LANGUAGES: &[&str] = &[
+    "en", "de", "fr", "es", "pt", "zh", "ja", "ar", "ru", "sr", "pl", "fil", "yue", "und",
+];
+const SAMPLE_SCRIPTS: &[&str] = &["Latn", "Cyrl", "Arab", "Hans", "Hant", "Jpan"];
+const SAMPLE_REGIONS: &[&str] = &[
+    "US", "GB", "DE", "FR", "BR", "CN", "TW", "RS", "PL", "EG", "419", "001",
+];
+const SAMPLE_VARIANTS: &[&str] = &["posix", "valencia", "1996", "fonipa"];
+
+/// Generates `count` well-formed language identifiers for use as test inputs.
+///
+/// The identifiers combine common languages, scripts, regions and variants.
+/// The same `seed` always produces the same list.
+pub fn sample_langids(count: usize, seed: u64) -> Vec<LanguageIdentifier> {
+    // xorshift64 requires a non-zero state.
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    let mut next = |bound: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % bound as u64) as usize
+    };
+
+    (0..count)
+        .map(|_| {
+            let language = SAMPLE_LANGUAGES[next(SAMPLE_LANGUAGES.len())];
+            let script = match next(3) {
+                0 => Some(SAMPLE_SCRIPTS[next(SAMPLE_SCRIPTS.len())]),
+                _ => None,
+            };
+            let region = match next(3) {
+                0 => None,
+                _ => Some(SAMPLE_REGIONS[next(SAMPLE_REGIONS.len())]),
+            };
+            let variants: &[&str] = match next(6) {
+                0 => &SAMPLE_VARIANTS[next(SAMPLE_VARIANTS.len())..],
+                _ => &[],
+            };
+            LanguageIdentifier::from_parts(Some(language), script, region, variants)
+                .expect("Sample subtags are well-formed.")
+        })
+        .collect()
+}
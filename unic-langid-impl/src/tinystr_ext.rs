@@ -0,0 +1,90 @@
+use tinystr::{TinyStr4, TinyStr8};
+
+/// Helpers operating on `TinyStr` values which are not (yet) provided by `tinystr`.
+///
+/// All of them work on the whole word at once rather than on individual bytes.
+pub trait TinyStrExt {
+    /// Compares two values ignoring the ASCII case of their characters.
+    ///
+    /// This allows comparing raw input subtags against stored, normalized ones,
+    /// without producing normalized copies first.
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool;
+
+    /// Returns `true` if the value contains no ASCII uppercase letters.
+    fn is_ascii_lowercase(&self) -> bool;
+
+    /// Returns `true` if the value contains no ASCII lowercase letters.
+    fn is_ascii_uppercase(&self) -> bool;
+
+    /// Returns `true` if the first character is not an ASCII lowercase letter,
+    /// and the remaining ones are not ASCII uppercase letters.
+    fn is_ascii_titlecase(&self) -> bool;
+}
+
+// For each byte of an ASCII word, the high bit of the mask is set if the byte
+// falls into `A..=Z` (or `a..=z`). Adding the offsets never carries across bytes.
+// Shifting a mask right by two moves that bit onto the `0x20` case bit.
+macro_rules! ascii_case_masks {
+    ($word:expr, $t:ty) => {{
+        let word = $word;
+        let hi: $t = <$t>::from_le_bytes([0x80; std::mem::size_of::<$t>()]);
+        let rep = |b: u8| <$t>::from_le_bytes([b; std::mem::size_of::<$t>()]);
+        let upper = (word + rep(0x3f)) & !(word + rep(0x25)) & hi;
+        let lower = (word + rep(0x1f)) & !(word + rep(0x05)) & hi;
+        (upper, lower)
+    }};
+}
+
+impl TinyStrExt for TinyStr4 {
+    #[inline]
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        let word = Into::<u32>::into(*self);
+        let (upper, lower) = ascii_case_masks!(word, u32);
+        (word ^ Into::<u32>::into(*other)) & !((upper | lower) >> 2) == 0
+    }
+
+    #[inline]
+    fn is_ascii_lowercase(&self) -> bool {
+        let (upper, _) = ascii_case_masks!(Into::<u32>::into(*self), u32);
+        upper == 0
+    }
+
+    #[inline]
+    fn is_ascii_uppercase(&self) -> bool {
+        let (_, lower) = ascii_case_masks!(Into::<u32>::into(*self), u32);
+        lower == 0
+    }
+
+    #[inline]
+    fn is_ascii_titlecase(&self) -> bool {
+        let (upper, lower) = ascii_case_masks!(Into::<u32>::into(*self), u32);
+        lower & 0x80 == 0 && upper & !0x80 == 0
+    }
+}
+
+impl TinyStrExt for TinyStr8 {
+    #[inline]
+    fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        let word = Into::<u64>::into(*self);
+        let (upper, lower) = ascii_case_masks!(word, u64);
+        (word ^ Into::<u64>::into(*other)) & !((upper | lower) >> 2) == 0
+    }
+
+    #[inline]
+    fn is_ascii_lowercase(&self) -> bool {
+        let (upper, _) = ascii_case_masks!(Into::<u64>::into(*self), u64);
+        upper == 0
+    }
+
+    #[inline]
+    fn is_ascii_uppercase(&self) -> bool {
+        let (_, lower) = ascii_case_masks!(Into::<u64>::into(*self), u64);
+        lower == 0
+    }
+
+    #[inline]
+    fn is_ascii_titlecase(&self) -> bool {
+        let (upper, lower) = ascii_case_masks!(Into::<u64>::into(*self), u64);
+        lower & 0x80 == 0 && upper & !0x80 == 0
+    }
+}
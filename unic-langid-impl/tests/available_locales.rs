@@ -0,0 +1,39 @@
+use unic_langid_impl::{available_locales, data_versions, CldrCoverage, LanguageIdentifier};
+
+#[test]
+fn is_cldr_locale_test() {
+    let entries = vec![
+        ("en", true),
+        ("en-GB", true),
+        ("ca-ES-valencia", true),
+        ("sr-Latn-BA", true),
+        ("zh-Hant-TW", false),
+        ("tlh", false),
+        ("und", false),
+    ];
+
+    for (input, expected) in entries {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(li.is_cldr_locale(CldrCoverage::Modern), expected, "{}", input);
+    }
+}
+
+#[test]
+#[cfg(feature = "likelysubtags-remove")]
+fn is_cldr_locale_default_content_test() {
+    for input in &["en-US", "de-DE", "zh-CN", "sr-RS"] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert!(li.is_cldr_locale(CldrCoverage::Modern), "{}", input);
+    }
+}
+
+#[test]
+fn available_locales_test() {
+    let locales: Vec<LanguageIdentifier> = available_locales(CldrCoverage::Modern).collect();
+
+    assert_eq!(locales.len(), 369);
+    assert!(locales.contains(&"en-US-posix".parse().unwrap()));
+    assert!(locales.iter().all(|l| l.is_cldr_locale(CldrCoverage::Modern)));
+
+    assert_eq!(data_versions().available_locales, Some("35.1"));
+}
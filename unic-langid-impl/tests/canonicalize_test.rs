@@ -10,3 +10,38 @@ fn test_canonicalize() {
     assert_canonicalize("eN-uS", "en-US");
     assert_canonicalize("ZH_hans_hK", "zh-Hans-HK");
 }
+
+#[test]
+fn test_canonicalize_iso639() {
+    assert_canonicalize("ger", "de");
+    assert_canonicalize("DEU_at", "de-AT");
+    assert_canonicalize("chi-Hant-TW", "zh-Hant-TW");
+    assert_canonicalize("haw", "haw");
+    assert_canonicalize("tgl", "fil");
+    assert_canonicalize("tl-PH", "fil-PH");
+    assert_canonicalize("nob", "nb");
+    assert_canonicalize("iw", "iw");
+    assert_canonicalize("cmn", "cmn");
+    assert_canonicalize("hbs", "hbs");
+}
+
+#[test]
+fn test_canonicalize_script_alias() {
+    assert_canonicalize("und-qaai", "und-Zinh");
+    assert_canonicalize("cop-Qaac-EG", "cop-Copt-EG");
+}
+
+#[test]
+fn test_canonicalize_cow() {
+    use std::borrow::Cow;
+    use unic_langid_impl::canonicalize_cow;
+
+    for input in &["und", "en-US", "sr-Cyrl-RS-1996-ekavsk", "und-Zinh"] {
+        assert_eq!(canonicalize_cow(input), Ok(Cow::Borrowed(*input)));
+    }
+    for input in &["EN-us", "en_US", "ger-DE", "und-Qaai", "de-nedis-macos"] {
+        let result = canonicalize_cow(input).unwrap();
+        assert!(matches!(result, Cow::Owned(_)), "{}", input);
+        assert_eq!(Ok(result.into_owned()), canonicalize(input));
+    }
+}
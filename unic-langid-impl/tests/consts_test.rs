@@ -0,0 +1,22 @@
+use unic_langid_impl::consts::{self, ALL, EN_US, ES_419, SR_LATN, ZH_HANT_TW};
+use unic_langid_impl::LanguageIdentifier;
+
+static DEFAULT: LanguageIdentifier = EN_US;
+
+#[test]
+fn test_consts() {
+    assert_eq!(DEFAULT.to_string(), "en-US");
+    assert_eq!(ES_419.get_region(), Some("419"));
+    assert_eq!(SR_LATN.to_string(), "sr-Latn");
+    assert_eq!(ZH_HANT_TW.to_string(), "zh-Hant-TW");
+    assert_eq!(consts::DE.get_language(), "de");
+    assert_eq!(consts::FIL.get_language(), "fil");
+}
+
+#[test]
+fn test_consts_are_canonical() {
+    for li in ALL {
+        let parsed: LanguageIdentifier = li.to_string().parse().unwrap();
+        assert_eq!(&parsed, li);
+    }
+}
@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::{CoreLanguageIdentifier, LanguageIdentifier, LanguageIdentifierError};
+
+#[test]
+fn test_core_langid_size() {
+    assert_eq!(std::mem::size_of::<CoreLanguageIdentifier>(), 16);
+}
+
+#[test]
+fn test_core_langid_conversions() {
+    for input in &["und", "en", "en-US", "sr-Cyrl", "zh-Hant-TW", "und-419"] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let core = CoreLanguageIdentifier::try_from(&langid).unwrap();
+        assert_eq!(core.to_string(), *input);
+        assert_eq!(core.get_language(), langid.get_language());
+        assert_eq!(core.get_script(), langid.get_script());
+        assert_eq!(core.get_region(), langid.get_region());
+        assert_eq!(LanguageIdentifier::from(core), langid);
+        assert_eq!(input.parse::<CoreLanguageIdentifier>(), Ok(core));
+    }
+    assert_eq!(
+        CoreLanguageIdentifier::default(),
+        CoreLanguageIdentifier::UND
+    );
+}
+
+#[test]
+fn test_core_langid_variants() {
+    let langid: LanguageIdentifier = "de-CH-1996".parse().unwrap();
+    assert_eq!(langid.to_core().to_string(), "de-CH");
+    assert_eq!(
+        CoreLanguageIdentifier::try_from(langid),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidSubtag
+        ))
+    );
+    assert!("de-CH-1996".parse::<CoreLanguageIdentifier>().is_err());
+}
+
+#[test]
+fn test_core_langid_matches() {
+    let en: CoreLanguageIdentifier = "en".parse().unwrap();
+    let en_us: CoreLanguageIdentifier = "en-US".parse().unwrap();
+    assert!(en.matches(&en_us, true, false));
+    assert!(!en.matches(&en_us, false, true));
+    assert!(en_us.matches(&en_us, false, false));
+}
@@ -0,0 +1,39 @@
+use std::convert::TryFrom;
+
+use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+
+#[test]
+fn langid_to_icu_test() {
+    for input in &[
+        "en-US",
+        "und",
+        "sr-Latn-RS",
+        "de-CH-1996",
+        "es-419",
+        "und-Arab",
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let icu = icu_locid::LanguageIdentifier::try_from(&langid).unwrap();
+        assert_eq!(icu.to_string(), *input);
+        assert_eq!(LanguageIdentifier::from(&icu), langid);
+        assert_eq!(LanguageIdentifier::from(icu), langid);
+    }
+
+    let options = ParserOptions {
+        preserve_variant_order: true,
+        ..ParserOptions::default()
+    };
+    let langid = LanguageIdentifier::from_str_with("sl-rozaj-biske-1994", &options).unwrap();
+    let icu = icu_locid::LanguageIdentifier::try_from(langid).unwrap();
+    assert_eq!(icu.to_string(), "sl-1994-biske-rozaj");
+
+    let langid: LanguageIdentifier = "abcdef-US".parse().unwrap();
+    assert!(icu_locid::LanguageIdentifier::try_from(&langid).is_err());
+}
+
+#[test]
+fn icu_to_langid_test() {
+    let icu: icu_locid::LanguageIdentifier = "EN_latn_us-Valencia".parse().unwrap();
+    let langid = LanguageIdentifier::from(&icu);
+    assert_eq!(langid.to_string(), "en-Latn-US-valencia");
+}
@@ -0,0 +1,89 @@
+use unic_langid_impl::{LangIdSet, LanguageIdentifier};
+
+fn langids(input: &[&str]) -> Vec<LanguageIdentifier> {
+    input.iter().map(|s| s.parse().unwrap()).collect()
+}
+
+fn sorted(set: &LangIdSet) -> Vec<String> {
+    let mut result: Vec<String> = set.iter().map(|langid| langid.to_string()).collect();
+    result.sort();
+    result
+}
+
+#[test]
+fn test_langid_set_matches_any_linear_scan() {
+    let available = langids(&[
+        "en",
+        "en-US",
+        "en-Latn-US",
+        "en-US-posix",
+        "und",
+        "und-US",
+        "sr-Cyrl",
+        "sr-Latn-RS",
+        "de-CH-1996",
+        "zh-Hant-TW",
+    ]);
+    let requested = langids(&[
+        "en",
+        "en-US",
+        "en-Latn",
+        "und",
+        "und-US",
+        "sr-RS",
+        "de-CH-1996",
+        "de-1996",
+        "de-AT",
+        "pl",
+        "zh-TW",
+        "und-Hant",
+    ]);
+    let set: LangIdSet = available.clone().into();
+    assert_eq!(set.len(), available.len());
+
+    for req in &requested {
+        let expected = available.iter().any(|a| a.matches(req, false, true));
+        assert_eq!(set.matches_any(req), expected, "{}", req);
+    }
+    for langid in &available {
+        assert!(set.contains(langid));
+    }
+    assert!(!set.contains(&"en-GB".parse().unwrap()));
+}
+
+#[test]
+fn test_langid_set_insert_remove() {
+    let mut set = LangIdSet::new();
+    let langid: LanguageIdentifier = "sr-Cyrl-RS-1996-ekavsk".parse().unwrap();
+    assert!(set.is_empty());
+    assert!(set.insert(&langid));
+    assert!(!set.insert(&langid));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![langid.clone()]);
+    assert!(set.remove(&langid));
+    assert!(!set.remove(&langid));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_langid_set_union() {
+    let set1: LangIdSet = langids(&["en", "fr-FR", "pl-PL"]).into();
+    let set2: LangIdSet = langids(&["en-US", "fr", "de", "pl-PL"]).into();
+    assert_eq!(sorted(&set1.union(&set2)), vec!["de", "en", "fr", "pl-PL"]);
+    assert_eq!(set1.union(&set2), set2.union(&set1));
+    assert_eq!(set1.union(&LangIdSet::new()), set1);
+
+    let set3: LangIdSet = langids(&["und"]).into();
+    assert_eq!(sorted(&set1.union(&set3)), vec!["und"]);
+}
+
+#[test]
+fn test_langid_set_intersection() {
+    let set1: LangIdSet = langids(&["en", "fr-FR", "pl", "de-AT"]).into();
+    let set2: LangIdSet = langids(&["en-US", "en-GB", "fr", "pl", "de-CH"]).into();
+    assert_eq!(
+        sorted(&set1.intersection(&set2)),
+        vec!["en-GB", "en-US", "fr-FR", "pl"]
+    );
+    assert_eq!(set1.intersection(&set2), set2.intersection(&set1));
+    assert!(set1.intersection(&LangIdSet::new()).is_empty());
+}
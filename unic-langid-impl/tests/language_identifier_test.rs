@@ -1,8 +1,10 @@
 use tinystr::{TinyStr4, TinyStr8};
 use unic_langid_impl::parser::errors::ParserError;
 use unic_langid_impl::parser::parse_language_identifier;
-use unic_langid_impl::CharacterDirection;
-use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+#[cfg(feature = "layout")]
+use unic_langid_impl::{CharacterDirection, LineOrientation};
+use unic_langid_impl::{data_versions, LanguageIdentifier, LanguageIdentifierError, ParserOptions};
+use unic_langid_impl::{RecoveredLanguageIdentifier, SkippedSubtag};
 
 fn assert_language_identifier(
     loc: &LanguageIdentifier,
@@ -43,6 +45,7 @@ fn test_language_casing() {
     assert_parsed_language_identifier("En-uS", Some("en"), None, Some("US"), None);
     assert_parsed_language_identifier("eN-lAtN-uS", Some("en"), Some("Latn"), Some("US"), None);
     assert_parsed_language_identifier("ZH_cyrl_hN", Some("zh"), Some("Cyrl"), Some("HN"), None);
+    assert_parsed_language_identifier("UnD-uS", None, None, Some("US"), None);
 }
 
 #[test]
@@ -87,6 +90,20 @@ fn test_from_parts_unchecked() {
     assert_eq!(&langid.to_string(), "en-macos-nedis");
 }
 
+#[test]
+fn test_into_tinystr_parts() {
+    let langid: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse().unwrap();
+    let (lang, script, region, variants) = langid.clone().into_tinystr_parts();
+    assert_eq!(lang, "sr".parse().ok());
+    assert_eq!(script, "Cyrl".parse().ok());
+    assert_eq!(region, "RS".parse().ok());
+    assert_eq!(variants.as_deref(), Some(&["ekavsk".parse().unwrap()][..]));
+
+    let langid2 =
+        unsafe { LanguageIdentifier::from_raw_parts_unchecked(lang, script, region, variants) };
+    assert_eq!(langid2, langid);
+}
+
 #[test]
 fn test_matches() {
     let langid_en: LanguageIdentifier = "en".parse().unwrap();
@@ -146,6 +163,7 @@ fn test_matches_as_range() {
     assert_eq!(langid.matches(&langid2, true, true), true);
 }
 
+#[cfg(feature = "layout")]
 #[test]
 fn test_character_direction() {
     let langid: LanguageIdentifier = "en-US".parse().unwrap();
@@ -153,3 +171,846 @@ fn test_character_direction() {
     assert_eq!(langid.get_character_direction(), CharacterDirection::LTR);
     assert_eq!(langid2.get_character_direction(), CharacterDirection::RTL);
 }
+
+#[cfg(feature = "layout")]
+#[test]
+fn test_try_character_direction() {
+    let assert_direction = |input: &str, direction| {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.try_get_character_direction(), direction, "{}", input);
+    };
+    assert_direction("en-US", Some(CharacterDirection::LTR));
+    assert_direction("fil", Some(CharacterDirection::LTR));
+    assert_direction("he-IL", Some(CharacterDirection::RTL));
+    assert_direction("tlh", None);
+    #[cfg(not(feature = "likelysubtags-add"))]
+    assert_direction("und-Arab", None);
+    #[cfg(feature = "likelysubtags-add")]
+    assert_direction("und-Arab", Some(CharacterDirection::RTL));
+}
+
+#[cfg(feature = "layout")]
+#[test]
+fn test_line_orientation() {
+    let assert_orientation = |input: &str, orientation| {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.get_line_orientation(), orientation);
+    };
+    assert_orientation("en-Latn-US", LineOrientation::Horizontal);
+    assert_orientation("ar-Arab", LineOrientation::Horizontal);
+    assert_orientation("zh-Hant-TW", LineOrientation::Mixed);
+    assert_orientation("ko-Kore", LineOrientation::Mixed);
+    assert_orientation("mn-Mong-CN", LineOrientation::Vertical);
+    assert_orientation("en", LineOrientation::Horizontal);
+
+    #[cfg(feature = "likelysubtags-add")]
+    assert_orientation("ja", LineOrientation::Mixed);
+    #[cfg(not(feature = "likelysubtags-add"))]
+    assert_orientation("ja", LineOrientation::Horizontal);
+}
+
+#[test]
+fn test_canonical_fast_path() {
+    assert_parsed_language_identifier("und-Latn", None, Some("Latn"), None, None);
+    assert_parsed_language_identifier("en-419", Some("en"), None, Some("419"), None);
+    assert_parsed_language_identifier(
+        "en-Latn-US-macos-nedis",
+        Some("en"),
+        Some("Latn"),
+        Some("US"),
+        Some(&["macos", "nedis"]),
+    );
+
+    // Unsorted and duplicated variants fall back to the full parser.
+    assert_parsed_language_identifier(
+        "en-nedis-macos-nedis",
+        Some("en"),
+        None,
+        None,
+        Some(&["macos", "nedis"]),
+    );
+
+    assert_eq!(
+        parse_language_identifier("en-US-"),
+        Err(ParserError::TrailingSeparator)
+    );
+}
+
+#[test]
+fn test_data_versions() {
+    let versions = data_versions();
+    #[cfg(feature = "layout")]
+    assert_eq!(versions.layout, Some("35.1"));
+    #[cfg(not(feature = "layout"))]
+    assert_eq!(versions.layout, None);
+    #[cfg(feature = "likelysubtags-add")]
+    assert_eq!(versions.likelysubtags, Some("35.1"));
+    #[cfg(not(feature = "likelysubtags-add"))]
+    assert_eq!(versions.likelysubtags, None);
+    assert_eq!(versions.language_aliases, "42");
+}
+
+#[test]
+fn test_diff() {
+    let langid: LanguageIdentifier = "en-Latn-US".parse().unwrap();
+    let langid2: LanguageIdentifier = "en-Cyrl-US-macos".parse().unwrap();
+    let langid3: LanguageIdentifier = "en-Latn-US".parse().unwrap();
+    let langid4: LanguageIdentifier = "de".parse().unwrap();
+
+    let diff = langid.diff(&langid2);
+    assert_eq!(diff.language, None);
+    assert_eq!(diff.script, Some((Some("Latn"), Some("Cyrl"))));
+    assert_eq!(diff.region, None);
+    assert_eq!(diff.variants, Some((vec![], vec!["macos"])));
+    assert_eq!(
+        diff.to_string(),
+        "script mismatch: Latn vs Cyrl, variants mismatch: none vs macos"
+    );
+
+    let diff = langid.diff(&langid3);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "no mismatch");
+
+    let diff = langid.diff(&langid4);
+    assert_eq!(
+        diff.to_string(),
+        "language mismatch: en vs de, script mismatch: Latn vs none, region mismatch: US vs none"
+    );
+}
+
+#[test]
+fn test_subsumes() {
+    let und: LanguageIdentifier = "und".parse().unwrap();
+    let en: LanguageIdentifier = "en".parse().unwrap();
+    let en_gb: LanguageIdentifier = "en-GB".parse().unwrap();
+    let en_latn: LanguageIdentifier = "en-Latn".parse().unwrap();
+    let en_latn_gb: LanguageIdentifier = "en-Latn-GB".parse().unwrap();
+    let en_macos: LanguageIdentifier = "en-macos".parse().unwrap();
+    let en_gb_macos_nedis: LanguageIdentifier = "en-GB-macos-nedis".parse().unwrap();
+    let fr: LanguageIdentifier = "fr".parse().unwrap();
+
+    assert_eq!(und.subsumes(&en_gb), true);
+    assert_eq!(en.subsumes(&en), true);
+    assert_eq!(en.subsumes(&en_gb), true);
+    assert_eq!(en.subsumes(&en_latn_gb), true);
+    assert_eq!(en_latn.subsumes(&en_latn_gb), true);
+    assert_eq!(en_gb.subsumes(&en_latn_gb), true);
+    assert_eq!(en_macos.subsumes(&en_gb_macos_nedis), true);
+
+    assert_eq!(en_gb.subsumes(&en), false);
+    assert_eq!(en_latn.subsumes(&en_gb), false);
+    assert_eq!(en.subsumes(&fr), false);
+    assert_eq!(en_macos.subsumes(&en_gb), false);
+
+    // Unlike `matches`, variants are compared as sets.
+    assert_eq!(en_macos.matches(&en_gb_macos_nedis, true, false), false);
+}
+
+#[test]
+fn test_common_ancestor() {
+    let assert_ancestor = |a: &str, b: &str, expected: &str| {
+        let a: LanguageIdentifier = a.parse().unwrap();
+        let b: LanguageIdentifier = b.parse().unwrap();
+        let ancestor = a.common_ancestor(&b);
+        assert_eq!(ancestor.to_string(), expected);
+        assert_eq!(b.common_ancestor(&a), ancestor);
+        assert!(ancestor.subsumes(&a));
+        assert!(ancestor.subsumes(&b));
+    };
+
+    assert_ancestor("en-Latn-US", "en-Latn-GB", "en-Latn");
+    assert_ancestor("en-US", "en-US", "en-US");
+    assert_ancestor("en-US", "en", "en");
+    assert_ancestor("sr-Latn-RS", "sr-Cyrl-RS", "sr-RS");
+    assert_ancestor("en-US", "fr-FR", "und");
+    assert_ancestor("de-macos-nedis", "de-nedis-posix", "de-nedis");
+}
+
+#[test]
+fn test_parser_options() {
+    let default = ParserOptions::default();
+    assert_eq!(
+        LanguageIdentifier::from_str_with("en_US", &default),
+        "en-US".parse()
+    );
+
+    let options = ParserOptions {
+        allow_underscore: false,
+        ..ParserOptions::default()
+    };
+    assert!(LanguageIdentifier::from_str_with("en_US", &options).is_err());
+    assert!(LanguageIdentifier::from_str_with("en-US", &options).is_ok());
+
+    let options = ParserOptions {
+        allow_extension: true,
+        ..ParserOptions::default()
+    };
+    assert!(LanguageIdentifier::from_str_with("en-US-u-hc-h12", &default).is_err());
+    assert_eq!(
+        LanguageIdentifier::from_str_with("en-US-u-hc-h12", &options),
+        "en-US".parse()
+    );
+
+    let options = ParserOptions {
+        lenient: true,
+        ..ParserOptions::default()
+    };
+    assert_eq!(
+        LanguageIdentifier::from_str_with("en-Latn-US-a-macos-toolongvariant", &options),
+        "en-Latn-US-macos".parse()
+    );
+    assert!(LanguageIdentifier::from_str_with("e-US", &options).is_err());
+
+    let options = ParserOptions {
+        max_length: Some(5),
+        ..ParserOptions::default()
+    };
+    assert!(LanguageIdentifier::from_str_with("en-US", &options).is_ok());
+    assert_eq!(
+        LanguageIdentifier::from_str_with("en-Latn-US", &options),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InputTooLong
+        ))
+    );
+
+    let options = ParserOptions {
+        preserve_variant_order: true,
+        ..ParserOptions::default()
+    };
+    let li = LanguageIdentifier::from_str_with("sl_ROZAJ-biske-1994-biske", &options).unwrap();
+    assert_eq!(li.get_variants(), vec!["rozaj", "biske", "1994"]);
+    assert_eq!(li.to_string(), "sl-rozaj-biske-1994");
+    let canonical: LanguageIdentifier = "sl-1994-biske-rozaj".parse().unwrap();
+    assert_ne!(li, canonical);
+    assert!(li.eq_canonical(&canonical));
+    assert!(!li.eq_canonical(&"sl-biske-rozaj".parse::<LanguageIdentifier>().unwrap()));
+}
+
+#[test]
+fn test_parser_separators() {
+    assert_eq!(
+        "sR_cyrl-Rs_1994".parse::<LanguageIdentifier>(),
+        "sr-Cyrl-RS-1994".parse()
+    );
+    assert!("".parse::<LanguageIdentifier>().is_err());
+    assert!("en--US".parse::<LanguageIdentifier>().is_err());
+    assert!("en-US-".parse::<LanguageIdentifier>().is_err());
+    assert!("en-US_".parse::<LanguageIdentifier>().is_err());
+    assert!("en-\u{dc}S".parse::<LanguageIdentifier>().is_err());
+    assert!("en-Latn-\u{e9}t\u{e9}".parse::<LanguageIdentifier>().is_err());
+}
+
+#[test]
+fn test_strict_parsing() {
+    assert!(LanguageIdentifier::from_str_strict("en").is_ok());
+    assert!(LanguageIdentifier::from_str_strict("und-Latn-419").is_ok());
+    assert!(LanguageIdentifier::from_str_strict("ca-ES-valencia").is_ok());
+    assert!(LanguageIdentifier::from_str_strict("en-macos-nedis").is_ok());
+
+    assert_eq!(
+        LanguageIdentifier::from_str_strict("en_US"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidLanguage
+        ))
+    );
+    assert_eq!(
+        LanguageIdentifier::from_str_strict("EN-us"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::NonCanonical
+        ))
+    );
+    assert_eq!(
+        LanguageIdentifier::from_str_strict("en-nedis-macos"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::NonCanonical
+        ))
+    );
+
+    // The default parsing stays permissive.
+    assert!("EN_us".parse::<LanguageIdentifier>().is_ok());
+}
+
+#[test]
+fn test_root() {
+    assert_parsed_language_identifier("root", None, None, None, None);
+    assert_parsed_language_identifier("ROOT", None, None, None, None);
+    assert_parsed_language_identifier("rOoT", None, None, None, None);
+    assert_parsed_language_identifier("uNd", None, None, None, None);
+    assert_parsed_language_identifier("roots", Some("roots"), None, None, None);
+    assert_parsed_language_identifier("unD-US", None, None, Some("US"), None);
+    assert_parsed_language_identifier("une", Some("une"), None, None, None);
+    assert_parsed_language_identifier("root-Latn", None, Some("Latn"), None, None);
+
+    let langid: LanguageIdentifier = "root".parse().unwrap();
+    assert_eq!(langid, LanguageIdentifier::default());
+    assert_eq!(langid.to_string(), "und");
+    assert_eq!(format!("{:#}", langid), "root");
+
+    let langid: LanguageIdentifier = "und-US".parse().unwrap();
+    assert_eq!(format!("{:#}", langid), "und-US");
+}
+
+#[test]
+fn test_from_os_str() {
+    use std::ffi::OsStr;
+
+    let langid = LanguageIdentifier::from_os_str(OsStr::new("sr_Cyrl_RS")).unwrap();
+    assert_eq!(langid.to_string(), "sr-Cyrl-RS");
+
+    assert_eq!(
+        LanguageIdentifier::from_os_str(OsStr::new("zółw")),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidLanguage
+        ))
+    );
+    assert!(LanguageIdentifier::from_os_str(OsStr::new("en-US-x")).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_from_os_str_non_unicode() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let input = OsStr::from_bytes(&[b'e', b'n', 0xff]);
+    assert!(LanguageIdentifier::from_os_str(input).is_err());
+}
+
+#[test]
+fn test_fill_missing_from() {
+    let assert_fill = |input: &str, other: &str, expected: &str| {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        let other: LanguageIdentifier = other.parse().unwrap();
+        langid.fill_missing_from(&other);
+        assert_eq!(langid.to_string(), expected);
+    };
+    assert_fill("sr", "sr-Cyrl-RS", "sr-Cyrl-RS");
+    assert_fill("sr-Latn", "sr-Cyrl-RS", "sr-Latn-RS");
+    assert_fill("und", "en-US", "und-US");
+    assert_fill("de-AT-nedis", "en-Latn-US-macos", "de-Latn-AT-nedis");
+    assert_fill("de", "en-macos-posix", "de-macos-posix");
+    assert_fill("de-CH", "und", "de-CH");
+}
+
+#[test]
+fn test_und_const() {
+    const UND: LanguageIdentifier = LanguageIdentifier::new();
+    static ROOT: LanguageIdentifier = LanguageIdentifier::UND;
+
+    assert_eq!(UND, LanguageIdentifier::default());
+    assert_eq!(ROOT, LanguageIdentifier::default());
+    assert_eq!(UND.to_string(), "und");
+    assert_eq!("und".parse::<LanguageIdentifier>().unwrap(), ROOT);
+}
+
+#[test]
+fn test_canonical_casing() {
+    for input in &["en", "und-Latn-US", "sr-Cyrl-RS", "en-419", "de-1996-fonipa"] {
+        assert!(LanguageIdentifier::from_str_strict(input).is_ok(), "{}", input);
+    }
+    for input in &["EN", "eN", "en-latn", "en-LATN", "en-LaTn", "en-us", "en-Us", "de-1996-FONIPA"] {
+        assert!(LanguageIdentifier::from_str_strict(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_display_padding() {
+    let langid: LanguageIdentifier = "en-US".parse().unwrap();
+    assert_eq!(format!("{:<8}|", langid), "en-US   |");
+    assert_eq!(format!("{:>8}|", langid), "   en-US|");
+    assert_eq!(format!("{:*^9}|", langid), "**en-US**|");
+    assert_eq!(format!("{:3}|", langid), "en-US|");
+
+    let langid = LanguageIdentifier::UND;
+    assert_eq!(format!("{:#6}|", langid), "root  |");
+    assert_eq!(format!("{:#<6}|", langid), "und###|");
+}
+
+#[test]
+fn test_canonical_language_identifier() {
+    use unic_langid_impl::CanonicalLanguageIdentifier;
+
+    let li = CanonicalLanguageIdentifier::canonicalize("EN_latn_us_NEDIS_macos").unwrap();
+    assert_eq!(li.to_string(), "en-Latn-US-macos-nedis");
+    assert_eq!(li.get_region(), Some("US"));
+
+    let li2: CanonicalLanguageIdentifier = "en-Latn-US-nedis-macos"
+        .parse::<LanguageIdentifier>()
+        .unwrap()
+        .into();
+    assert_eq!(li, li2);
+
+    let raw = unsafe {
+        LanguageIdentifier::from_raw_parts_unchecked(
+            "de".parse().ok(),
+            None,
+            None,
+            Some(vec!["nedis".parse().unwrap(), "macos".parse().unwrap()].into_boxed_slice()),
+        )
+    };
+    let li = CanonicalLanguageIdentifier::from(raw);
+    assert_eq!(li.get_variants(), vec!["macos", "nedis"]);
+    assert_eq!(li.into_inner().to_string(), "de-macos-nedis");
+
+    assert!(CanonicalLanguageIdentifier::canonicalize("e-US").is_err());
+}
+
+#[test]
+fn test_is_pseudo_locale() {
+    for input in &["en-XA", "ar-XB", "en-XC", "qps-ploc", "qps-Latn"] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert!(li.is_pseudo_locale(), "{}", input);
+    }
+    for input in &["en", "en-US", "ar-EG", "und-XK"] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert!(!li.is_pseudo_locale(), "{}", input);
+    }
+}
+
+#[test]
+fn test_speaks_same_language() {
+    let entries = vec![
+        ("zh-TW", "zh-Hans", true),
+        ("sr-Latn", "sr-Cyrl-BA", true),
+        ("en-US-posix", "en", true),
+        ("en", "de", false),
+        ("und", "und", false),
+    ];
+
+    for (a, b, expected) in entries {
+        let li1: LanguageIdentifier = a.parse().unwrap();
+        let li2: LanguageIdentifier = b.parse().unwrap();
+        assert_eq!(li1.speaks_same_language(&li2), expected, "{} {}", a, b);
+        assert_eq!(li2.speaks_same_language(&li1), expected, "{} {}", b, a);
+    }
+}
+
+#[test]
+fn test_matches_str() {
+    let options = ParserOptions::default();
+    let entries = vec![
+        ("en-Latn-GB", "en-*-GB", true),
+        ("en-Latn-GB", "en_GB", true),
+        ("en-Latn-GB", "*", true),
+        ("en-Latn-GB", "*-Latn", true),
+        ("en-Latn-GB", "EN-latn-gb", true),
+        ("en-Latn-GB", "en-Cyrl", false),
+        ("en-Latn-GB", "de-*-GB", false),
+        ("de-DE-1996-macos", "de-macos-1996", true),
+        ("de-DE-1996-macos", "de-*-*-1996", false),
+        ("de-DE-1996-macos", "de-*-*-*", true),
+        ("de-DE-1996", "de-1996-macos", false),
+        ("de-DE", "de-1996", false),
+    ];
+
+    for (input, range, expected) in entries {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(
+            li.matches_str(range, &options),
+            Ok(expected),
+            "{} {}",
+            input,
+            range
+        );
+        if !range.contains('*') {
+            let other: LanguageIdentifier = range.parse().unwrap();
+            assert_eq!(
+                li.matches(&other, false, true),
+                expected,
+                "{} {}",
+                input,
+                range
+            );
+        }
+    }
+
+    let li: LanguageIdentifier = "en-US".parse().unwrap();
+    assert!(li.matches_str("", &options).is_err());
+    assert!(li.matches_str("en-US-x", &options).is_err());
+    assert!(li.matches_str("en_US", &ParserOptions::strict()).is_err());
+
+    let options = ParserOptions {
+        allow_extension: true,
+        ..ParserOptions::default()
+    };
+    assert_eq!(li.matches_str("en-US-u-hc-h12", &options), Ok(true));
+}
+
+#[test]
+fn test_sourced_language_identifier() {
+    use unic_langid_impl::SourcedLanguageIdentifier;
+
+    let li: SourcedLanguageIdentifier = "EN_latn_us".parse().unwrap();
+    assert_eq!(li.to_string(), "EN_latn_us");
+    assert_eq!(li.get_script(), Some("Latn"));
+    assert!(!li.is_canonical());
+
+    let (langid, source) = li.into_parts();
+    assert_eq!(langid.to_string(), "en-Latn-US");
+    assert_eq!(source, "EN_latn_us");
+
+    let li: SourcedLanguageIdentifier = "de-DE-1996".parse().unwrap();
+    assert!(li.is_canonical());
+    assert_eq!(li.to_string(), "de-DE-1996");
+
+    let langid: LanguageIdentifier = "de-DE-1996".parse().unwrap();
+    assert_eq!(*li, langid);
+
+    assert!(SourcedLanguageIdentifier::from_str_with("en_US", &ParserOptions::strict()).is_err());
+    assert!("e-US".parse::<SourcedLanguageIdentifier>().is_err());
+}
+
+#[test]
+fn test_parse_list() {
+    use unic_langid_impl::parse_list;
+
+    let entries = vec![
+        ("de:en_GB:en", vec!["de", "en-GB", "en"]),
+        ("en-US, fr;q=0.8, *;q=0.1", vec!["en-US", "fr"]),
+        ("sr-Latn; pl\tcs\n", vec!["sr-Latn", "pl", "cs"]),
+        ("", vec![]),
+        (" , ", vec![]),
+    ];
+
+    for (input, expected) in entries {
+        let list: Vec<String> = parse_list(input)
+            .unwrap()
+            .iter()
+            .map(|li| li.to_string())
+            .collect();
+        assert_eq!(list, expected, "{}", input);
+    }
+
+    assert_eq!(
+        parse_list("en:e-US"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidLanguage
+        ))
+    );
+}
+
+#[test]
+fn test_extract() {
+    use unic_langid_impl::extract;
+
+    let entries = vec![
+        ("messages_en_US.properties", Some(("en-US", 9..14))),
+        ("strings.fr-CA.json", Some(("fr-CA", 8..13))),
+        ("values-de/strings.xml", Some(("de", 7..9))),
+        ("app.sr_Latn_RS.ftl", Some(("sr-Latn-RS", 4..14))),
+        ("de-DE-1996.dic", Some(("de-DE-1996", 0..10))),
+        ("es_419", Some(("es-419", 0..6))),
+        ("pt_BR_strings.po", Some(("pt-BR", 0..5))),
+        ("help.en.md", Some(("en", 5..7))),
+        ("README.md", None),
+        ("main.ts", None),
+        ("messages.properties", None),
+        ("", None),
+    ];
+
+    for (input, expected) in entries {
+        let result = extract(input).map(|(li, range)| (li.to_string(), range));
+        let expected = expected.map(|(li, range)| (li.to_string(), range));
+        assert_eq!(result, expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_android_qualifier() {
+    let entries = vec![
+        ("en", "en", "en"),
+        ("en-rUS", "en-US", "en-rUS"),
+        ("b+en+US", "en-US", "en-rUS"),
+        ("b+sr+Latn+RS", "sr-Latn-RS", "b+sr+Latn+RS"),
+        ("b+es+419", "es-419", "b+es+419"),
+        ("b+de+DE+1996", "de-DE-1996", "b+de+DE+1996"),
+        ("fil-rPH", "fil-PH", "b+fil+PH"),
+    ];
+
+    for (input, langid, qualifier) in entries {
+        let li = LanguageIdentifier::from_android_qualifier(input).unwrap();
+        assert_eq!(li.to_string(), langid);
+        assert_eq!(li.to_android_qualifier(), qualifier);
+    }
+
+    for input in &["", "en-US", "en-rUSA", "en-rUS-x", "b+", "b+en+$", "english"] {
+        assert!(
+            LanguageIdentifier::from_android_qualifier(input).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_string_conversions() {
+    use std::borrow::Cow;
+
+    let li: LanguageIdentifier = "sr-latn".parse().unwrap();
+    assert_eq!(String::from(&li), "sr-Latn");
+    assert_eq!(String::from(li.clone()), "sr-Latn");
+    assert!(matches!(li.to_cow(), Cow::Owned(s) if s == "sr-Latn"));
+
+    for input in &["pl", "fil", "und", "root"] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert!(matches!(li.to_cow(), Cow::Borrowed(_)), "{}", input);
+        assert_eq!(li.to_cow(), li.to_string());
+    }
+}
+
+#[test]
+fn test_match_quality() {
+    use unic_langid_impl::MatchQuality;
+
+    let assert_quality = |a: &str, b: &str, expected: MatchQuality| {
+        let a: LanguageIdentifier = a.parse().unwrap();
+        let b: LanguageIdentifier = b.parse().unwrap();
+        assert_eq!(a.match_quality(&b), expected, "{} {}", a, b);
+        assert_eq!(b.match_quality(&a), expected, "{} {}", b, a);
+    };
+
+    assert_quality("en", "en", MatchQuality::Exact);
+    assert_quality("de-CH-1996", "de-CH-1996", MatchQuality::Exact);
+    assert_quality("en-CA", "en-GB", MatchQuality::RegionDiffers);
+    assert_quality("en", "en-GB", MatchQuality::RegionDiffers);
+    assert_quality("de-CH", "de-CH-1996", MatchQuality::RegionDiffers);
+    assert_quality("sr-Latn-RS", "sr-Cyrl-RS", MatchQuality::ScriptDiffers);
+    assert_quality("sr", "sr-Latn", MatchQuality::ScriptDiffers);
+    assert_quality("sr-Latn-RS", "sr-Cyrl-BA", MatchQuality::LanguageOnly);
+    assert_quality("en-CA", "fr-CA", MatchQuality::None);
+    assert_quality("und", "en", MatchQuality::None);
+}
+
+#[test]
+fn test_stable_hash() {
+    let hash = |input: &str| input.parse::<LanguageIdentifier>().unwrap().stable_hash();
+
+    // The values are part of the API and must not change between versions.
+    assert_eq!(hash("und"), 5525269238850885370);
+    assert_eq!(hash("en-US"), 14676792195390135641);
+    assert_eq!(hash("sr-Cyrl-RS-ekavsk"), 3132246194428619811);
+
+    assert_eq!(hash("SR_cyrl_rs_EKAVSK"), hash("sr-Cyrl-RS-ekavsk"));
+    assert_eq!(hash("de-nedis-macos"), hash("de-macos-nedis"));
+    assert_ne!(hash("en-US"), hash("en-GB"));
+    assert_ne!(hash("iw"), hash("he"));
+
+    let options = ParserOptions {
+        preserve_variant_order: true,
+        ..ParserOptions::default()
+    };
+    let li = LanguageIdentifier::from_str_with("sl-rozaj-biske-1994", &options).unwrap();
+    let canonical: LanguageIdentifier = "sl-1994-biske-rozaj".parse().unwrap();
+    assert!(li.eq_canonical(&canonical));
+    assert_eq!(li.stable_hash(), canonical.stable_hash());
+    assert_eq!(li.to_string(), "sl-rozaj-biske-1994");
+}
+
+#[test]
+fn test_eq_str_ignore_case() {
+    let inputs = &[
+        "und",
+        "en",
+        "en-US",
+        "sr-Cyrl",
+        "sr-Latn-RS-ekavsk",
+        "de-CH-1996-fonipa",
+        "und-419",
+    ];
+    let candidates = &[
+        "UND",
+        "",
+        "en",
+        "EN_us",
+        "en-GB",
+        "en-US-",
+        "SR-cyrl",
+        "sr-latn-rs-EKAVSK",
+        "sr-Latn-RS",
+        "de-ch-fonipa-1996",
+        "de-CH-1996-1996",
+        "de-CH-1996-fonipa-fonipa",
+        "und-419",
+        "419",
+    ];
+    for input in inputs {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        for candidate in candidates {
+            let expected = matches!(
+                candidate.parse::<LanguageIdentifier>(),
+                Ok(parsed) if parsed == langid
+            );
+            assert_eq!(
+                langid.eq_str_ignore_case(candidate),
+                expected,
+                "{} == {}",
+                input,
+                candidate
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_all() {
+    let langids = LanguageIdentifier::parse_all(&["en-US", "und", "sr-Cyrl-RS"]).unwrap();
+    assert_eq!(
+        langids.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+        vec!["en-US", "und", "sr-Cyrl-RS"]
+    );
+
+    let inputs = vec![
+        "e".to_string(),
+        "en".to_string(),
+        "en-US-$".to_string(),
+        "x".to_string(),
+    ];
+    let err = LanguageIdentifierError::ParserError;
+    assert_eq!(
+        LanguageIdentifier::parse_all(&inputs),
+        Err(vec![
+            (0, err(ParserError::InvalidLanguage)),
+            (2, err(ParserError::InvalidSubtag)),
+            (3, err(ParserError::InvalidLanguage)),
+        ])
+    );
+
+    let empty: &[&str] = &[];
+    assert_eq!(LanguageIdentifier::parse_all(empty), Ok(vec![]));
+}
+
+#[test]
+fn test_validate() {
+    let options = ParserOptions::default();
+    let langid = LanguageIdentifier::validate("en-Latn-US-valencia", &options).unwrap();
+    assert_eq!(langid.to_string(), "en-Latn-US-valencia");
+
+    let errors = LanguageIdentifier::validate("e-Latn-U$-valencia-ab", &options).unwrap_err();
+    assert_eq!(
+        errors.errors(),
+        &[
+            (0, ParserError::InvalidLanguage),
+            (7, ParserError::InvalidSubtag),
+            (19, ParserError::InvalidSubtag),
+        ]
+    );
+    assert_eq!(
+        errors.to_string(),
+        "The given language subtag is invalid at byte 0; \
+         Invalid subtag at byte 7; \
+         Invalid subtag at byte 19"
+    );
+
+    // A rejected subtag does not affect the subtags which follow it.
+    let errors = LanguageIdentifier::validate("en-Lat$-US", &options).unwrap_err();
+    assert_eq!(errors.errors(), &[(3, ParserError::InvalidSubtag)]);
+
+    // Invalid subtags are reported in the lenient mode as well.
+    let options = ParserOptions {
+        lenient: true,
+        max_length: Some(8),
+        require_canonical: true,
+        ..ParserOptions::default()
+    };
+    let errors = LanguageIdentifier::validate("en_US-x-ab", &options).unwrap_err();
+    assert_eq!(
+        errors.into_errors(),
+        vec![
+            (0, ParserError::InputTooLong),
+            (0, ParserError::NonCanonical),
+            (6, ParserError::InvalidSubtag),
+            (8, ParserError::InvalidSubtag),
+        ]
+    );
+}
+
+#[test]
+fn test_recover() {
+    let options = ParserOptions::default();
+    let recovered = RecoveredLanguageIdentifier::from_str_with("en-Latn-US", &options).unwrap();
+    assert!(recovered.is_complete());
+    assert_eq!(recovered.into_inner().to_string(), "en-Latn-US");
+
+    let recovered =
+        RecoveredLanguageIdentifier::from_str_with("sr_Lat$_RS_ekavsk_$", &options).unwrap();
+    assert_eq!(recovered.langid.to_string(), "sr-ekavsk");
+    assert_eq!(
+        recovered.skipped,
+        vec![
+            SkippedSubtag {
+                offset: 3,
+                subtag: "Lat$".to_string(),
+                error: ParserError::InvalidSubtag,
+            },
+            SkippedSubtag {
+                offset: 8,
+                subtag: "RS".to_string(),
+                error: ParserError::InvalidSubtag,
+            },
+            SkippedSubtag {
+                offset: 18,
+                subtag: "$".to_string(),
+                error: ParserError::InvalidSubtag,
+            },
+        ]
+    );
+
+    // The recovered identifier is the one parsed in the lenient mode.
+    let lenient = ParserOptions {
+        lenient: true,
+        ..ParserOptions::default()
+    };
+    for input in &["en-US-x-valencia", "en-$-US", "de-1996-a-b", "und-Latn-US-posix"] {
+        let recovered = RecoveredLanguageIdentifier::from_str_with(input, &options).unwrap();
+        assert_eq!(
+            recovered.langid,
+            LanguageIdentifier::from_str_with(input, &lenient).unwrap()
+        );
+    }
+
+    // The language subtag is not skipped.
+    assert_eq!(
+        RecoveredLanguageIdentifier::from_str_with("e-US", &options),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidLanguage
+        ))
+    );
+}
+
+#[test]
+fn test_empty_subtags() {
+    for (input, error) in &[
+        ("-en", ParserError::EmptySubtag),
+        ("en--US", ParserError::EmptySubtag),
+        ("en_-US", ParserError::EmptySubtag),
+        ("-", ParserError::EmptySubtag),
+        ("en-US-", ParserError::TrailingSeparator),
+        ("en-", ParserError::TrailingSeparator),
+        ("", ParserError::InvalidLanguage),
+    ] {
+        assert_eq!(&parse_language_identifier(input).unwrap_err(), error, "{}", input);
+    }
+
+    // The lenient mode repairs the input.
+    let options = ParserOptions {
+        lenient: true,
+        ..ParserOptions::default()
+    };
+    for input in &["-en-US", "en--US", "en-US-", "en-_US--"] {
+        let langid = LanguageIdentifier::from_str_with(input, &options).unwrap();
+        assert_eq!(langid.to_string(), "en-US", "{}", input);
+    }
+
+    let recovered = RecoveredLanguageIdentifier::from_str_with("en--US-", &options).unwrap();
+    assert_eq!(
+        recovered
+            .skipped
+            .iter()
+            .map(|skipped| (skipped.offset, &skipped.error))
+            .collect::<Vec<_>>(),
+        vec![
+            (3, &ParserError::EmptySubtag),
+            (7, &ParserError::TrailingSeparator)
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+use std::convert::TryFrom;
+
+use language_tags::LanguageTag;
+use unic_langid_impl::LanguageIdentifier;
+
+#[test]
+fn langid_to_language_tag_test() {
+    for input in &["en-US", "und", "sr-Latn-RS", "de-CH-1996", "es-419"] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let tag = LanguageTag::from(&langid);
+        assert_eq!(tag.as_str(), *input);
+        assert_eq!(LanguageTag::from(langid).as_str(), *input);
+    }
+}
+
+#[test]
+fn language_tag_to_langid_test() {
+    let tag = LanguageTag::parse("en-latn-us").unwrap();
+    let langid = LanguageIdentifier::try_from(&tag).unwrap();
+    assert_eq!(langid.to_string(), "en-Latn-US");
+    assert_eq!(LanguageIdentifier::try_from(tag), Ok(langid));
+
+    for input in &[
+        "zh-yue-HK",
+        "en-US-u-ca-buddhist",
+        "de-x-foo",
+        "x-private",
+        "i-klingon",
+        "art-lojban",
+        "zh-guoyu",
+        "zh-hakka",
+        "cel-gaulish",
+    ] {
+        let tag = LanguageTag::parse(input).unwrap();
+        assert!(LanguageIdentifier::try_from(&tag).is_err(), "{}", input);
+    }
+}
@@ -0,0 +1,86 @@
+use unic_langid_impl::Language;
+
+#[test]
+fn test_language_from_str() {
+    let language: Language = "DE".parse().expect("Parsing failed.");
+    assert_eq!(language.as_str(), "de");
+
+    assert!("und".parse::<Language>().is_err());
+    assert!("d1".parse::<Language>().is_err());
+}
+
+#[test]
+fn test_language_canonicalize() {
+    let language: Language = "ger".parse().expect("Parsing failed.");
+    assert_eq!(language.canonicalize().as_str(), "de");
+
+    let language: Language = "deu".parse().expect("Parsing failed.");
+    assert_eq!(language.canonicalize().as_str(), "de");
+
+    let language: Language = "haw".parse().expect("Parsing failed.");
+    assert_eq!(language.canonicalize().as_str(), "haw");
+
+    let language: Language = "tgl".parse().expect("Parsing failed.");
+    assert_eq!(language.canonicalize().as_str(), "fil");
+    assert_eq!(language.to_alpha2(), Some("tl"));
+}
+
+#[test]
+fn test_language_codes() {
+    let language: Language = "cs".parse().expect("Parsing failed.");
+    assert_eq!(language.to_alpha2(), Some("cs"));
+    assert_eq!(language.to_alpha3_t(), "ces");
+    assert_eq!(language.to_alpha3_b(), "cze");
+
+    let language: Language = "cze".parse().expect("Parsing failed.");
+    assert_eq!(language.to_alpha2(), Some("cs"));
+    assert_eq!(language.to_alpha3_t(), "ces");
+
+    let language: Language = "en".parse().expect("Parsing failed.");
+    assert_eq!(language.to_alpha3_t(), "eng");
+    assert_eq!(language.to_alpha3_b(), "eng");
+
+    let language: Language = "haw".parse().expect("Parsing failed.");
+    assert_eq!(language.to_alpha2(), None);
+    assert_eq!(language.to_alpha3_t(), "haw");
+    assert_eq!(language.to_alpha3_b(), "haw");
+}
+
+#[test]
+fn test_language_scope() {
+    use unic_langid_impl::{language_scope, LanguageScope};
+
+    assert_eq!(language_scope("zh"), Some(LanguageScope::Macrolanguage));
+    assert_eq!(language_scope("AR"), Some(LanguageScope::Macrolanguage));
+    assert_eq!(language_scope("cmn"), Some(LanguageScope::Individual));
+    assert_eq!(language_scope("sla"), Some(LanguageScope::Collection));
+    assert_eq!(language_scope("mul"), Some(LanguageScope::Special));
+    assert_eq!(language_scope("und"), Some(LanguageScope::Special));
+    assert_eq!(language_scope("qaa"), Some(LanguageScope::PrivateUse));
+    assert_eq!(language_scope("qtz"), Some(LanguageScope::PrivateUse));
+    assert_eq!(language_scope("qwe"), Some(LanguageScope::Collection));
+    assert_eq!(language_scope("1"), None);
+
+    let language: Language = "zh".parse().expect("Parsing failed.");
+    assert_eq!(language.get_scope(), LanguageScope::Macrolanguage);
+}
+
+#[test]
+fn test_language_is_deprecated() {
+    let language: Language = "iw".parse().expect("Parsing failed.");
+    assert!(language.is_deprecated());
+
+    let language: Language = "he".parse().expect("Parsing failed.");
+    assert!(!language.is_deprecated());
+}
+
+#[test]
+fn test_language_aliases_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/language_alias_table.rs"]
+    mod language_alias_table;
+
+    for pair in language_alias_table::LANGUAGE_ALIASES.windows(2) {
+        assert!(pair[0].0 < pair[1].0, "{:?}", pair);
+    }
+}
@@ -96,4 +96,115 @@ fn remove_likely_subtags_test() {
     let script: TinyStr4 = "Hant".parse().unwrap();
     let result = remove_likely_subtags(Some(lang), Some(script), None);
     assert_eq!(result, Some(extract_input("zh-TW")));
+
+    let (lang, script, region) = extract_input("en-Latn-US");
+    let result = remove_likely_subtags(lang, script, region);
+    assert_eq!(result, Some(extract_input("en")));
+}
+
+#[test]
+fn likely_script_and_region_test() {
+    use unic_langid_impl::LanguageIdentifier;
+
+    let li: LanguageIdentifier = "und-Arab".parse().unwrap();
+    assert_eq!(li.likely_script().as_deref(), Some("Arab"));
+    assert_eq!(li.likely_region().as_deref(), Some("EG"));
+
+    let li: LanguageIdentifier = "mk".parse().unwrap();
+    assert_eq!(li.likely_script().as_deref(), Some("Cyrl"));
+    assert_eq!(li.likely_region().as_deref(), Some("MK"));
+    assert_eq!(li.to_string(), "mk");
+
+    let li: LanguageIdentifier = "en-Cyrl".parse().unwrap();
+    assert_eq!(li.likely_script().as_deref(), Some("Cyrl"));
+    assert_eq!(li.likely_region().as_deref(), Some("US"));
+
+    let li: LanguageIdentifier = "eo".parse().unwrap();
+    assert_eq!(li.likely_script().as_deref(), Some("Latn"));
+    assert_eq!(li.likely_region().as_deref(), Some("001"));
+
+    let li: LanguageIdentifier = "und".parse().unwrap();
+    assert_eq!(li.likely_script().as_deref(), None);
+    assert_eq!(li.likely_region().as_deref(), None);
+}
+
+#[test]
+fn likely_language_for_region_test() {
+    use unic_langid_impl::likely_language_for_region;
+
+    assert_eq!(
+        likely_language_for_region("PL").map(|li| li.to_string()),
+        Some("pl-Latn-PL".to_string())
+    );
+    assert_eq!(
+        likely_language_for_region("419").map(|li| li.to_string()),
+        Some("es-Latn-419".to_string())
+    );
+    assert_eq!(likely_language_for_region("ZZ"), None);
+    assert_eq!(likely_language_for_region(""), None);
+}
+
+#[test]
+fn speaks_same_language_test() {
+    use unic_langid_impl::LanguageIdentifier;
+
+    let entries = vec![
+        ("und-TW", "zh-Hans", true),
+        ("und-RS", "sr-Latn", true),
+        ("und-DE", "de-AT", true),
+        ("und-JP", "zh", false),
+    ];
+
+    for (a, b, expected) in entries {
+        let li1: LanguageIdentifier = a.parse().unwrap();
+        let li2: LanguageIdentifier = b.parse().unwrap();
+        assert_eq!(li1.speaks_same_language(&li2), expected, "{} {}", a, b);
+    }
+}
+
+#[test]
+fn add_likely_subtags_cached_test() {
+    use unic_langid_impl::likelysubtags::cache::MaximizeCache;
+    use unic_langid_impl::LanguageIdentifier;
+
+    let cache = MaximizeCache::new(4);
+    for _ in 0..2 {
+        for i in STRINGS {
+            let mut li: LanguageIdentifier = i.0.parse().unwrap();
+            let mut expected = li.clone();
+            assert_eq!(
+                li.add_likely_subtags_cached(&cache),
+                expected.add_likely_subtags()
+            );
+            assert_eq!(li, expected);
+        }
+    }
+    assert_eq!(cache.len(), 4);
+
+    cache.clear();
+    assert!(cache.is_empty());
+
+    let cache = MaximizeCache::new(0);
+    let mut li: LanguageIdentifier = "sr-ME".parse().unwrap();
+    assert!(li.add_likely_subtags_cached(&cache));
+    assert!(cache.is_empty());
+}
+
+#[test]
+#[cfg(feature = "layout")]
+fn character_direction_fallback_test() {
+    use unic_langid_impl::{CharacterDirection, LanguageIdentifier};
+
+    for (input, expected) in &[
+        ("und-Arab", CharacterDirection::RTL),
+        ("und-IL", CharacterDirection::RTL),
+        ("und-Hebr-IL", CharacterDirection::RTL),
+        ("und-EG", CharacterDirection::RTL),
+        ("und-Latn", CharacterDirection::LTR),
+        ("und-PL", CharacterDirection::LTR),
+        ("und", CharacterDirection::LTR),
+    ] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&li.get_character_direction(), expected, "{}", input);
+    }
 }
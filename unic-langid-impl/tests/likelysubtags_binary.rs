@@ -0,0 +1,80 @@
+use unic_langid_impl::likelysubtags::runtime::{DataError, LikelySubtagsData};
+use unic_langid_impl::LanguageIdentifier;
+
+static DATA: &[u8] = include_bytes!("../data/likelySubtags.bin");
+
+static STRINGS: &[&str] = &[
+    "en-US",
+    "es-AR",
+    "it",
+    "zh-Hans-CN",
+    "sr-Cyrl-SR",
+    "und-PL",
+    "und-Latn-AM",
+    "ug-Cyrl",
+    "und-Thai-CN",
+    "en-Latn-DE",
+    "und-Arab",
+    "zh-TW",
+    "zh-Hant",
+];
+
+#[test]
+fn matches_embedded_data() {
+    let data = LikelySubtagsData::from_bytes(DATA).unwrap();
+    assert_eq!(data.cldr_version(), "35.1");
+
+    for s in STRINGS {
+        let mut embedded: LanguageIdentifier = s.parse().unwrap();
+        let mut runtime = embedded.clone();
+        assert_eq!(
+            embedded.add_likely_subtags(),
+            runtime.add_likely_subtags_with(&data)
+        );
+        assert_eq!(embedded, runtime);
+
+        assert_eq!(
+            embedded.remove_likely_subtags(),
+            runtime.remove_likely_subtags_with(&data)
+        );
+        assert_eq!(embedded, runtime);
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let data = LikelySubtagsData::from_bytes(DATA).unwrap();
+    assert_eq!(data.to_bytes(), DATA);
+}
+
+#[test]
+fn invalid_data() {
+    assert!(matches!(
+        LikelySubtagsData::from_bytes(b"ULSX"),
+        Err(DataError::InvalidData(_))
+    ));
+    assert!(matches!(
+        LikelySubtagsData::from_bytes(&DATA[..DATA.len() - 1]),
+        Err(DataError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn invalid_subtags() {
+    // The first `lang_only` key follows the header and the table length.
+    let offset = 4 + 1 + 1 + "35.1".len() + 4;
+
+    let mut data = DATA.to_vec();
+    data[offset] = b'-';
+    assert!(matches!(
+        LikelySubtagsData::from_bytes(&data),
+        Err(DataError::InvalidData(_))
+    ));
+
+    let mut data = DATA.to_vec();
+    data[offset] = data[offset].to_ascii_uppercase();
+    assert!(matches!(
+        LikelySubtagsData::from_bytes(&data),
+        Err(DataError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,62 @@
+use unic_langid_impl::likelysubtags::runtime::{DataError, LikelySubtagsData};
+use unic_langid_impl::LanguageIdentifier;
+
+static STRINGS: &[&str] = &[
+    "en-US",
+    "es-AR",
+    "it",
+    "zh-Hans-CN",
+    "sr-Cyrl-SR",
+    "und-PL",
+    "und-Latn-AM",
+    "ug-Cyrl",
+    "und-Thai-CN",
+    "en-Latn-DE",
+    "und-Arab",
+    "zh-TW",
+    "zh-Hant",
+];
+
+#[test]
+fn matches_embedded_data() {
+    let data = LikelySubtagsData::from_path("./data/likelySubtags.json").unwrap();
+
+    for s in STRINGS {
+        let mut embedded: LanguageIdentifier = s.parse().unwrap();
+        let mut runtime = embedded.clone();
+        assert_eq!(
+            embedded.add_likely_subtags(),
+            runtime.add_likely_subtags_with(&data)
+        );
+        assert_eq!(embedded, runtime);
+
+        assert_eq!(
+            embedded.remove_likely_subtags(),
+            runtime.remove_likely_subtags_with(&data)
+        );
+        assert_eq!(embedded, runtime);
+    }
+}
+
+#[test]
+fn invalid_data() {
+    assert!(matches!(
+        LikelySubtagsData::from_json("{"),
+        Err(DataError::Json(_))
+    ));
+    assert!(matches!(
+        LikelySubtagsData::from_json("{\"supplemental\": {}}"),
+        Err(DataError::InvalidData(_))
+    ));
+    assert!(matches!(
+        LikelySubtagsData::from_path("./data/missing.json"),
+        Err(DataError::Io(_))
+    ));
+}
+
+#[test]
+fn matches_binary_data() {
+    let data = LikelySubtagsData::from_path("./data/likelySubtags.json").unwrap();
+    let bytes = std::fs::read("./data/likelySubtags.bin").unwrap();
+    assert_eq!(data.to_bytes(), bytes);
+}
@@ -0,0 +1,79 @@
+use unic_langid_impl::{LanguageIdentifier, LocaleIndex};
+
+fn langids(input: &[&str]) -> Vec<LanguageIdentifier> {
+    input.iter().map(|s| s.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_locale_index_matches_linear_scan() {
+    let available = langids(&[
+        "en",
+        "en-US",
+        "en-GB",
+        "en-Latn-US",
+        "en-US-posix",
+        "und",
+        "und-US",
+        "sr-Cyrl",
+        "sr-Latn-RS",
+        "sr",
+        "de-AT",
+        "de-CH-1996",
+        "zh-Hant-TW",
+    ]);
+    let requested = langids(&[
+        "en",
+        "en-US",
+        "en-Latn",
+        "und",
+        "und-US",
+        "sr-RS",
+        "de-CH-1996",
+        "de",
+        "pl",
+        "zh-TW",
+    ]);
+    let index: LocaleIndex = available.clone().into();
+
+    for req in &requested {
+        for &(available_as_range, requested_as_range) in
+            &[(false, false), (true, false), (false, true), (true, true)]
+        {
+            let expected: Vec<&LanguageIdentifier> = available
+                .iter()
+                .filter(|a| a.matches(req, available_as_range, requested_as_range))
+                .collect();
+            let result: Vec<&LanguageIdentifier> = index
+                .matches(req, available_as_range, requested_as_range)
+                .collect();
+            assert_eq!(
+                result, expected,
+                "{} {} {}",
+                req, available_as_range, requested_as_range
+            );
+        }
+    }
+}
+
+#[test]
+fn test_locale_index_lookup() {
+    let mut index = LocaleIndex::new();
+    assert!(index.is_empty());
+
+    for li in langids(&["en-US", "de", "de-CH-1996"]) {
+        index.insert(li);
+    }
+    assert_eq!(index.len(), 3);
+    assert_eq!(
+        index.get(1).map(ToString::to_string),
+        Some("de".to_string())
+    );
+    assert_eq!(index.as_slice().len(), 3);
+
+    let de_ch: LanguageIdentifier = "de-CH".parse().unwrap();
+    let de_ch_1996: LanguageIdentifier = "de-CH-1996".parse().unwrap();
+    assert_eq!(index.position(&de_ch_1996), Some(2));
+    assert_eq!(index.position(&de_ch), None);
+    assert!(!index.contains(&de_ch));
+    assert!(index.contains(&de_ch_1996));
+}
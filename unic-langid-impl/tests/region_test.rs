@@ -0,0 +1,48 @@
+use unic_langid_impl::Region;
+
+#[test]
+fn test_region_from_str() {
+    let region: Region = "de".parse().expect("Parsing failed.");
+    assert_eq!(region.as_str(), "DE");
+
+    let region: Region = "419".parse().expect("Parsing failed.");
+    assert_eq!(region.as_str(), "419");
+
+    assert!("DEU".parse::<Region>().is_err());
+    assert!("D1".parse::<Region>().is_err());
+}
+
+#[test]
+fn test_region_alpha3() {
+    let region = Region::from_alpha3("DEU").expect("Unknown region.");
+    assert_eq!(region.as_str(), "DE");
+    assert_eq!(region.to_alpha3(), Some("DEU"));
+
+    assert_eq!(
+        Region::from_alpha3("usa").map(|r| r.to_string()),
+        Some("US".to_string())
+    );
+    assert_eq!(Region::from_alpha3("XYZ"), None);
+
+    let region: Region = "419".parse().expect("Parsing failed.");
+    assert_eq!(region.to_alpha3(), None);
+}
+
+#[test]
+fn test_region_numeric() {
+    let region = Region::from_numeric(276).expect("Unknown region.");
+    assert_eq!(region.as_str(), "DE");
+    assert_eq!(region.to_numeric(), Some(276));
+
+    assert_eq!(
+        Region::from_numeric(4).map(|r| r.to_string()),
+        Some("AF".to_string())
+    );
+    assert_eq!(Region::from_numeric(419), None);
+
+    let region: Region = "419".parse().expect("Parsing failed.");
+    assert_eq!(region.to_numeric(), Some(419));
+
+    let region: Region = "XK".parse().expect("Parsing failed.");
+    assert_eq!(region.to_numeric(), None);
+}
@@ -0,0 +1,59 @@
+use unic_langid_impl::Script;
+
+#[test]
+fn test_script_from_str() {
+    let script: Script = "cYRL".parse().expect("Parsing failed.");
+    assert_eq!(script.as_str(), "Cyrl");
+
+    assert!("Cyr".parse::<Script>().is_err());
+    assert!("Cyr1".parse::<Script>().is_err());
+}
+
+#[test]
+fn test_script_numeric() {
+    let script = Script::from_numeric(215).expect("Unknown script.");
+    assert_eq!(script.as_str(), "Latn");
+    assert_eq!(script.to_numeric(), Some(215));
+
+    let script: Script = "Hant".parse().expect("Parsing failed.");
+    assert_eq!(script.to_numeric(), Some(502));
+
+    assert_eq!(Script::from_numeric(1), None);
+}
+
+#[test]
+fn test_script_numeric_private_use() {
+    let script = Script::from_numeric(900).expect("Unknown script.");
+    assert_eq!(script.as_str(), "Qaaa");
+
+    let script = Script::from_numeric(949).expect("Unknown script.");
+    assert_eq!(script.as_str(), "Qabx");
+    assert_eq!(script.to_numeric(), Some(949));
+
+    let script: Script = "Qaai".parse().expect("Parsing failed.");
+    assert_eq!(script.to_numeric(), Some(908));
+
+    let script: Script = "Qaby".parse().expect("Parsing failed.");
+    assert_eq!(script.to_numeric(), None);
+}
+
+#[test]
+fn test_script_alias() {
+    let script = Script::from_alias("cyrillic").expect("Unknown script.");
+    assert_eq!(script.as_str(), "Cyrl");
+    assert_eq!(script.to_alias(), Some("Cyrillic"));
+
+    let script: Script = "Hans".parse().expect("Parsing failed.");
+    assert_eq!(script.to_alias(), None);
+
+    assert_eq!(Script::from_alias("Klingon"), None);
+}
+
+#[test]
+fn test_script_canonicalize() {
+    let script: Script = "qaai".parse().expect("Parsing failed.");
+    assert_eq!(script.canonicalize().as_str(), "Zinh");
+
+    let script: Script = "Latn".parse().expect("Parsing failed.");
+    assert_eq!(script.canonicalize(), script);
+}
@@ -0,0 +1,59 @@
+use unic_langid_impl::test_util::{langid, langids, sample_langids};
+use unic_langid_impl::{assert_langid_eq, assert_langid_matches, LanguageIdentifier};
+
+#[test]
+fn assert_langid_eq_test() {
+    let li: LanguageIdentifier = "en-US".parse().unwrap();
+    assert_langid_eq!(li, "en_us");
+    assert_langid_eq!("EN-us", li);
+    assert_langid_eq!(String::from("sr-latn"), "sr-Latn");
+}
+
+#[test]
+#[should_panic(expected = "`en-US` does not equal `en-GB`")]
+fn assert_langid_eq_fails_test() {
+    assert_langid_eq!("en-US", "en-GB");
+}
+
+#[test]
+fn assert_langid_matches_test() {
+    assert_langid_matches!("en", "en-US");
+    assert_langid_matches!("en-US", "en");
+    assert_langid_matches!(langid("en"), "en-US", true, false);
+    assert_langid_matches!("en-US", "en-US", false, false);
+}
+
+#[test]
+#[should_panic(expected = "`en` does not match `en-US`")]
+fn assert_langid_matches_fails_test() {
+    assert_langid_matches!("en", "en-US", false, true);
+}
+
+#[test]
+#[should_panic(expected = "Malformed Language Identifier")]
+fn langid_fails_test() {
+    langid("en-$");
+}
+
+#[test]
+fn langids_test() {
+    let list = langids(&["en-US", "de"]);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[1].get_language(), "de");
+}
+
+#[test]
+fn sample_langids_test() {
+    let samples = sample_langids(200, 42);
+    assert_eq!(samples.len(), 200);
+    assert_eq!(samples, sample_langids(200, 42));
+    assert_ne!(samples, sample_langids(200, 43));
+
+    for li in &samples {
+        let parsed: LanguageIdentifier = li.to_string().parse().unwrap();
+        assert_eq!(&parsed, li);
+    }
+    assert!(samples.iter().any(|li| li.get_script().is_some()));
+    assert!(samples.iter().any(|li| li.get_region().is_some()));
+    assert!(samples.iter().any(|li| !li.get_variants().is_empty()));
+}
@@ -11,6 +11,18 @@ use unic_langid_impl::LanguageIdentifier;
 #[proc_macro_hack]
 pub fn langid(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
+    expand(&id, true)
+}
+
+/// Same as `langid`, but rejects variants, which cannot be stored in a `static`
+/// since they are allocated on the heap.
+#[proc_macro_hack]
+pub fn static_langid(input: TokenStream) -> TokenStream {
+    let id = parse_macro_input!(input as LitStr);
+    expand(&id, false)
+}
+
+fn expand(id: &LitStr, allow_variants: bool) -> TokenStream {
     let parsed: LanguageIdentifier = id.value().parse().expect("Malformed Language Identifier");
 
     let (lang, script, region, variants) = parsed.into_raw_parts();
@@ -30,6 +42,13 @@ pub fn langid(input: TokenStream) -> TokenStream {
         quote!(None)
     };
     let variants = if let Some(variants) = variants {
+        if !allow_variants {
+            let message = format!(
+                "Language identifiers with variants cannot be static: {}",
+                id.value()
+            );
+            return TokenStream::from(syn::Error::new(id.span(), message).to_compile_error());
+        }
         let v: Vec<_> = variants
             .iter()
             .map(|v| quote!($crate::TinyStr8::new_unchecked(#v)))
@@ -7,3 +7,10 @@ pub use unic_langid_impl::LanguageIdentifier;
 /// (Documentation goes here on the re-export, not in the other crate.)
 #[proc_macro_hack]
 pub use unic_langid_macros_impl::langid;
+
+/// Builds a `LanguageIdentifier` which can be stored in a `static`.
+///
+/// Used by `static_langids!`. Variants are rejected at build time, since they
+/// are allocated on the heap.
+#[proc_macro_hack]
+pub use unic_langid_macros_impl::static_langid;
@@ -53,6 +53,23 @@
 //!
 //! At the moment `langid!` can also be used for const variables, but only if no variants are used.
 //!
+//! The `static_langids!` macro uses that to declare a static list of language identifiers,
+//! such as the set of locales supported by an application, without any startup cost.
+//!
+//! ``` ignore
+//! use unic_langid::{static_langids, LanguageIdentifier};
+//!
+//! static_langids! {
+//!     pub static SUPPORTED = ["en-US", "es-AR", "de"];
+//! }
+//!
+//! assert_eq!(SUPPORTED.len(), 3);
+//! assert_eq!(SUPPORTED[1], "es-AR");
+//! ```
+//!
+//! Variants are stored on the heap, so identifiers with variants, such as
+//! `"de-CH-1996"`, are rejected by `static_langids!` with a compile error.
+//!
 //! The macros are optional to reduce the dependency chain and compilation time of `unic-langid`.
 //!
 //! ## Likely Subtags
@@ -67,6 +84,9 @@
 //!
 //! Both methods return a `bool` that indicates if the identifier has been modified.
 //!
+//! Each method can also be selected on its own with `feature = "likelysubtags-add"`
+//! or `feature = "likelysubtags-remove"`.
+//!
 //! ``` ignore
 //! use unic_langid::LanuageIdentifier;
 //!
@@ -83,6 +103,40 @@
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! ## Runtime data
+//!
+//! If `feature = "binary-data"` is selected, the likely subtags data can also be loaded
+//! at runtime from a compact binary format generated by `generate_likelysubtags_binary`,
+//! either from a file, or embedded with `include_bytes!`.
+//!
+//! If `feature = "runtime-data"` is selected, the data can be also loaded from a CLDR JSON file.
+//!
+//! This allows long-lived applications to update the data without recompiling:
+//!
+//! ``` ignore
+//! use unic_langid::LanguageIdentifier;
+//! use unic_langid::likelysubtags::runtime::LikelySubtagsData;
+//!
+//! let data = LikelySubtagsData::from_path("/usr/share/cldr/likelySubtags.json")
+//!     .expect("Loading data failed.");
+//!
+//! let mut li: LanguageIdentifier = "fr-FR".parse()
+//!     .expect("Parsing failed.");
+//!
+//! assert_eq!(li.add_likely_subtags_with(&data), true);
+//! assert_eq!(li, "fr-Latn-FR");
+//! ```
+//!
+//! ## Custom CLDR data
+//!
+//! The crate bundles a snapshot of the CLDR data. Organizations which must pin
+//! to a specific CLDR release can enable the `cldr-build` feature and point the
+//! `UNIC_LANGID_CLDR_PATH` environment variable at a local checkout of the CLDR JSON
+//! data (containing `cldr-core` and `cldr-misc-modern` packages), and the data tables
+//! will be regenerated from it during the build.
+//!
+//! The versions of the embedded data can be verified at runtime with `data_versions`.
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Language Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_language_identifier
 //! [`LanguageIdentifier`]: ./struct.LanguageIdentifier.html
 
@@ -90,6 +144,9 @@ pub use unic_langid_impl::*;
 
 #[cfg(feature = "unic-langid-macros")]
 pub use unic_langid_macros::langid;
+#[cfg(feature = "unic-langid-macros")]
+#[doc(hidden)]
+pub use unic_langid_macros::static_langid;
 
 #[cfg(feature = "unic-langid-macros")]
 #[macro_export]
@@ -104,3 +161,16 @@ macro_rules! langids {
         }
     };
 }
+
+#[cfg(feature = "unic-langid-macros")]
+#[macro_export]
+macro_rules! static_langids {
+    ( $(#[$meta:meta])* $vis:vis static $name:ident = [ $($langid:expr),* $(,)? ]; ) => {
+        $(#[$meta])*
+        $vis static $name: &[$crate::LanguageIdentifier] = &[
+            $(
+                $crate::static_langid!($langid),
+            )*
+        ];
+    };
+}
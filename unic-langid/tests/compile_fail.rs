@@ -0,0 +1,6 @@
+#[test]
+#[cfg(feature = "macros")]
+fn static_langids_variants() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/static_langids_variants.rs");
+}
@@ -1,6 +1,6 @@
 use unic_langid::LanguageIdentifier;
 #[cfg(feature = "unic-langid-macros")]
-use unic_langid::{langid, langids};
+use unic_langid::{langid, langids, static_langids};
 
 #[test]
 fn basic_test() {
@@ -22,3 +22,16 @@ fn langids_macro_test() {
     assert_eq!(langids.len(), 4);
     assert_eq!(langids.get(3).unwrap().get_language(), "pl");
 }
+
+#[cfg(feature = "unic-langid-macros")]
+static_langids! {
+    static SUPPORTED = ["en-US", "pl", "de-AT", "Pl-Latn-PL"];
+}
+
+#[test]
+#[cfg(feature = "unic-langid-macros")]
+fn static_langids_macro_test() {
+    assert_eq!(SUPPORTED.len(), 4);
+    assert_eq!(&SUPPORTED[0].to_string(), "en-US");
+    assert_eq!(&SUPPORTED[3].to_string(), "pl-Latn-PL");
+}
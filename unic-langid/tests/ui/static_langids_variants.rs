@@ -0,0 +1,9 @@
+use unic_langid::static_langids;
+
+static_langids! {
+    static SUPPORTED = ["en-US", "de-CH-1996"];
+}
+
+fn main() {
+    let _ = SUPPORTED;
+}
@@ -0,0 +1,33 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+use unic_locale_impl::Locale;
+
+fn locale_display_bench(c: &mut Criterion) {
+    let locales: Vec<Locale> = [
+        "en-US-u-hc-h12",
+        "en-GB-u-ca-gregory-hc-h12",
+        "es-AR-x-private",
+        "th-u-ca-buddhist",
+        "de-u-co-phonebk-ka-shifted",
+        "ar-u-nu-native",
+        "ar-u-nu-latn",
+        "ja-t-it",
+        "ja-Kana-t-it",
+        "und-Latn-t-und-cyrl",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    c.bench_function("locale_to_string", move |b| {
+        b.iter(|| {
+            for locale in &locales {
+                let _ = locale.to_string();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, locale_display_bench,);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+use serde_json::Value;
+use std::fs;
+
+/// Returns the first currency of a region which is still in use and
+/// legal tender, the order of CLDR being the order of preference.
+fn get_current_currency(currencies: &[Value]) -> Option<&str> {
+    currencies.iter().find_map(|entry| {
+        let (currency, info) = entry.as_object()?.iter().next()?;
+        if info.get("_to").is_some() || info["_tender"] == "false" {
+            return None;
+        }
+        Some(currency.as_str())
+    })
+}
+
+fn main() {
+    let contents = fs::read_to_string("./data/currencyData.json")
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let regions = v["supplemental"]["currencyData"]["region"]
+        .as_object()
+        .unwrap();
+
+    let mut result: Vec<(&str, &str)> = regions
+        .iter()
+        .filter(|(region, _)| region.len() == 2)
+        .filter_map(|(region, currencies)| {
+            get_current_currency(currencies.as_array().unwrap())
+                .map(|currency| (region.as_str(), currency))
+        })
+        .collect();
+    result.sort_unstable();
+
+    let version = v["supplemental"]["version"]["_cldrVersion"]
+        .as_str()
+        .unwrap();
+    println!("//! Region currencies, based on CLDR `currencyData`.\n");
+    println!("/// Version of CLDR the table is based on.");
+    println!("pub const CLDR_VERSION: &str = \"{}\";\n", version);
+    println!("/// Sorted list of regions with their current legal tender currency.");
+    println!(
+        "pub const REGION_CURRENCIES: [(&str, &str); {}] = [",
+        result.len()
+    );
+    for chunk in result.chunks(6) {
+        let line: Vec<String> = chunk
+            .iter()
+            .map(|(region, currency)| format!("(\"{}\", \"{}\")", region, currency))
+            .collect();
+        println!("    {},", line.join(", "));
+    }
+    println!("];");
+    println!(
+        r#"
+pub fn get_region_currency(region: &str) -> Option<&'static str> {{
+    REGION_CURRENCIES
+        .binary_search_by_key(&region, |(r, _)| *r)
+        .ok()
+        .map(|idx| REGION_CURRENCIES[idx].1)
+}}"#
+    );
+}
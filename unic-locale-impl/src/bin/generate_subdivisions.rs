@@ -0,0 +1,63 @@
+use std::env;
+use std::fs;
+
+/// Expands a CLDR string range such as `ad02~8` into `ad02`, `ad03`, ..., `ad08`.
+fn expand_range(item: &str, result: &mut Vec<String>) {
+    let (start, end) = match item.split_once('~') {
+        Some(range) => range,
+        None => {
+            result.push(item.to_string());
+            return;
+        }
+    };
+    let (prefix, first) = start.split_at(start.len() - 1);
+    let (first, last) = match (first.chars().next(), end.chars().next()) {
+        (Some(first), Some(last)) if end.len() == 1 => (first, last),
+        _ => panic!("Unsupported range: {}", item),
+    };
+    result.extend((first..=last).map(|c| format!("{}{}", prefix, c)));
+}
+
+/// Returns the subdivisions of the `<id>` element with the given status.
+fn get_ids(source: &str, status: &str, result: &mut Vec<String>) {
+    let tag = format!("<id type=\"subdivision\" idStatus=\"{}\">", status);
+    let start = source.find(&tag).expect("Missing subdivision ids") + tag.len();
+    let end = start + source[start..].find("</id>").unwrap();
+    for item in source[start..end].split_whitespace() {
+        expand_range(item, result);
+    }
+}
+
+fn main() {
+    let version = env::args()
+        .nth(1)
+        .expect("Usage: generate_subdivisions <CLDR version>");
+    let source = fs::read_to_string("./data/subdivision.xml")
+        .expect("Something went wrong reading the file");
+
+    // Deprecated subdivisions are still valid, only `unknown` ones are not.
+    let mut result = vec![];
+    get_ids(&source, "regular", &mut result);
+    get_ids(&source, "deprecated", &mut result);
+    result.sort_unstable();
+    result.dedup();
+
+    println!(
+        "//! Subdivisions of the `-u-sd-` keyword, based on CLDR `validity/subdivision.xml`.\n"
+    );
+    println!("/// Version of CLDR the table is based on.");
+    println!("pub const CLDR_VERSION: &str = \"{}\";\n", version);
+    println!("/// Sorted list of regular and deprecated CLDR subdivision identifiers.");
+    println!("pub static SUBDIVISIONS: [&str; {}] = [", result.len());
+    for chunk in result.chunks(12) {
+        let line: Vec<String> = chunk.iter().map(|sd| format!("\"{}\"", sd)).collect();
+        println!("    {},", line.join(", "));
+    }
+    println!("];");
+    println!(
+        r#"
+pub fn is_known_subdivision(subdivision: &str) -> bool {{
+    SUBDIVISIONS.binary_search(&subdivision).is_ok()
+}}"#
+    );
+}
@@ -0,0 +1,99 @@
+use serde_json::Value;
+use std::fs;
+
+fn print_table(doc: &str, name: &str, rows: &[(&str, &str)]) {
+    println!("/// {}", doc);
+    println!("pub const {}: [(&str, &str); {}] = [", name, rows.len());
+    for (key, value) in rows {
+        println!("    (\"{}\", \"{}\"),", key, value);
+    }
+    println!("];\n");
+}
+
+fn main() {
+    let contents =
+        fs::read_to_string("./data/timezone.json").expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let types = v["keyword"]["u"]["tz"].as_object().unwrap();
+
+    let mut timezones = vec![];
+    let mut deprecated = vec![];
+    let mut iana_aliases = vec![];
+
+    for (id, info) in types.iter().filter(|(id, _)| !id.starts_with('_')) {
+        if info["_deprecated"] == "true" {
+            if let Some(preferred) = info["_preferred"].as_str() {
+                deprecated.push((id.as_str(), preferred));
+            }
+            continue;
+        }
+        let aliases: Vec<&str> = match info["_alias"].as_str() {
+            Some(aliases) => aliases.split(' ').collect(),
+            None => continue,
+        };
+        // The first alias is the CLDR canonical name, which may be an
+        // outdated IANA link; `_iana` then holds the current IANA name.
+        let name = info["_iana"].as_str().unwrap_or(aliases[0]);
+        timezones.push((id.as_str(), name));
+        iana_aliases.extend(
+            aliases
+                .into_iter()
+                .filter(|alias| *alias != name)
+                .map(|alias| (alias, id.as_str())),
+        );
+    }
+    timezones.sort_unstable();
+    deprecated.sort_unstable();
+    iana_aliases.sort_unstable();
+
+    let version = v["version"]["_cldrVersion"].as_str().unwrap();
+    println!("//! Time zones of the `-u-tz-` keyword, based on CLDR `bcp47/timezone.xml`.\n");
+    println!("/// Version of CLDR the table is based on.");
+    println!("pub const CLDR_VERSION: &str = \"{}\";\n", version);
+    print_table(
+        "Sorted list of CLDR short time zone identifiers with their IANA time zone names.",
+        "TIMEZONES",
+        &timezones,
+    );
+    print_table(
+        "Sorted list of deprecated short time zone identifiers with their replacements.",
+        "TIMEZONE_ALIASES",
+        &deprecated,
+    );
+    print_table(
+        "Sorted list of IANA time zone aliases with their short time zone identifiers.",
+        "IANA_ALIASES",
+        &iana_aliases,
+    );
+    print!(
+        r#"/// Returns the canonical short time zone identifier, replacing deprecated ones.
+pub fn canonicalize_timezone(tz: &str) -> Option<&'static str> {{
+    if let Ok(idx) = TIMEZONES.binary_search_by(|(id, _)| (*id).cmp(tz)) {{
+        return Some(TIMEZONES[idx].0);
+    }}
+    TIMEZONE_ALIASES
+        .binary_search_by(|(id, _)| (*id).cmp(tz))
+        .ok()
+        .map(|idx| TIMEZONE_ALIASES[idx].1)
+}}
+
+pub fn get_iana_name(tz: &str) -> Option<&'static str> {{
+    let tz = canonicalize_timezone(tz)?;
+    TIMEZONES
+        .binary_search_by(|(id, _)| (*id).cmp(tz))
+        .ok()
+        .map(|idx| TIMEZONES[idx].1)
+}}
+
+pub fn get_timezone(iana_name: &str) -> Option<&'static str> {{
+    if let Some((id, _)) = TIMEZONES.iter().find(|(_, name)| *name == iana_name) {{
+        return Some(id);
+    }}
+    IANA_ALIASES
+        .binary_search_by(|(name, _)| (*name).cmp(iana_name))
+        .ok()
+        .map(|idx| IANA_ALIASES[idx].1)
+}}
+"#
+    );
+}
@@ -0,0 +1,83 @@
+//! Keys and values of the `-u-` extension, based on CLDR `bcp47/*.xml`.
+
+/// Version of CLDR the table is based on.
+pub const CLDR_VERSION: &str = "44";
+
+/// Sorted list of the keys of the `-u-` extension with their sorted values.
+///
+/// `None` stands for keys whose values are not enumerated, such as currency
+/// codes or subdivisions. Values spanning several subtags are joined with `-`.
+pub const KEYWORDS: [(&str, Option<&[&str]>); 29] = [
+    (
+        "ca",
+        Some(&[
+            "buddhist", "chinese", "coptic", "dangi", "ethioaa", "ethiopic", "gregory", "hebrew",
+            "indian", "islamic", "islamic-civil", "islamic-rgsa", "islamic-tbla",
+            "islamic-umalqura", "iso8601", "japanese", "persian", "roc",
+        ]),
+    ),
+    ("cf", Some(&["account", "standard"])),
+    (
+        "co",
+        Some(&[
+            "big5han", "compat", "dict", "direct", "ducet", "emoji", "eor", "gb2312", "phonebk",
+            "phonetic", "pinyin", "reformed", "search", "searchjl", "standard", "stroke", "trad",
+            "unihan", "zhuyin",
+        ]),
+    ),
+    ("cu", None),
+    ("dx", None),
+    ("em", Some(&["default", "emoji", "text"])),
+    ("fw", Some(&["fri", "mon", "sat", "sun", "thu", "tue", "wed"])),
+    ("hc", Some(&["h11", "h12", "h23", "h24"])),
+    ("ka", Some(&["noignore", "shifted"])),
+    ("kb", Some(&["false", "true"])),
+    ("kc", Some(&["false", "true"])),
+    ("kf", Some(&["false", "lower", "upper"])),
+    ("kh", Some(&["false", "true"])),
+    ("kk", Some(&["false", "true"])),
+    ("kn", Some(&["false", "true"])),
+    ("kr", None),
+    ("ks", Some(&["identic", "level1", "level2", "level3", "level4"])),
+    ("kv", Some(&["currency", "punct", "space", "symbol"])),
+    ("lb", Some(&["loose", "normal", "strict"])),
+    ("lw", Some(&["breakall", "keepall", "normal", "phrase"])),
+    ("ms", Some(&["metric", "uksystem", "ussystem"])),
+    ("mu", Some(&["celsius", "fahrenhe", "kelvin"])),
+    (
+        "nu",
+        Some(&[
+            "adlm", "ahom", "arab", "arabext", "armn", "armnlow", "bali", "beng", "bhks", "brah",
+            "cakm", "cham", "cyrl", "deva", "diak", "ethi", "finance", "fullwide", "geor", "gong",
+            "gonm", "grek", "greklow", "gujr", "guru", "hanidays", "hanidec", "hans", "hansfin",
+            "hant", "hantfin", "hebr", "hmng", "hmnp", "java", "jpan", "jpanfin", "jpanyear",
+            "kali", "kawi", "khmr", "knda", "lana", "lanatham", "laoo", "latn", "lepc", "limb",
+            "mathbold", "mathdbl", "mathmono", "mathsanb", "mathsans", "mlym", "modi", "mong",
+            "mroo", "mtei", "mymr", "mymrshan", "mymrtlng", "nagm", "native", "newa", "nkoo",
+            "olck", "orya", "osma", "rohg", "roman", "romanlow", "saur", "segment", "shrd", "sind",
+            "sinh", "sora", "sund", "takr", "talu", "taml", "tamldec", "telu", "thai", "tibt",
+            "tirh", "tnsa", "traditio", "vaii", "wara", "wcho",
+        ]),
+    ),
+    ("rg", None),
+    ("sd", None),
+    ("ss", Some(&["none", "standard"])),
+    ("tz", None),
+    ("va", Some(&["posix"])),
+    ("vt", None),
+];
+
+/// Returns `true` if the key is known, and so is the value unless the
+/// values of the key are not enumerated.
+///
+/// Boolean keywords without a value stand for `true`.
+pub fn is_known_keyword(key: &str, value: &str) -> bool {
+    let value = if value.is_empty() { "true" } else { value };
+    match KEYWORDS.binary_search_by_key(&key, |(k, _)| *k) {
+        Ok(idx) => match KEYWORDS[idx].1 {
+            Some(values) => values.binary_search(&value).is_ok(),
+            None => true,
+        },
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,17 @@
+//! Locale data tables derived from CLDR.
+//!
+//! Each table is behind its own feature.
+#[cfg(feature = "currency-data")]
+pub(crate) mod currency;
+#[cfg(feature = "keyword-data")]
+pub(crate) mod keyword;
+#[cfg(feature = "numbering-data")]
+pub(crate) mod numbering;
+#[cfg(feature = "subdivision-data")]
+pub(crate) mod subdivision;
+#[cfg(feature = "time-data")]
+pub(crate) mod time;
+#[cfg(feature = "timezone-data")]
+pub(crate) mod timezone;
+#[cfg(feature = "week-data")]
+pub(crate) mod week;
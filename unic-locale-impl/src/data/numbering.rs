@@ -0,0 +1,52 @@
+//! Default numbering systems, based on CLDR `defaultNumberingSystem`.
+
+/// Version of CLDR the table is based on.
+pub const CLDR_VERSION: &str = "44";
+
+/// The numbering system used when no other is specified.
+pub const DEFAULT_NUMBERING_SYSTEM: &str = "latn";
+
+/// Locales with a default numbering system other than `latn`.
+///
+/// Each entry is `(language, script, region, numbering system)`.
+/// More specific entries come before the generic entry for the same language.
+pub const NUMBERING_SYSTEMS: &[(&str, Option<&str>, Option<&str>, &str)] = &[
+    ("ar", None, Some("DZ"), "latn"),
+    ("ar", None, Some("EH"), "latn"),
+    ("ar", None, Some("LY"), "latn"),
+    ("ar", None, Some("MA"), "latn"),
+    ("ar", None, Some("TN"), "latn"),
+    ("ar", None, None, "arab"),
+    ("as", None, None, "beng"),
+    ("bn", None, None, "beng"),
+    ("ckb", None, None, "arab"),
+    ("dz", None, None, "tibt"),
+    ("fa", None, None, "arabext"),
+    ("ks", None, None, "arabext"),
+    ("lrc", None, None, "arabext"),
+    ("mr", None, None, "deva"),
+    ("my", None, None, "mymr"),
+    ("mzn", None, None, "arabext"),
+    ("ne", None, None, "deva"),
+    ("pa", Some("Arab"), None, "arabext"),
+    ("ps", None, None, "arabext"),
+    ("sd", None, None, "arab"),
+    ("ur", None, Some("IN"), "arabext"),
+    ("uz", Some("Arab"), None, "arabext"),
+];
+
+pub fn get_numbering_system(
+    language: &str,
+    script: Option<&str>,
+    region: Option<&str>,
+) -> &'static str {
+    NUMBERING_SYSTEMS
+        .iter()
+        .find(|(l, s, r, _)| {
+            *l == language
+                && (s.is_none() || *s == script)
+                && (r.is_none() || *r == region)
+        })
+        .map(|(_, _, _, nu)| *nu)
+        .unwrap_or(DEFAULT_NUMBERING_SYSTEM)
+}
@@ -0,0 +1,24 @@
+//! Default hour cycles, based on CLDR `timeData`.
+
+/// Version of CLDR the table is based on.
+pub const CLDR_VERSION: &str = "44";
+
+/// The hour cycle used in regions which are not listed.
+pub const DEFAULT_HOUR_CYCLE: &str = "h23";
+
+/// Sorted list of regions which prefer the 12-hour clock (`h12`).
+pub const HOUR_CYCLE_H12: [&str; 83] = [
+    "AE", "AG", "AL", "AS", "AU", "BB", "BD", "BH", "BM", "BN", "BS", "BT", "CA", "CO", "CY", "DJ",
+    "DM", "DZ", "EG", "EH", "ER", "ET", "FJ", "FM", "GD", "GH", "GM", "GU", "GY", "HK", "IN", "IQ",
+    "JO", "KI", "KN", "KP", "KR", "KW", "KY", "LB", "LC", "LR", "LS", "LY", "MH", "MO", "MP", "MR",
+    "MW", "MY", "NA", "NZ", "OM", "PG", "PH", "PK", "PR", "PS", "QA", "SA", "SB", "SD", "SL", "SO",
+    "SS", "SY", "SZ", "TC", "TD", "TN", "TO", "TT", "TW", "UM", "US", "VC", "VG", "VI", "VU", "WS",
+    "YE", "ZA", "ZM",
+];
+
+pub fn get_hour_cycle(region: Option<&str>) -> &'static str {
+    match region {
+        Some(region) if HOUR_CYCLE_H12.binary_search(&region).is_ok() => "h12",
+        _ => DEFAULT_HOUR_CYCLE,
+    }
+}
@@ -0,0 +1,647 @@
+//! Time zones of the `-u-tz-` keyword, based on CLDR `bcp47/timezone.xml`.
+
+/// Version of CLDR the table is based on.
+pub const CLDR_VERSION: &str = "42";
+
+/// Sorted list of CLDR short time zone identifiers with their IANA time zone names.
+pub const TIMEZONES: [(&str, &str); 461] = [
+    ("adalv", "Europe/Andorra"),
+    ("aedxb", "Asia/Dubai"),
+    ("afkbl", "Asia/Kabul"),
+    ("aganu", "America/Antigua"),
+    ("aiaxa", "America/Anguilla"),
+    ("altia", "Europe/Tirane"),
+    ("amevn", "Asia/Yerevan"),
+    ("ancur", "America/Curacao"),
+    ("aolad", "Africa/Luanda"),
+    ("aqcas", "Antarctica/Casey"),
+    ("aqdav", "Antarctica/Davis"),
+    ("aqddu", "Antarctica/DumontDUrville"),
+    ("aqmaw", "Antarctica/Mawson"),
+    ("aqmcm", "Antarctica/McMurdo"),
+    ("aqplm", "Antarctica/Palmer"),
+    ("aqrot", "Antarctica/Rothera"),
+    ("aqsyw", "Antarctica/Syowa"),
+    ("aqtrl", "Antarctica/Troll"),
+    ("aqvos", "Antarctica/Vostok"),
+    ("arbue", "America/Argentina/Buenos_Aires"),
+    ("arcor", "America/Argentina/Cordoba"),
+    ("arctc", "America/Argentina/Catamarca"),
+    ("arirj", "America/Argentina/La_Rioja"),
+    ("arjuj", "America/Argentina/Jujuy"),
+    ("arluq", "America/Argentina/San_Luis"),
+    ("armdz", "America/Argentina/Mendoza"),
+    ("arrgl", "America/Argentina/Rio_Gallegos"),
+    ("arsla", "America/Argentina/Salta"),
+    ("artuc", "America/Argentina/Tucuman"),
+    ("aruaq", "America/Argentina/San_Juan"),
+    ("arush", "America/Argentina/Ushuaia"),
+    ("asppg", "Pacific/Pago_Pago"),
+    ("atvie", "Europe/Vienna"),
+    ("auadl", "Australia/Adelaide"),
+    ("aubhq", "Australia/Broken_Hill"),
+    ("aubne", "Australia/Brisbane"),
+    ("audrw", "Australia/Darwin"),
+    ("aueuc", "Australia/Eucla"),
+    ("auhba", "Australia/Hobart"),
+    ("aukns", "Australia/Currie"),
+    ("auldc", "Australia/Lindeman"),
+    ("auldh", "Australia/Lord_Howe"),
+    ("aumel", "Australia/Melbourne"),
+    ("aumqi", "Antarctica/Macquarie"),
+    ("auper", "Australia/Perth"),
+    ("ausyd", "Australia/Sydney"),
+    ("awaua", "America/Aruba"),
+    ("azbak", "Asia/Baku"),
+    ("basjj", "Europe/Sarajevo"),
+    ("bbbgi", "America/Barbados"),
+    ("bddac", "Asia/Dhaka"),
+    ("bebru", "Europe/Brussels"),
+    ("bfoua", "Africa/Ouagadougou"),
+    ("bgsof", "Europe/Sofia"),
+    ("bhbah", "Asia/Bahrain"),
+    ("bibjm", "Africa/Bujumbura"),
+    ("bjptn", "Africa/Porto-Novo"),
+    ("bmbda", "Atlantic/Bermuda"),
+    ("bnbwn", "Asia/Brunei"),
+    ("bolpb", "America/La_Paz"),
+    ("bqkra", "America/Kralendijk"),
+    ("braux", "America/Araguaina"),
+    ("brbel", "America/Belem"),
+    ("brbvb", "America/Boa_Vista"),
+    ("brcgb", "America/Cuiaba"),
+    ("brcgr", "America/Campo_Grande"),
+    ("brern", "America/Eirunepe"),
+    ("brfen", "America/Noronha"),
+    ("brfor", "America/Fortaleza"),
+    ("brmao", "America/Manaus"),
+    ("brmcz", "America/Maceio"),
+    ("brpvh", "America/Porto_Velho"),
+    ("brrbr", "America/Rio_Branco"),
+    ("brrec", "America/Recife"),
+    ("brsao", "America/Sao_Paulo"),
+    ("brssa", "America/Bahia"),
+    ("brstm", "America/Santarem"),
+    ("bsnas", "America/Nassau"),
+    ("btthi", "Asia/Thimphu"),
+    ("bwgbe", "Africa/Gaborone"),
+    ("bymsq", "Europe/Minsk"),
+    ("bzbze", "America/Belize"),
+    ("cacfq", "America/Creston"),
+    ("caedm", "America/Edmonton"),
+    ("caffs", "America/Rainy_River"),
+    ("cafne", "America/Fort_Nelson"),
+    ("caglb", "America/Glace_Bay"),
+    ("cagoo", "America/Goose_Bay"),
+    ("cahal", "America/Halifax"),
+    ("caiql", "America/Iqaluit"),
+    ("camon", "America/Moncton"),
+    ("canpg", "America/Nipigon"),
+    ("capnt", "America/Pangnirtung"),
+    ("careb", "America/Resolute"),
+    ("careg", "America/Regina"),
+    ("casjf", "America/St_Johns"),
+    ("cathu", "America/Thunder_Bay"),
+    ("cator", "America/Toronto"),
+    ("cavan", "America/Vancouver"),
+    ("cawnp", "America/Winnipeg"),
+    ("caybx", "America/Blanc-Sablon"),
+    ("caycb", "America/Cambridge_Bay"),
+    ("cayda", "America/Dawson"),
+    ("caydq", "America/Dawson_Creek"),
+    ("cayek", "America/Rankin_Inlet"),
+    ("cayev", "America/Inuvik"),
+    ("cayxy", "America/Whitehorse"),
+    ("cayyn", "America/Swift_Current"),
+    ("cayzf", "America/Yellowknife"),
+    ("cayzs", "America/Atikokan"),
+    ("cccck", "Indian/Cocos"),
+    ("cdfbm", "Africa/Lubumbashi"),
+    ("cdfih", "Africa/Kinshasa"),
+    ("cfbgf", "Africa/Bangui"),
+    ("cgbzv", "Africa/Brazzaville"),
+    ("chzrh", "Europe/Zurich"),
+    ("ciabj", "Africa/Abidjan"),
+    ("ckrar", "Pacific/Rarotonga"),
+    ("clipc", "Pacific/Easter"),
+    ("clpuq", "America/Punta_Arenas"),
+    ("clscl", "America/Santiago"),
+    ("cmdla", "Africa/Douala"),
+    ("cnsha", "Asia/Shanghai"),
+    ("cnurc", "Asia/Urumqi"),
+    ("cobog", "America/Bogota"),
+    ("crsjo", "America/Costa_Rica"),
+    ("cst6cdt", "CST6CDT"),
+    ("cuhav", "America/Havana"),
+    ("cvrai", "Atlantic/Cape_Verde"),
+    ("cxxch", "Indian/Christmas"),
+    ("cyfmg", "Asia/Famagusta"),
+    ("cynic", "Asia/Nicosia"),
+    ("czprg", "Europe/Prague"),
+    ("deber", "Europe/Berlin"),
+    ("debsngn", "Europe/Busingen"),
+    ("djjib", "Africa/Djibouti"),
+    ("dkcph", "Europe/Copenhagen"),
+    ("dmdom", "America/Dominica"),
+    ("dosdq", "America/Santo_Domingo"),
+    ("dzalg", "Africa/Algiers"),
+    ("ecgps", "Pacific/Galapagos"),
+    ("ecgye", "America/Guayaquil"),
+    ("eetll", "Europe/Tallinn"),
+    ("egcai", "Africa/Cairo"),
+    ("eheai", "Africa/El_Aaiun"),
+    ("erasm", "Africa/Asmara"),
+    ("esceu", "Africa/Ceuta"),
+    ("eslpa", "Atlantic/Canary"),
+    ("esmad", "Europe/Madrid"),
+    ("est5edt", "EST5EDT"),
+    ("etadd", "Africa/Addis_Ababa"),
+    ("fihel", "Europe/Helsinki"),
+    ("fimhq", "Europe/Mariehamn"),
+    ("fjsuv", "Pacific/Fiji"),
+    ("fkpsy", "Atlantic/Stanley"),
+    ("fmksa", "Pacific/Kosrae"),
+    ("fmpni", "Pacific/Pohnpei"),
+    ("fmtkk", "Pacific/Chuuk"),
+    ("fotho", "Atlantic/Faroe"),
+    ("frpar", "Europe/Paris"),
+    ("galbv", "Africa/Libreville"),
+    ("gazastrp", "Asia/Gaza"),
+    ("gblon", "Europe/London"),
+    ("gdgnd", "America/Grenada"),
+    ("getbs", "Asia/Tbilisi"),
+    ("gfcay", "America/Cayenne"),
+    ("gggci", "Europe/Guernsey"),
+    ("ghacc", "Africa/Accra"),
+    ("gigib", "Europe/Gibraltar"),
+    ("gldkshvn", "America/Danmarkshavn"),
+    ("glgoh", "America/Nuuk"),
+    ("globy", "America/Scoresbysund"),
+    ("glthu", "America/Thule"),
+    ("gmbjl", "Africa/Banjul"),
+    ("gmt", "Etc/GMT"),
+    ("gncky", "Africa/Conakry"),
+    ("gpbbr", "America/Guadeloupe"),
+    ("gpmsb", "America/Marigot"),
+    ("gpsbh", "America/St_Barthelemy"),
+    ("gqssg", "Africa/Malabo"),
+    ("grath", "Europe/Athens"),
+    ("gsgrv", "Atlantic/South_Georgia"),
+    ("gtgua", "America/Guatemala"),
+    ("gugum", "Pacific/Guam"),
+    ("gwoxb", "Africa/Bissau"),
+    ("gygeo", "America/Guyana"),
+    ("hebron", "Asia/Hebron"),
+    ("hkhkg", "Asia/Hong_Kong"),
+    ("hntgu", "America/Tegucigalpa"),
+    ("hrzag", "Europe/Zagreb"),
+    ("htpap", "America/Port-au-Prince"),
+    ("hubud", "Europe/Budapest"),
+    ("iddjj", "Asia/Jayapura"),
+    ("idjkt", "Asia/Jakarta"),
+    ("idmak", "Asia/Makassar"),
+    ("idpnk", "Asia/Pontianak"),
+    ("iedub", "Europe/Dublin"),
+    ("imdgs", "Europe/Isle_of_Man"),
+    ("inccu", "Asia/Kolkata"),
+    ("iodga", "Indian/Chagos"),
+    ("iqbgw", "Asia/Baghdad"),
+    ("irthr", "Asia/Tehran"),
+    ("isrey", "Atlantic/Reykjavik"),
+    ("itrom", "Europe/Rome"),
+    ("jeruslm", "Asia/Jerusalem"),
+    ("jesth", "Europe/Jersey"),
+    ("jmkin", "America/Jamaica"),
+    ("joamm", "Asia/Amman"),
+    ("jptyo", "Asia/Tokyo"),
+    ("kenbo", "Africa/Nairobi"),
+    ("kgfru", "Asia/Bishkek"),
+    ("khpnh", "Asia/Phnom_Penh"),
+    ("kicxi", "Pacific/Kiritimati"),
+    ("kipho", "Pacific/Kanton"),
+    ("kitrw", "Pacific/Tarawa"),
+    ("kmyva", "Indian/Comoro"),
+    ("knbas", "America/St_Kitts"),
+    ("kpfnj", "Asia/Pyongyang"),
+    ("krsel", "Asia/Seoul"),
+    ("kwkwi", "Asia/Kuwait"),
+    ("kygec", "America/Cayman"),
+    ("kzaau", "Asia/Aqtau"),
+    ("kzakx", "Asia/Aqtobe"),
+    ("kzala", "Asia/Almaty"),
+    ("kzguw", "Asia/Atyrau"),
+    ("kzksn", "Asia/Qostanay"),
+    ("kzkzo", "Asia/Qyzylorda"),
+    ("kzura", "Asia/Oral"),
+    ("lavte", "Asia/Vientiane"),
+    ("lbbey", "Asia/Beirut"),
+    ("lccas", "America/St_Lucia"),
+    ("livdz", "Europe/Vaduz"),
+    ("lkcmb", "Asia/Colombo"),
+    ("lrmlw", "Africa/Monrovia"),
+    ("lsmsu", "Africa/Maseru"),
+    ("ltvno", "Europe/Vilnius"),
+    ("lulux", "Europe/Luxembourg"),
+    ("lvrix", "Europe/Riga"),
+    ("lytip", "Africa/Tripoli"),
+    ("macas", "Africa/Casablanca"),
+    ("mcmon", "Europe/Monaco"),
+    ("mdkiv", "Europe/Chisinau"),
+    ("metgd", "Europe/Podgorica"),
+    ("mgtnr", "Indian/Antananarivo"),
+    ("mhkwa", "Pacific/Kwajalein"),
+    ("mhmaj", "Pacific/Majuro"),
+    ("mkskp", "Europe/Skopje"),
+    ("mlbko", "Africa/Bamako"),
+    ("mmrgn", "Asia/Yangon"),
+    ("mncoq", "Asia/Choibalsan"),
+    ("mnhvd", "Asia/Hovd"),
+    ("mnuln", "Asia/Ulaanbaatar"),
+    ("momfm", "Asia/Macau"),
+    ("mpspn", "Pacific/Saipan"),
+    ("mqfdf", "America/Martinique"),
+    ("mrnkc", "Africa/Nouakchott"),
+    ("msmni", "America/Montserrat"),
+    ("mst7mdt", "MST7MDT"),
+    ("mtmla", "Europe/Malta"),
+    ("muplu", "Indian/Mauritius"),
+    ("mvmle", "Indian/Maldives"),
+    ("mwblz", "Africa/Blantyre"),
+    ("mxchi", "America/Chihuahua"),
+    ("mxcjs", "America/Ciudad_Juarez"),
+    ("mxcun", "America/Cancun"),
+    ("mxhmo", "America/Hermosillo"),
+    ("mxmam", "America/Matamoros"),
+    ("mxmex", "America/Mexico_City"),
+    ("mxmid", "America/Merida"),
+    ("mxmty", "America/Monterrey"),
+    ("mxmzt", "America/Mazatlan"),
+    ("mxoji", "America/Ojinaga"),
+    ("mxpvr", "America/Bahia_Banderas"),
+    ("mxstis", "America/Santa_Isabel"),
+    ("mxtij", "America/Tijuana"),
+    ("mykch", "Asia/Kuching"),
+    ("mykul", "Asia/Kuala_Lumpur"),
+    ("mzmpm", "Africa/Maputo"),
+    ("nawdh", "Africa/Windhoek"),
+    ("ncnou", "Pacific/Noumea"),
+    ("nenim", "Africa/Niamey"),
+    ("nfnlk", "Pacific/Norfolk"),
+    ("nglos", "Africa/Lagos"),
+    ("nimga", "America/Managua"),
+    ("nlams", "Europe/Amsterdam"),
+    ("noosl", "Europe/Oslo"),
+    ("npktm", "Asia/Kathmandu"),
+    ("nrinu", "Pacific/Nauru"),
+    ("nuiue", "Pacific/Niue"),
+    ("nzakl", "Pacific/Auckland"),
+    ("nzcht", "Pacific/Chatham"),
+    ("ommct", "Asia/Muscat"),
+    ("papty", "America/Panama"),
+    ("pelim", "America/Lima"),
+    ("pfgmr", "Pacific/Gambier"),
+    ("pfnhv", "Pacific/Marquesas"),
+    ("pfppt", "Pacific/Tahiti"),
+    ("pgpom", "Pacific/Port_Moresby"),
+    ("pgraw", "Pacific/Bougainville"),
+    ("phmnl", "Asia/Manila"),
+    ("pkkhi", "Asia/Karachi"),
+    ("plwaw", "Europe/Warsaw"),
+    ("pmmqc", "America/Miquelon"),
+    ("pnpcn", "Pacific/Pitcairn"),
+    ("prsju", "America/Puerto_Rico"),
+    ("pst8pdt", "PST8PDT"),
+    ("ptfnc", "Atlantic/Madeira"),
+    ("ptlis", "Europe/Lisbon"),
+    ("ptpdl", "Atlantic/Azores"),
+    ("pwror", "Pacific/Palau"),
+    ("pyasu", "America/Asuncion"),
+    ("qadoh", "Asia/Qatar"),
+    ("rereu", "Indian/Reunion"),
+    ("robuh", "Europe/Bucharest"),
+    ("rsbeg", "Europe/Belgrade"),
+    ("ruasf", "Europe/Astrakhan"),
+    ("rubax", "Asia/Barnaul"),
+    ("ruchita", "Asia/Chita"),
+    ("rudyr", "Asia/Anadyr"),
+    ("rugdx", "Asia/Magadan"),
+    ("ruikt", "Asia/Irkutsk"),
+    ("rukgd", "Europe/Kaliningrad"),
+    ("rukhndg", "Asia/Khandyga"),
+    ("rukra", "Asia/Krasnoyarsk"),
+    ("rukuf", "Europe/Samara"),
+    ("rukvx", "Europe/Kirov"),
+    ("rumow", "Europe/Moscow"),
+    ("runoz", "Asia/Novokuznetsk"),
+    ("ruoms", "Asia/Omsk"),
+    ("ruovb", "Asia/Novosibirsk"),
+    ("rupkc", "Asia/Kamchatka"),
+    ("rurtw", "Europe/Saratov"),
+    ("rusred", "Asia/Srednekolymsk"),
+    ("rutof", "Asia/Tomsk"),
+    ("ruuly", "Europe/Ulyanovsk"),
+    ("ruunera", "Asia/Ust-Nera"),
+    ("ruuus", "Asia/Sakhalin"),
+    ("ruvog", "Europe/Volgograd"),
+    ("ruvvo", "Asia/Vladivostok"),
+    ("ruyek", "Asia/Yekaterinburg"),
+    ("ruyks", "Asia/Yakutsk"),
+    ("rwkgl", "Africa/Kigali"),
+    ("saruh", "Asia/Riyadh"),
+    ("sbhir", "Pacific/Guadalcanal"),
+    ("scmaw", "Indian/Mahe"),
+    ("sdkrt", "Africa/Khartoum"),
+    ("sesto", "Europe/Stockholm"),
+    ("sgsin", "Asia/Singapore"),
+    ("shshn", "Atlantic/St_Helena"),
+    ("silju", "Europe/Ljubljana"),
+    ("sjlyr", "Arctic/Longyearbyen"),
+    ("skbts", "Europe/Bratislava"),
+    ("slfna", "Africa/Freetown"),
+    ("smsai", "Europe/San_Marino"),
+    ("sndkr", "Africa/Dakar"),
+    ("somgq", "Africa/Mogadishu"),
+    ("srpbm", "America/Paramaribo"),
+    ("ssjub", "Africa/Juba"),
+    ("sttms", "Africa/Sao_Tome"),
+    ("svsal", "America/El_Salvador"),
+    ("sxphi", "America/Lower_Princes"),
+    ("sydam", "Asia/Damascus"),
+    ("szqmn", "Africa/Mbabane"),
+    ("tcgdt", "America/Grand_Turk"),
+    ("tdndj", "Africa/Ndjamena"),
+    ("tfpfr", "Indian/Kerguelen"),
+    ("tglfw", "Africa/Lome"),
+    ("thbkk", "Asia/Bangkok"),
+    ("tjdyu", "Asia/Dushanbe"),
+    ("tkfko", "Pacific/Fakaofo"),
+    ("tldil", "Asia/Dili"),
+    ("tmasb", "Asia/Ashgabat"),
+    ("tntun", "Africa/Tunis"),
+    ("totbu", "Pacific/Tongatapu"),
+    ("trist", "Europe/Istanbul"),
+    ("ttpos", "America/Port_of_Spain"),
+    ("tvfun", "Pacific/Funafuti"),
+    ("twtpe", "Asia/Taipei"),
+    ("tzdar", "Africa/Dar_es_Salaam"),
+    ("uaiev", "Europe/Kyiv"),
+    ("uaozh", "Europe/Zaporozhye"),
+    ("uasip", "Europe/Simferopol"),
+    ("uauzh", "Europe/Uzhgorod"),
+    ("ugkla", "Africa/Kampala"),
+    ("umawk", "Pacific/Wake"),
+    ("umjon", "Pacific/Johnston"),
+    ("ummdy", "Pacific/Midway"),
+    ("unk", "Etc/Unknown"),
+    ("usadk", "America/Adak"),
+    ("usaeg", "America/Indiana/Marengo"),
+    ("usanc", "America/Anchorage"),
+    ("usboi", "America/Boise"),
+    ("uschi", "America/Chicago"),
+    ("usden", "America/Denver"),
+    ("usdet", "America/Detroit"),
+    ("ushnl", "Pacific/Honolulu"),
+    ("usind", "America/Indiana/Indianapolis"),
+    ("usinvev", "America/Indiana/Vevay"),
+    ("usjnu", "America/Juneau"),
+    ("usknx", "America/Indiana/Knox"),
+    ("uslax", "America/Los_Angeles"),
+    ("uslui", "America/Kentucky/Louisville"),
+    ("usmnm", "America/Menominee"),
+    ("usmoc", "America/Kentucky/Monticello"),
+    ("usmtm", "America/Metlakatla"),
+    ("usndcnt", "America/North_Dakota/Center"),
+    ("usndnsl", "America/North_Dakota/New_Salem"),
+    ("usnyc", "America/New_York"),
+    ("usoea", "America/Indiana/Vincennes"),
+    ("usome", "America/Nome"),
+    ("usphx", "America/Phoenix"),
+    ("ussit", "America/Sitka"),
+    ("ustel", "America/Indiana/Tell_City"),
+    ("uswlz", "America/Indiana/Winamac"),
+    ("uswsq", "America/Indiana/Petersburg"),
+    ("usxul", "America/North_Dakota/Beulah"),
+    ("usyak", "America/Yakutat"),
+    ("utc", "Etc/UTC"),
+    ("utce01", "Etc/GMT-1"),
+    ("utce02", "Etc/GMT-2"),
+    ("utce03", "Etc/GMT-3"),
+    ("utce04", "Etc/GMT-4"),
+    ("utce05", "Etc/GMT-5"),
+    ("utce06", "Etc/GMT-6"),
+    ("utce07", "Etc/GMT-7"),
+    ("utce08", "Etc/GMT-8"),
+    ("utce09", "Etc/GMT-9"),
+    ("utce10", "Etc/GMT-10"),
+    ("utce11", "Etc/GMT-11"),
+    ("utce12", "Etc/GMT-12"),
+    ("utce13", "Etc/GMT-13"),
+    ("utce14", "Etc/GMT-14"),
+    ("utcw01", "Etc/GMT+1"),
+    ("utcw02", "Etc/GMT+2"),
+    ("utcw03", "Etc/GMT+3"),
+    ("utcw04", "Etc/GMT+4"),
+    ("utcw05", "Etc/GMT+5"),
+    ("utcw06", "Etc/GMT+6"),
+    ("utcw07", "Etc/GMT+7"),
+    ("utcw08", "Etc/GMT+8"),
+    ("utcw09", "Etc/GMT+9"),
+    ("utcw10", "Etc/GMT+10"),
+    ("utcw11", "Etc/GMT+11"),
+    ("utcw12", "Etc/GMT+12"),
+    ("uymvd", "America/Montevideo"),
+    ("uzskd", "Asia/Samarkand"),
+    ("uztas", "Asia/Tashkent"),
+    ("vavat", "Europe/Vatican"),
+    ("vcsvd", "America/St_Vincent"),
+    ("veccs", "America/Caracas"),
+    ("vgtov", "America/Tortola"),
+    ("vistt", "America/St_Thomas"),
+    ("vnsgn", "Asia/Ho_Chi_Minh"),
+    ("vuvli", "Pacific/Efate"),
+    ("wfmau", "Pacific/Wallis"),
+    ("wsapw", "Pacific/Apia"),
+    ("yeade", "Asia/Aden"),
+    ("ytmam", "Indian/Mayotte"),
+    ("zajnb", "Africa/Johannesburg"),
+    ("zmlun", "Africa/Lusaka"),
+    ("zwhre", "Africa/Harare"),
+];
+
+/// Sorted list of deprecated short time zone identifiers with their replacements.
+pub const TIMEZONE_ALIASES: [(&str, &str); 7] = [
+    ("aqams", "nzakl"),
+    ("camtr", "cator"),
+    ("cnckg", "cnsha"),
+    ("cnhrb", "cnsha"),
+    ("cnkhg", "cnurc"),
+    ("gaza", "gazastrp"),
+    ("usnavajo", "usden"),
+];
+
+/// Sorted list of IANA time zone aliases with their short time zone identifiers.
+pub const IANA_ALIASES: [(&str, &str); 134] = [
+    ("Africa/Asmera", "erasm"),
+    ("Africa/Timbuktu", "mlbko"),
+    ("America/Argentina/ComodRivadavia", "arctc"),
+    ("America/Atka", "usadk"),
+    ("America/Buenos_Aires", "arbue"),
+    ("America/Catamarca", "arctc"),
+    ("America/Coral_Harbour", "cayzs"),
+    ("America/Cordoba", "arcor"),
+    ("America/Ensenada", "mxtij"),
+    ("America/Fort_Wayne", "usind"),
+    ("America/Godthab", "glgoh"),
+    ("America/Indianapolis", "usind"),
+    ("America/Jujuy", "arjuj"),
+    ("America/Knox_IN", "usknx"),
+    ("America/Louisville", "uslui"),
+    ("America/Mendoza", "armdz"),
+    ("America/Montreal", "cator"),
+    ("America/Porto_Acre", "brrbr"),
+    ("America/Rosario", "arcor"),
+    ("America/Shiprock", "usden"),
+    ("America/Virgin", "vistt"),
+    ("Antarctica/South_Pole", "nzakl"),
+    ("Asia/Ashkhabad", "tmasb"),
+    ("Asia/Calcutta", "inccu"),
+    ("Asia/Chongqing", "cnsha"),
+    ("Asia/Chungking", "cnsha"),
+    ("Asia/Dacca", "bddac"),
+    ("Asia/Harbin", "cnsha"),
+    ("Asia/Istanbul", "trist"),
+    ("Asia/Kashgar", "cnurc"),
+    ("Asia/Katmandu", "npktm"),
+    ("Asia/Macao", "momfm"),
+    ("Asia/Rangoon", "mmrgn"),
+    ("Asia/Saigon", "vnsgn"),
+    ("Asia/Tel_Aviv", "jeruslm"),
+    ("Asia/Thimbu", "btthi"),
+    ("Asia/Ujung_Pandang", "idmak"),
+    ("Asia/Ulan_Bator", "mnuln"),
+    ("Atlantic/Faeroe", "fotho"),
+    ("Atlantic/Jan_Mayen", "sjlyr"),
+    ("Australia/ACT", "ausyd"),
+    ("Australia/Canberra", "ausyd"),
+    ("Australia/LHI", "auldh"),
+    ("Australia/NSW", "ausyd"),
+    ("Australia/North", "audrw"),
+    ("Australia/Queensland", "aubne"),
+    ("Australia/South", "auadl"),
+    ("Australia/Tasmania", "auhba"),
+    ("Australia/Victoria", "aumel"),
+    ("Australia/West", "auper"),
+    ("Australia/Yancowinna", "aubhq"),
+    ("Brazil/Acre", "brrbr"),
+    ("Brazil/DeNoronha", "brfen"),
+    ("Brazil/East", "brsao"),
+    ("Brazil/West", "brmao"),
+    ("Canada/Atlantic", "cahal"),
+    ("Canada/Central", "cawnp"),
+    ("Canada/East-Saskatchewan", "careg"),
+    ("Canada/Eastern", "cator"),
+    ("Canada/Mountain", "caedm"),
+    ("Canada/Newfoundland", "casjf"),
+    ("Canada/Pacific", "cavan"),
+    ("Canada/Saskatchewan", "careg"),
+    ("Canada/Yukon", "cayxy"),
+    ("Chile/Continental", "clscl"),
+    ("Chile/EasterIsland", "clipc"),
+    ("Cuba", "cuhav"),
+    ("EST", "utcw05"),
+    ("Egypt", "egcai"),
+    ("Eire", "iedub"),
+    ("Etc/GMT+0", "gmt"),
+    ("Etc/GMT-0", "gmt"),
+    ("Etc/GMT0", "gmt"),
+    ("Etc/Greenwich", "gmt"),
+    ("Etc/UCT", "utc"),
+    ("Etc/Universal", "utc"),
+    ("Etc/Zulu", "utc"),
+    ("Europe/Belfast", "gblon"),
+    ("Europe/Kiev", "uaiev"),
+    ("Europe/Nicosia", "cynic"),
+    ("Europe/Tiraspol", "mdkiv"),
+    ("GB", "gblon"),
+    ("GB-Eire", "gblon"),
+    ("GMT", "gmt"),
+    ("GMT+0", "gmt"),
+    ("GMT-0", "gmt"),
+    ("GMT0", "gmt"),
+    ("Greenwich", "gmt"),
+    ("HST", "utcw10"),
+    ("Hongkong", "hkhkg"),
+    ("Iceland", "isrey"),
+    ("Iran", "irthr"),
+    ("Israel", "jeruslm"),
+    ("Jamaica", "jmkin"),
+    ("Japan", "jptyo"),
+    ("Kwajalein", "mhkwa"),
+    ("Libya", "lytip"),
+    ("MST", "utcw07"),
+    ("Mexico/BajaNorte", "mxtij"),
+    ("Mexico/BajaSur", "mxmzt"),
+    ("Mexico/General", "mxmex"),
+    ("NZ", "nzakl"),
+    ("NZ-CHAT", "nzcht"),
+    ("Navajo", "usden"),
+    ("PRC", "cnsha"),
+    ("Pacific/Enderbury", "kipho"),
+    ("Pacific/Ponape", "fmpni"),
+    ("Pacific/Samoa", "asppg"),
+    ("Pacific/Truk", "fmtkk"),
+    ("Pacific/Yap", "fmtkk"),
+    ("Poland", "plwaw"),
+    ("Portugal", "ptlis"),
+    ("ROC", "twtpe"),
+    ("ROK", "krsel"),
+    ("Singapore", "sgsin"),
+    ("Turkey", "trist"),
+    ("UCT", "utc"),
+    ("US/Alaska", "usanc"),
+    ("US/Aleutian", "usadk"),
+    ("US/Arizona", "usphx"),
+    ("US/Central", "uschi"),
+    ("US/East-Indiana", "usind"),
+    ("US/Eastern", "usnyc"),
+    ("US/Hawaii", "ushnl"),
+    ("US/Indiana-Starke", "usknx"),
+    ("US/Michigan", "usdet"),
+    ("US/Mountain", "usden"),
+    ("US/Pacific", "uslax"),
+    ("US/Pacific-New", "uslax"),
+    ("US/Samoa", "asppg"),
+    ("UTC", "utc"),
+    ("Universal", "utc"),
+    ("W-SU", "rumow"),
+    ("Zulu", "utc"),
+];
+
+/// Returns the canonical short time zone identifier, replacing deprecated ones.
+pub fn canonicalize_timezone(tz: &str) -> Option<&'static str> {
+    if let Ok(idx) = TIMEZONES.binary_search_by(|(id, _)| (*id).cmp(tz)) {
+        return Some(TIMEZONES[idx].0);
+    }
+    TIMEZONE_ALIASES
+        .binary_search_by(|(id, _)| (*id).cmp(tz))
+        .ok()
+        .map(|idx| TIMEZONE_ALIASES[idx].1)
+}
+
+pub fn get_iana_name(tz: &str) -> Option<&'static str> {
+    let tz = canonicalize_timezone(tz)?;
+    TIMEZONES
+        .binary_search_by(|(id, _)| (*id).cmp(tz))
+        .ok()
+        .map(|idx| TIMEZONES[idx].1)
+}
+
+pub fn get_timezone(iana_name: &str) -> Option<&'static str> {
+    if let Some((id, _)) = TIMEZONES.iter().find(|(_, name)| *name == iana_name) {
+        return Some(id);
+    }
+    IANA_ALIASES
+        .binary_search_by(|(name, _)| (*name).cmp(iana_name))
+        .ok()
+        .map(|idx| IANA_ALIASES[idx].1)
+}
@@ -0,0 +1,31 @@
+//! First day of the week, based on CLDR `weekData`.
+
+/// Version of CLDR the table is based on.
+pub const CLDR_VERSION: &str = "44";
+
+/// The first day of the week used in regions which are not listed.
+pub const DEFAULT_FIRST_DAY: &str = "mon";
+
+/// Sorted list of regions with a first day of the week other than Monday.
+pub const FIRST_DAYS: [(&str, &str); 73] = [
+    ("AE", "sat"), ("AF", "sat"), ("AG", "sun"), ("AS", "sun"), ("AU", "sun"), ("BD", "sun"),
+    ("BH", "sat"), ("BR", "sun"), ("BS", "sun"), ("BT", "sun"), ("BW", "sun"), ("BZ", "sun"),
+    ("CA", "sun"), ("CN", "sun"), ("CO", "sun"), ("DJ", "sat"), ("DM", "sun"), ("DO", "sun"),
+    ("DZ", "sat"), ("EG", "sat"), ("ET", "sun"), ("GT", "sun"), ("GU", "sun"), ("HK", "sun"),
+    ("HN", "sun"), ("ID", "sun"), ("IL", "sun"), ("IN", "sun"), ("IQ", "sat"), ("IR", "sat"),
+    ("JM", "sun"), ("JO", "sat"), ("JP", "sun"), ("KE", "sun"), ("KH", "sun"), ("KR", "sun"),
+    ("KW", "sat"), ("LA", "sun"), ("LY", "sat"), ("MH", "sun"), ("MM", "sun"), ("MO", "sun"),
+    ("MT", "sun"), ("MV", "fri"), ("MX", "sun"), ("MZ", "sun"), ("NI", "sun"), ("NP", "sun"),
+    ("OM", "sat"), ("PA", "sun"), ("PE", "sun"), ("PH", "sun"), ("PK", "sun"), ("PR", "sun"),
+    ("PT", "sun"), ("PY", "sun"), ("QA", "sat"), ("SA", "sun"), ("SD", "sat"), ("SG", "sun"),
+    ("SV", "sun"), ("SY", "sat"), ("TH", "sun"), ("TT", "sun"), ("TW", "sun"), ("UM", "sun"),
+    ("US", "sun"), ("VE", "sun"), ("VI", "sun"), ("WS", "sun"), ("YE", "sun"), ("ZA", "sun"),
+    ("ZW", "sun"),
+];
+
+pub fn get_first_day(region: Option<&str>) -> &'static str {
+    region
+        .and_then(|region| FIRST_DAYS.binary_search_by_key(&region, |(r, _)| *r).ok())
+        .map(|idx| FIRST_DAYS[idx].1)
+        .unwrap_or(DEFAULT_FIRST_DAY)
+}
@@ -0,0 +1,85 @@
+#[cfg(feature = "currency-data")]
+use crate::data::currency;
+#[cfg(feature = "keyword-data")]
+use crate::data::keyword;
+#[cfg(feature = "numbering-data")]
+use crate::data::numbering;
+#[cfg(feature = "subdivision-data")]
+use crate::data::subdivision;
+#[cfg(feature = "time-data")]
+use crate::data::time;
+#[cfg(feature = "timezone-data")]
+use crate::data::timezone;
+#[cfg(feature = "week-data")]
+use crate::data::week;
+
+/// Versions of the CLDR data embedded in the crate.
+///
+/// Each field reports the CLDR version of a single data table.
+/// Tables which are behind a disabled feature are reported as `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataVersions {
+    /// Versions of the data embedded in `unic-langid-impl`.
+    pub langid: unic_langid_impl::DataVersions,
+    /// Version of the numbering systems data, if the `numbering-data` feature is enabled.
+    pub numbering: Option<&'static str>,
+    /// Version of the hour cycles data, if the `time-data` feature is enabled.
+    pub time: Option<&'static str>,
+    /// Version of the first day of the week data, if the `week-data` feature is enabled.
+    pub week: Option<&'static str>,
+    /// Version of the region currencies data, if the `currency-data` feature is enabled.
+    pub currency: Option<&'static str>,
+    /// Version of the time zones data, if the `timezone-data` feature is enabled.
+    pub timezone: Option<&'static str>,
+    /// Version of the `-u-` keywords data, if the `keyword-data` feature is enabled.
+    pub keyword: Option<&'static str>,
+    /// Version of the subdivisions data, if the `subdivision-data` feature is enabled.
+    pub subdivision: Option<&'static str>,
+}
+
+/// Returns the CLDR versions of all data tables embedded in the crate.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::data_versions;
+///
+/// let versions = data_versions();
+///
+/// if let Some(version) = versions.currency {
+///     println!("Currency data from CLDR {}", version);
+/// }
+/// ```
+pub fn data_versions() -> DataVersions {
+    DataVersions {
+        langid: unic_langid_impl::data_versions(),
+        #[cfg(feature = "numbering-data")]
+        numbering: Some(numbering::CLDR_VERSION),
+        #[cfg(not(feature = "numbering-data"))]
+        numbering: None,
+        #[cfg(feature = "time-data")]
+        time: Some(time::CLDR_VERSION),
+        #[cfg(not(feature = "time-data"))]
+        time: None,
+        #[cfg(feature = "week-data")]
+        week: Some(week::CLDR_VERSION),
+        #[cfg(not(feature = "week-data"))]
+        week: None,
+        #[cfg(feature = "currency-data")]
+        currency: Some(currency::CLDR_VERSION),
+        #[cfg(not(feature = "currency-data"))]
+        currency: None,
+        #[cfg(feature = "timezone-data")]
+        timezone: Some(timezone::CLDR_VERSION),
+        #[cfg(not(feature = "timezone-data"))]
+        timezone: None,
+        #[cfg(feature = "keyword-data")]
+        keyword: Some(keyword::CLDR_VERSION),
+        #[cfg(not(feature = "keyword-data"))]
+        keyword: None,
+        #[cfg(feature = "subdivision-data")]
+        subdivision: Some(subdivision::CLDR_VERSION),
+        #[cfg(not(feature = "subdivision-data"))]
+        subdivision: None,
+    }
+}
@@ -22,18 +22,14 @@ impl From<LanguageIdentifierError> for LocaleError {
     }
 }
 
-impl Error for LocaleError {
-    fn description(&self) -> &str {
-        match self {
-            LocaleError::Unknown => "Unknown error",
-            LocaleError::ParserError(p) => p.description(),
-            LocaleError::LanguageIdentifierError(_) => "LangId Error",
-        }
-    }
-}
+impl Error for LocaleError {}
 
 impl Display for LocaleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(self.description())
+        match self {
+            LocaleError::Unknown => f.write_str("Unknown error"),
+            LocaleError::ParserError(p) => write!(f, "{}", p),
+            LocaleError::LanguageIdentifierError(_) => f.write_str("LangId Error"),
+        }
     }
 }
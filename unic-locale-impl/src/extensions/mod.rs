@@ -3,70 +3,230 @@ mod transform;
 mod unicode;
 
 pub use private::PrivateExtensionList;
-pub use transform::TransformExtensionList;
-pub use unicode::UnicodeExtensionList;
+pub use transform::{TransformExtensionList, TransformMechanism};
+pub use unicode::{CollationType, Subdivision, UnicodeExtensionList};
 
 use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::iter::Peekable;
 use std::str::FromStr;
 
-use tinystr::TinyStr8;
+use tinystr::{TinyStr4, TinyStr8};
 
+use crate::errors::LocaleError;
 use crate::parser::ParserError;
+use unic_langid_impl::LanguageIdentifier;
 
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+/// Type of an extension, identified by its singleton.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::ExtensionType;
+///
+/// let ext_type: ExtensionType = "u".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(ext_type, ExtensionType::Unicode);
+///
+/// let ext_type: ExtensionType = "A".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(ext_type, ExtensionType::Other('a'));
+/// assert_eq!(ext_type.to_string(), "a");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
 pub enum ExtensionType {
+    /// The `-u-` extension.
     Unicode,
+    /// The `-t-` extension.
     Transform,
+    /// An extension with any other singleton, such as `-a-`.
     Other(char),
+    /// The `-x-` private use extension.
     Private,
 }
 
 impl ExtensionType {
     pub fn from_char(key: char) -> Result<Self, ParserError> {
-        match key {
+        match key.to_ascii_lowercase() {
             'u' => Ok(ExtensionType::Unicode),
             't' => Ok(ExtensionType::Transform),
             'x' => Ok(ExtensionType::Private),
-            sign if sign.is_ascii_alphanumeric() => {
-                Ok(ExtensionType::Other(sign.to_ascii_lowercase()))
-            }
+            sign if sign.is_ascii_alphanumeric() => Ok(ExtensionType::Other(sign)),
             _ => Err(ParserError::InvalidExtension),
         }
     }
-}
 
-impl std::fmt::Display for ExtensionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let ch = match self {
+    /// Returns the singleton of the extension.
+    pub fn as_char(&self) -> char {
+        match self {
             ExtensionType::Unicode => 'u',
             ExtensionType::Transform => 't',
             ExtensionType::Other(n) => *n,
             ExtensionType::Private => 'x',
-        };
-        f.write_char(ch)
+        }
+    }
+}
+
+impl FromStr for ExtensionType {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut chars = source.chars();
+        match (chars.next(), chars.next()) {
+            (Some(key), None) => Self::from_char(key),
+            _ => Err(ParserError::InvalidExtension),
+        }
     }
 }
 
+impl std::fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_char(self.as_char())
+    }
+}
+
+/// Extensions of a `Locale`.
+///
+/// Each of the extensions is stored in its own typed list:
+///
+///  * `unicode` - the `-u-` extension with attributes and keywords, such as `-u-ca-buddhist`,
+///  * `transform` - the `-t-` extension with a source language and fields, such as `-t-es-h0-hybrid`,
+///  * `other` - extensions with other singletons, such as `-a-foo`, keyed by the singleton,
+///  * `private` - the `-x-` private use subtags, such as `-x-testing`.
+///
+/// `Extensions` can be parsed from a string, or assembled with `Extensions::builder`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Extensions;
+///
+/// let extensions = Extensions::builder()
+///     .unicode_keyword("ca", &["buddhist"])
+///     .private_tag("testing")
+///     .build()
+///     .expect("Building failed.");
+///
+/// assert_eq!(extensions.to_string(), "-u-ca-buddhist-x-testing");
+///
+/// let parsed: Extensions = "u-ca-buddhist-x-testing".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(extensions, parsed);
+/// ```
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
-pub struct ExtensionsMap {
+pub struct Extensions {
     pub unicode: UnicodeExtensionList,
     pub transform: TransformExtensionList,
     pub other: BTreeMap<char, Vec<TinyStr8>>,
     pub private: PrivateExtensionList,
 }
 
-impl ExtensionsMap {
+/// Alias of `Extensions` kept for compatibility.
+#[deprecated(note = "Use `Extensions` instead.")]
+pub type ExtensionsMap = Extensions;
+
+/// Raw internal representation of a `-u-` keyword or a `-t-` field:
+/// the key followed by its values.
+pub type RawKeyword<'a> = (u32, &'a [u64]);
+
+/// Raw internal representation of a `LanguageIdentifier`:
+/// the language, script and region, followed by the variants.
+pub type RawLanguageIdentifier<'a> = (Option<u64>, Option<u32>, Option<u32>, &'a [u64]);
+
+pub(crate) type RawKeywordBuf = (u32, Vec<u64>);
+pub(crate) type RawLanguageIdentifierBuf = (Option<u64>, Option<u32>, Option<u32>, Vec<u64>);
+
+/// `Extensions` decomposed into raw internal representations of their subtags.
+///
+/// Unlike `Extensions`, `RawExtensions` consists of primitive arrays only,
+/// so it can be constructed in a `const` context and embedded in generated code.
+///
+/// It is produced by `Locale::with_raw_parts_v2` and consumed by
+/// `Locale::from_raw_parts_v2_unchecked`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RawExtensions<'a> {
+    pub unicode_attributes: &'a [u64],
+    pub unicode_keywords: &'a [RawKeyword<'a>],
+    pub transform_language: Option<RawLanguageIdentifier<'a>>,
+    pub transform_fields: &'a [RawKeyword<'a>],
+    pub other: &'a [(char, &'a [u64])],
+    pub private: &'a [u64],
+}
+
+pub(crate) fn raw_subtags(subtags: &[TinyStr8]) -> Vec<u64> {
+    subtags.iter().map(|subtag| (*subtag).into()).collect()
+}
+
+pub(crate) unsafe fn subtags_from_raw(raw: &[u64]) -> Vec<TinyStr8> {
+    raw.iter()
+        .map(|subtag| TinyStr8::new_unchecked(*subtag))
+        .collect()
+}
+
+pub(crate) fn raw_langid(langid: &LanguageIdentifier) -> RawLanguageIdentifierBuf {
+    let (language, script, region, variants) = langid.clone().into_raw_parts();
+    let variants = variants.map_or_else(Vec::new, |variants| variants.into_vec());
+    (language, script, region, variants)
+}
+
+pub(crate) unsafe fn langid_from_raw(raw: RawLanguageIdentifier) -> LanguageIdentifier {
+    let (language, script, region, variants) = raw;
+    let variants = if variants.is_empty() {
+        None
+    } else {
+        Some(subtags_from_raw(variants).into_boxed_slice())
+    };
+    LanguageIdentifier::from_raw_parts_unchecked(
+        language.map(|language| TinyStr8::new_unchecked(language)),
+        script.map(|script| TinyStr4::new_unchecked(script)),
+        region.map(|region| TinyStr4::new_unchecked(region)),
+        variants,
+    )
+}
+
+fn as_raw_keywords(keywords: &[RawKeywordBuf]) -> Vec<RawKeyword<'_>> {
+    keywords
+        .iter()
+        .map(|(key, values)| (*key, values.as_slice()))
+        .collect()
+}
+
+fn parse_other_subtag(t: &str) -> Result<TinyStr8, ParserError> {
+    let s: TinyStr8 = t.parse().map_err(|_| ParserError::InvalidSubtag)?;
+    if t.len() < 2 || !s.is_ascii_alphanumeric() {
+        return Err(ParserError::InvalidSubtag);
+    }
+    Ok(s.to_ascii_lowercase())
+}
+
+fn parse_other_singleton(singleton: char) -> Result<char, ParserError> {
+    match ExtensionType::from_char(singleton)? {
+        ExtensionType::Other(singleton) => Ok(singleton),
+        _ => Err(ParserError::InvalidExtension),
+    }
+}
+
+impl Extensions {
     pub fn try_from_iter<'a>(
         iter: &mut Peekable<impl Iterator<Item = &'a str>>,
     ) -> Result<Self, ParserError> {
-        let mut result = ExtensionsMap::default();
+        let mut result = Extensions::default();
+        let mut singletons = vec![];
 
         let mut st = iter.next();
         while let Some(subtag) = st {
             let subtag = subtag.to_ascii_lowercase();
 
+            // Each singleton may only appear once, so it is ambiguous which
+            // extension a repeated one should contribute to.
+            if subtag.len() == 1 {
+                let singleton = subtag.as_bytes()[0] as char;
+                if singletons.contains(&singleton) {
+                    return Err(ParserError::DuplicateSingleton(singleton));
+                }
+                singletons.push(singleton);
+            }
+
             match subtag.as_str() {
                 "" => break,
                 "u" => {
@@ -78,7 +238,22 @@ impl ExtensionsMap {
                 "x" => {
                     result.private = PrivateExtensionList::try_from_iter(iter)?;
                 }
-                _ => unimplemented!(),
+                singleton if singleton.len() == 1 => {
+                    let singleton = parse_other_singleton(singleton.as_bytes()[0] as char)?;
+                    let mut subtags = vec![];
+                    while let Some(subtag) = iter.peek() {
+                        if subtag.len() == 1 {
+                            break;
+                        }
+                        subtags.push(parse_other_subtag(subtag)?);
+                        iter.next();
+                    }
+                    if subtags.is_empty() {
+                        return Err(ParserError::InvalidExtension);
+                    }
+                    result.other.insert(singleton, subtags);
+                }
+                _ => return Err(ParserError::InvalidExtension),
             }
 
             st = iter.next();
@@ -87,14 +262,214 @@ impl ExtensionsMap {
         Ok(result)
     }
 
+    /// Returns a builder for assembling `Extensions`.
+    pub fn builder() -> ExtensionsBuilder {
+        ExtensionsBuilder::default()
+    }
+
+    /// Returns an iterator over all extensions as `(type, key, values)` tuples.
+    ///
+    /// Extensions are ordered by their singletons, with the private use
+    /// extension last. Unicode attributes, the source language of the transform
+    /// extension, other extensions and private use tags have an empty key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{ExtensionType, Extensions};
+    ///
+    /// let extensions: Extensions = "u-ca-buddhist-t-es-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let items: Vec<_> = extensions.iter().collect();
+    /// assert_eq!(items, vec![
+    ///     (ExtensionType::Transform, "", vec!["es"]),
+    ///     (ExtensionType::Unicode, "ca", vec!["buddhist"]),
+    ///     (ExtensionType::Private, "", vec!["foo"]),
+    /// ]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (ExtensionType, &str, Vec<&str>)> {
+        let mut result: Vec<(ExtensionType, &str, Vec<&str>)> = vec![];
+        for (key, values) in self.transform.items() {
+            result.push((ExtensionType::Transform, key, values));
+        }
+        for (key, values) in self.unicode.items() {
+            result.push((ExtensionType::Unicode, key, values));
+        }
+        for (singleton, subtags) in &self.other {
+            let subtags = subtags.iter().map(|s| s.as_str()).collect();
+            result.push((ExtensionType::Other(*singleton), "", subtags));
+        }
+        if !self.private.is_empty() {
+            result.push((ExtensionType::Private, "", self.private.tags()));
+        }
+        // The sort is stable, so the order within each extension is kept.
+        result.sort_by_key(|(ext_type, _, _)| {
+            (*ext_type == ExtensionType::Private, ext_type.as_char())
+        });
+        result.into_iter()
+    }
+
+    /// Sets the subtags of an extension with a singleton other than `u`, `t` and `x`.
+    pub fn set_other(&mut self, singleton: char, subtags: &[&str]) -> Result<(), LocaleError> {
+        let singleton = parse_other_singleton(singleton)?;
+        if subtags.is_empty() {
+            return Err(ParserError::InvalidExtension.into());
+        }
+        let subtags = subtags
+            .iter()
+            .map(|subtag| parse_other_subtag(subtag))
+            .collect::<Result<_, _>>()?;
+        self.other.insert(singleton, subtags);
+        Ok(())
+    }
+
+    /// Merges other extensions into these ones.
+    ///
+    /// Keywords, fields, attributes and tags missing from `self` are copied from `other`.
+    /// In case of a conflict, values of `self` are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Extensions;
+    ///
+    /// let mut extensions: Extensions = "u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    /// let other: Extensions = "u-ca-buddhist-hc-h23-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// extensions.merge(&other);
+    /// assert_eq!(extensions.to_string(), "-u-ca-buddhist-hc-h12-x-foo");
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        self.unicode.merge(&other.unicode);
+        self.transform.merge(&other.transform);
+        for (key, value) in &other.other {
+            self.other.entry(*key).or_insert_with(|| value.clone());
+        }
+        self.private.merge(&other.private);
+    }
+
+    /// Removes the extension of the given type.
+    ///
+    /// Returns `true` if the extension was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{ExtensionType, Extensions};
+    ///
+    /// let mut extensions: Extensions = "u-ca-buddhist-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(extensions.remove(ExtensionType::Private));
+    /// assert!(!extensions.remove(ExtensionType::Transform));
+    /// assert_eq!(extensions.to_string(), "-u-ca-buddhist");
+    /// ```
+    pub fn remove(&mut self, ext_type: ExtensionType) -> bool {
+        match ext_type {
+            ExtensionType::Unicode => !std::mem::take(&mut self.unicode).is_empty(),
+            ExtensionType::Transform => !std::mem::take(&mut self.transform).is_empty(),
+            ExtensionType::Private => !std::mem::take(&mut self.private).is_empty(),
+            ExtensionType::Other(singleton) => {
+                self.other.remove(&singleton.to_ascii_lowercase()).is_some()
+            }
+        }
+    }
+
+    /// Removes all extensions.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns `true` if there are no extensions.
     pub fn is_empty(&self) -> bool {
-        self.unicode.is_empty() && self.transform.is_empty() && self.private.is_empty()
+        self.unicode.is_empty()
+            && self.transform.is_empty()
+            && self.other.is_empty()
+            && self.private.is_empty()
+    }
+
+    /// Calls `f` with the raw representation of the extensions.
+    pub(crate) fn with_raw<R>(&self, f: impl FnOnce(RawExtensions) -> R) -> R {
+        let (unicode_attributes, unicode_keywords) = self.unicode.to_raw();
+        let (transform_language, transform_fields) = self.transform.to_raw();
+        let other: Vec<(char, Vec<u64>)> = self
+            .other
+            .iter()
+            .map(|(singleton, subtags)| (*singleton, raw_subtags(subtags)))
+            .collect();
+        let private = self.private.to_raw();
+
+        let unicode_keywords = as_raw_keywords(&unicode_keywords);
+        let transform_fields = as_raw_keywords(&transform_fields);
+        let other: Vec<(char, &[u64])> = other
+            .iter()
+            .map(|(singleton, subtags)| (*singleton, subtags.as_slice()))
+            .collect();
+
+        f(RawExtensions {
+            unicode_attributes: &unicode_attributes,
+            unicode_keywords: &unicode_keywords,
+            transform_language: transform_language.as_ref().map(
+                |(language, script, region, variants)| {
+                    (*language, *script, *region, variants.as_slice())
+                },
+            ),
+            transform_fields: &transform_fields,
+            other: &other,
+            private: &private,
+        })
+    }
+
+    /// Restores the extensions from their raw representation without any checks.
+    pub(crate) unsafe fn from_raw_unchecked(raw: &RawExtensions) -> Self {
+        Self {
+            unicode: UnicodeExtensionList::from_raw_unchecked(
+                raw.unicode_attributes,
+                raw.unicode_keywords,
+            ),
+            transform: TransformExtensionList::from_raw_unchecked(
+                raw.transform_language,
+                raw.transform_fields,
+            ),
+            other: raw
+                .other
+                .iter()
+                .map(|(singleton, subtags)| (*singleton, subtags_from_raw(subtags)))
+                .collect(),
+            private: PrivateExtensionList::from_raw_unchecked(raw.private),
+        }
+    }
+
+    /// Returns the length in bytes of the serialized extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Extensions;
+    ///
+    /// let extensions: Extensions = "u-ca-buddhist-x-testing".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(extensions.byte_len(), extensions.to_string().len());
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.unicode.byte_len()
+            + self.transform.byte_len()
+            + self
+                .other
+                .values()
+                .map(|subtags| 2 + subtags.iter().map(|s| s.len() + 1).sum::<usize>())
+                .sum::<usize>()
+            + self.private.byte_len()
     }
 }
 
 static SEPARATORS: &[char] = &['-', '_'];
 
-impl FromStr for ExtensionsMap {
+impl FromStr for Extensions {
     type Err = ParserError;
 
     fn from_str(source: &str) -> Result<Self, Self::Err> {
@@ -103,10 +478,68 @@ impl FromStr for ExtensionsMap {
     }
 }
 
-impl std::fmt::Display for ExtensionsMap {
+impl std::fmt::Display for Extensions {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}{}", self.unicode, self.transform, self.private)?;
+        write!(f, "{}{}", self.unicode, self.transform)?;
+        for (singleton, subtags) in &self.other {
+            write!(f, "-{}", singleton)?;
+            for subtag in subtags {
+                write!(f, "-{}", subtag)?;
+            }
+        }
+        write!(f, "{}", self.private)?;
 
         Ok(())
     }
 }
+
+/// A builder for `Extensions`.
+///
+/// The first error encountered is reported by `build`.
+#[derive(Debug, Default)]
+pub struct ExtensionsBuilder {
+    extensions: Extensions,
+    error: Option<LocaleError>,
+}
+
+impl ExtensionsBuilder {
+    fn apply(mut self, f: impl FnOnce(&mut Extensions) -> Result<(), LocaleError>) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = f(&mut self.extensions) {
+                self.error = Some(err);
+            }
+        }
+        self
+    }
+
+    pub fn unicode_keyword(self, key: &str, value: &[&str]) -> Self {
+        self.apply(|ext| ext.unicode.set_keyword(key, value.to_vec()))
+    }
+
+    pub fn unicode_attribute(self, attribute: &str) -> Self {
+        self.apply(|ext| ext.unicode.set_attribute(attribute))
+    }
+
+    pub fn transform_lang(self, tlang: LanguageIdentifier) -> Self {
+        self.apply(|ext| ext.transform.set_tlang(tlang))
+    }
+
+    pub fn transform_field(self, tkey: &str, tvalue: &[&str]) -> Self {
+        self.apply(|ext| ext.transform.set_tfield(tkey, tvalue.to_vec()))
+    }
+
+    pub fn other(self, singleton: char, subtags: &[&str]) -> Self {
+        self.apply(|ext| ext.set_other(singleton, subtags))
+    }
+
+    pub fn private_tag(self, tag: &str) -> Self {
+        self.apply(|ext| ext.private.add_tag(tag))
+    }
+
+    pub fn build(self) -> Result<Extensions, LocaleError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.extensions),
+        }
+    }
+}
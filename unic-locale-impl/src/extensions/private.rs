@@ -1,3 +1,4 @@
+use super::{raw_subtags, subtags_from_raw};
 use crate::errors::LocaleError;
 use crate::parser::ParserError;
 
@@ -20,12 +21,46 @@ impl PrivateExtensionList {
         self.0.is_empty()
     }
 
+    pub(crate) fn to_raw(&self) -> Vec<u64> {
+        raw_subtags(&self.0)
+    }
+
+    pub(crate) unsafe fn from_raw_unchecked(subtags: &[u64]) -> Self {
+        Self(subtags_from_raw(subtags))
+    }
+
+    /// Returns the length in bytes of the serialized list, including the `-x` singleton.
+    pub(crate) fn byte_len(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        2 + self.0.iter().map(|subtag| subtag.len() + 1).sum::<usize>()
+    }
+
+    pub(crate) fn tags(&self) -> Vec<&str> {
+        self.0.iter().map(|t| t.as_str()).collect()
+    }
+
+    /// Returns `true` if the list contains the tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.0.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
     pub fn add_tag(&mut self, tag: &str) -> Result<(), LocaleError> {
         self.0.push(parse_value(tag)?);
         self.0.sort();
         Ok(())
     }
 
+    pub(crate) fn merge(&mut self, other: &Self) {
+        for tag in &other.0 {
+            if !self.0.contains(tag) {
+                self.0.push(*tag);
+            }
+        }
+        self.0.sort();
+    }
+
     pub fn try_from_iter<'a>(
         iter: &mut impl Iterator<Item = &'a str>,
     ) -> Result<Self, ParserError> {
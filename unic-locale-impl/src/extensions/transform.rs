@@ -1,3 +1,7 @@
+use super::{
+    langid_from_raw, raw_langid, raw_subtags, subtags_from_raw, RawKeyword, RawKeywordBuf,
+    RawLanguageIdentifier, RawLanguageIdentifierBuf,
+};
 use crate::errors::LocaleError;
 use crate::parser::ParserError;
 
@@ -5,6 +9,7 @@ use unic_langid_impl::LanguageIdentifier;
 
 use std::collections::BTreeMap;
 use std::iter::Peekable;
+use std::str::FromStr;
 
 use tinystr::{TinyStr4, TinyStr8};
 
@@ -14,6 +19,12 @@ pub struct TransformExtensionList {
     tfields: BTreeMap<TinyStr4, Vec<TinyStr8>>,
 }
 
+/// Field keys of the `-t-` extension, based on CLDR `bcp47/transform.xml`.
+const TKEYS: &[&str] = &["d0", "h0", "i0", "k0", "m0", "s0", "t0", "x0"];
+
+const MECHANISM: &str = "m0";
+const HYBRID: &str = "h0";
+
 fn parse_tkey(key: &str) -> Result<TinyStr4, ParserError> {
     if key.len() != 2
         || !key.as_bytes()[0].is_ascii_alphabetic()
@@ -22,7 +33,11 @@ fn parse_tkey(key: &str) -> Result<TinyStr4, ParserError> {
         return Err(ParserError::InvalidSubtag);
     }
     let tkey: TinyStr4 = key.parse().map_err(|_| ParserError::InvalidSubtag)?;
-    Ok(tkey.to_ascii_lowercase())
+    let tkey = tkey.to_ascii_lowercase();
+    if !TKEYS.contains(&tkey.as_str()) {
+        return Err(ParserError::InvalidSubtag);
+    }
+    Ok(tkey)
 }
 
 fn parse_tvalue(t: &str) -> Result<TinyStr8, ParserError> {
@@ -34,6 +49,28 @@ fn parse_tvalue(t: &str) -> Result<TinyStr8, ParserError> {
     Ok(s.to_ascii_lowercase())
 }
 
+/// Validates the values of a field against its key.
+///
+/// Every field needs at least one value. The first value of `m0` has to be
+/// a known `TransformMechanism`, and `h0` only accepts `hybrid`.
+fn validate_tfield(tkey: TinyStr4, tvalue: &[TinyStr8]) -> Result<(), ParserError> {
+    let first = tvalue.first().ok_or(ParserError::InvalidSubtag)?;
+    match tkey.as_str() {
+        MECHANISM => {
+            first.parse::<TransformMechanism>()?;
+        }
+        HYBRID if tvalue.len() != 1 || first.as_str() != "hybrid" => {
+            return Err(ParserError::InvalidSubtag);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn is_tkey(t: &str) -> bool {
+    t.len() == 2 && t.as_bytes()[0].is_ascii_alphabetic() && t.as_bytes()[1].is_ascii_digit()
+}
+
 fn is_language_subtag(t: &str) -> bool {
     let slen = t.len();
     (slen >= 2 && slen <= 8 || slen == 4) && !t.contains(|c: char| !c.is_ascii_alphabetic())
@@ -44,24 +81,123 @@ impl TransformExtensionList {
         self.tlang.is_none() && self.tfields.is_empty()
     }
 
+    pub(crate) fn to_raw(&self) -> (Option<RawLanguageIdentifierBuf>, Vec<RawKeywordBuf>) {
+        let tfields = self
+            .tfields
+            .iter()
+            .map(|(key, values)| ((*key).into(), raw_subtags(values)))
+            .collect();
+        (self.tlang.as_ref().map(raw_langid), tfields)
+    }
+
+    pub(crate) unsafe fn from_raw_unchecked(
+        tlang: Option<RawLanguageIdentifier>,
+        tfields: &[RawKeyword],
+    ) -> Self {
+        Self {
+            tlang: tlang.map(|tlang| langid_from_raw(tlang)),
+            tfields: tfields
+                .iter()
+                .map(|(key, values)| (TinyStr4::new_unchecked(*key), subtags_from_raw(values)))
+                .collect(),
+        }
+    }
+
+    /// Returns the length in bytes of the serialized list, including the `-t` singleton.
+    pub(crate) fn byte_len(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        2 + self.tlang.as_ref().map_or(0, |tlang| tlang.byte_len() + 1)
+            + self
+                .tfields
+                .iter()
+                .map(|(k, t)| k.len() + 1 + t.iter().map(|v| v.len() + 1).sum::<usize>())
+                .sum::<usize>()
+    }
+
+    /// Returns the subtags of the source language, with an empty key, followed by the fields.
+    pub(crate) fn items(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut result = vec![];
+        if let Some(tlang) = &self.tlang {
+            let mut subtags = vec![tlang.get_language()];
+            subtags.extend(tlang.get_script());
+            subtags.extend(tlang.get_region());
+            subtags.extend(tlang.get_variants());
+            result.push(("", subtags));
+        }
+        for (key, values) in &self.tfields {
+            result.push((key.as_str(), values.iter().map(|v| v.as_str()).collect()));
+        }
+        result
+    }
+
     pub fn set_tlang(&mut self, tlang: LanguageIdentifier) -> Result<(), LocaleError> {
         self.tlang = Some(tlang);
         Ok(())
     }
 
+    pub fn get_tfield(&self, tkey: &str) -> Option<Vec<&str>> {
+        let tkey = parse_tkey(tkey).ok()?;
+        self.tfields
+            .get(&tkey)
+            .map(|t| t.iter().map(|v| v.as_str()).collect())
+    }
+
+    /// Sets a field, such as `m0` in `-t-m0-ungegn`.
+    ///
+    /// Fails for keys other than `d0`, `h0`, `i0`, `k0`, `m0`, `s0`, `t0` and `x0`,
+    /// for an empty list of values, and for values not allowed for the key.
     pub fn set_tfield(&mut self, tkey: &str, tvalue: Vec<&str>) -> Result<(), LocaleError> {
         let tkey = parse_tkey(tkey)?;
         let mut t = Vec::with_capacity(tvalue.len());
         for val in tvalue {
             t.push(parse_tvalue(val)?);
         }
+        validate_tfield(tkey, &t)?;
 
         self.tfields.insert(tkey, t);
         Ok(())
     }
 
+    /// Returns the transform mechanism of the `m0` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::TransformMechanism;
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "und-Latn-t-und-cyrl-m0-ungegn-2007".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(
+    ///     loc.extensions.transform.get_mechanism(),
+    ///     Some(TransformMechanism::Ungegn)
+    /// );
+    /// assert!("und-t-m0-foobar".parse::<Locale>().is_err());
+    /// ```
+    pub fn get_mechanism(&self) -> Option<TransformMechanism> {
+        let tkey: TinyStr4 = MECHANISM.parse().ok()?;
+        self.tfields.get(&tkey)?.first()?.parse().ok()
+    }
+
+    /// Sets the `m0` field to the transform mechanism, replacing any previous values.
+    pub fn set_mechanism(&mut self, mechanism: TransformMechanism) -> Result<(), LocaleError> {
+        self.set_tfield(MECHANISM, vec![mechanism.as_str()])
+    }
+
+    pub(crate) fn merge(&mut self, other: &Self) {
+        if self.tlang.is_none() {
+            self.tlang = other.tlang.clone();
+        }
+        for (key, value) in &other.tfields {
+            self.tfields.entry(*key).or_insert_with(|| value.clone());
+        }
+    }
+
     pub fn try_from_iter<'a>(
-        mut iter: &mut Peekable<impl Iterator<Item = &'a str>>,
+        iter: &mut Peekable<impl Iterator<Item = &'a str>>,
     ) -> Result<Self, ParserError> {
         let mut text = Self::default();
 
@@ -73,26 +209,44 @@ impl TransformExtensionList {
         while let Some(subtag) = st_peek {
             let slen = subtag.len();
 
-            if slen == 2
-                && subtag.as_bytes()[0].is_ascii_alphabetic()
-                && subtag.as_bytes()[1].is_ascii_digit()
-            {
-                let tkey: TinyStr4 = subtag.parse().map_err(|_| ParserError::InvalidSubtag)?;
-                current_tkey = Some(tkey);
+            if is_tkey(subtag) {
+                if let Some(current_tkey) = current_tkey {
+                    validate_tfield(current_tkey, &current_tvalue)?;
+                    text.tfields.insert(current_tkey, current_tvalue);
+                    current_tvalue = vec![];
+                }
+                current_tkey = Some(parse_tkey(subtag)?);
                 iter.next();
-            } else if current_tkey.is_some() {
+            } else if current_tkey.is_some() && slen != 1 {
                 current_tvalue.push(parse_tvalue(subtag)?);
-            } else if is_language_subtag(subtag) {
+                iter.next();
+            } else if current_tkey.is_none() && text.tlang.is_none() && is_language_subtag(subtag) {
+                // The source language ends at the first field or extension.
+                let mut subtags = vec![];
+                while let Some(subtag) = iter.peek() {
+                    if subtag.len() == 1 || is_tkey(subtag) {
+                        break;
+                    }
+                    subtags.push(*subtag);
+                    iter.next();
+                }
                 text.tlang = Some(
-                    LanguageIdentifier::try_from_iter(&mut iter, true)
+                    LanguageIdentifier::try_from_iter(&mut subtags.into_iter().peekable(), false)
                         .map_err(|_| ParserError::InvalidLanguage)?,
                 );
-            } else {
+            } else if slen <= 1 {
                 break;
+            } else {
+                return Err(ParserError::InvalidSubtag);
             }
             st_peek = iter.peek();
         }
 
+        if let Some(current_tkey) = current_tkey {
+            validate_tfield(current_tkey, &current_tvalue)?;
+            text.tfields.insert(current_tkey, current_tvalue);
+        }
+
         Ok(text)
     }
 }
@@ -118,3 +272,122 @@ impl std::fmt::Display for TransformExtensionList {
         Ok(())
     }
 }
+
+/// Transform mechanism of the `m0` field, based on CLDR `bcp47/transform.xml`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::TransformMechanism;
+///
+/// let mechanism: TransformMechanism = "BGN".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(mechanism, TransformMechanism::Bgn);
+/// assert_eq!(mechanism.to_string(), "bgn");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum TransformMechanism {
+    /// American Library Association-Library of Congress.
+    Alaloc,
+    /// US Board on Geographic Names.
+    Bgn,
+    /// Buckwalter Arabic transliteration.
+    Buckwalt,
+    /// C11 escaping.
+    C11,
+    /// CSS escaping.
+    Css,
+    /// Deutsches Institut für Normung.
+    Din,
+    /// Russian standard GOST.
+    Gost,
+    /// International Organization for Standardization.
+    Iso,
+    /// Java escaping.
+    Java,
+    /// Myanmar Language Commission.
+    Mcst,
+    /// Mongolian National Standard.
+    Mns,
+    /// Transliteration of personal names.
+    Names,
+    /// Percent escaping.
+    Percent,
+    /// Plus escaping.
+    Plus,
+    /// Transliteration of proper names.
+    Prprname,
+    /// Satts Thai transliteration.
+    Satts,
+    /// United Nations Group of Experts on Geographical Names.
+    Ungegn,
+    /// Unicode escaping.
+    Unicode,
+    /// XML escaping.
+    Xml,
+    /// XML 1.0 escaping.
+    Xml10,
+}
+
+impl TransformMechanism {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransformMechanism::Alaloc => "alaloc",
+            TransformMechanism::Bgn => "bgn",
+            TransformMechanism::Buckwalt => "buckwalt",
+            TransformMechanism::C11 => "c11",
+            TransformMechanism::Css => "css",
+            TransformMechanism::Din => "din",
+            TransformMechanism::Gost => "gost",
+            TransformMechanism::Iso => "iso",
+            TransformMechanism::Java => "java",
+            TransformMechanism::Mcst => "mcst",
+            TransformMechanism::Mns => "mns",
+            TransformMechanism::Names => "names",
+            TransformMechanism::Percent => "percent",
+            TransformMechanism::Plus => "plus",
+            TransformMechanism::Prprname => "prprname",
+            TransformMechanism::Satts => "satts",
+            TransformMechanism::Ungegn => "ungegn",
+            TransformMechanism::Unicode => "unicode",
+            TransformMechanism::Xml => "xml",
+            TransformMechanism::Xml10 => "xml10",
+        }
+    }
+}
+
+impl FromStr for TransformMechanism {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source.to_ascii_lowercase().as_str() {
+            "alaloc" => Ok(TransformMechanism::Alaloc),
+            "bgn" => Ok(TransformMechanism::Bgn),
+            "buckwalt" => Ok(TransformMechanism::Buckwalt),
+            "c11" => Ok(TransformMechanism::C11),
+            "css" => Ok(TransformMechanism::Css),
+            "din" => Ok(TransformMechanism::Din),
+            "gost" => Ok(TransformMechanism::Gost),
+            "iso" => Ok(TransformMechanism::Iso),
+            "java" => Ok(TransformMechanism::Java),
+            "mcst" => Ok(TransformMechanism::Mcst),
+            "mns" => Ok(TransformMechanism::Mns),
+            "names" => Ok(TransformMechanism::Names),
+            "percent" => Ok(TransformMechanism::Percent),
+            "plus" => Ok(TransformMechanism::Plus),
+            "prprname" => Ok(TransformMechanism::Prprname),
+            "satts" => Ok(TransformMechanism::Satts),
+            "ungegn" => Ok(TransformMechanism::Ungegn),
+            "unicode" => Ok(TransformMechanism::Unicode),
+            "xml" => Ok(TransformMechanism::Xml),
+            "xml10" => Ok(TransformMechanism::Xml10),
+            _ => Err(ParserError::InvalidSubtag),
+        }
+    }
+}
+
+impl std::fmt::Display for TransformMechanism {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
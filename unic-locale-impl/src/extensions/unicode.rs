@@ -1,10 +1,16 @@
+use super::{raw_subtags, subtags_from_raw, RawKeyword, RawKeywordBuf};
 use crate::errors::LocaleError;
 use crate::parser::ParserError;
 
 use std::collections::BTreeMap;
 use std::iter::Peekable;
+use std::str::FromStr;
 
 use tinystr::{TinyStr4, TinyStr8};
+use unic_langid_impl::Region;
+
+#[cfg(feature = "subdivision-data")]
+const SUBDIVISION: &str = "sd";
 
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub struct UnicodeExtensionList {
@@ -41,6 +47,36 @@ fn parse_attribute(t: &str) -> Result<TinyStr8, ParserError> {
     Ok(s.to_ascii_lowercase())
 }
 
+/// Checks the values of keywords with a restricted syntax.
+///
+/// Subdivisions are only validated with `feature = "subdivision-data"`.
+#[cfg(feature = "subdivision-data")]
+fn validate_keyword(key: TinyStr4, types: &[TinyStr8]) -> Result<(), ParserError> {
+    if key == SUBDIVISION {
+        match types {
+            [sd] => {
+                sd.parse::<Subdivision>()?;
+            }
+            _ => return Err(ParserError::InvalidSubtag),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "subdivision-data"))]
+fn validate_keyword(_key: TinyStr4, _types: &[TinyStr8]) -> Result<(), ParserError> {
+    Ok(())
+}
+
+/// Drops the `true` type, so that boolean keywords such as `-u-kn-true`
+/// are stored in their canonical form `-u-kn`.
+fn canonicalize_types(mut types: Vec<TinyStr8>) -> Vec<TinyStr8> {
+    if types.len() == 1 && types[0] == "true" {
+        types.clear();
+    }
+    types
+}
+
 fn is_attribute(t: &str) -> bool {
     let slen = t.len();
     (slen >= 3 && slen <= 8) && !t.contains(|c: char| !c.is_ascii_alphanumeric())
@@ -56,6 +92,98 @@ impl UnicodeExtensionList {
         self.keywords.is_empty() && self.attributes.is_empty()
     }
 
+    pub(crate) fn to_raw(&self) -> (Vec<u64>, Vec<RawKeywordBuf>) {
+        let keywords = self
+            .keywords
+            .iter()
+            .map(|(key, values)| ((*key).into(), raw_subtags(values)))
+            .collect();
+        (raw_subtags(&self.attributes), keywords)
+    }
+
+    pub(crate) unsafe fn from_raw_unchecked(attributes: &[u64], keywords: &[RawKeyword]) -> Self {
+        Self {
+            keywords: keywords
+                .iter()
+                .map(|(key, values)| (TinyStr4::new_unchecked(*key), subtags_from_raw(values)))
+                .collect(),
+            attributes: subtags_from_raw(attributes),
+        }
+    }
+
+    /// Returns the length in bytes of the serialized list, including the `-u` singleton.
+    pub(crate) fn byte_len(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        2 + self
+            .keywords
+            .iter()
+            .map(|(k, t)| k.len() + 1 + t.iter().map(|v| v.len() + 1).sum::<usize>())
+            .sum::<usize>()
+            + self
+                .attributes
+                .iter()
+                .map(|attr| attr.len() + 1)
+                .sum::<usize>()
+    }
+
+    /// Returns the attributes, with an empty key, followed by the keywords.
+    pub(crate) fn items(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut result = vec![];
+        if !self.attributes.is_empty() {
+            result.push(("", self.attributes.iter().map(|a| a.as_str()).collect()));
+        }
+        for (key, types) in &self.keywords {
+            result.push((key.as_str(), types.iter().map(|t| t.as_str()).collect()));
+        }
+        result
+    }
+
+    /// Returns the keywords with their types, sorted by key.
+    ///
+    /// The order does not depend on the order in which the keywords were
+    /// parsed or set, so it is stable for serialization and hashing.
+    pub fn keywords(&self) -> impl Iterator<Item = (&str, Vec<&str>)> {
+        self.keywords
+            .iter()
+            .map(|(key, types)| (key.as_str(), types.iter().map(|t| t.as_str()).collect()))
+    }
+
+    pub fn get_keyword(&self, key: &str) -> Option<Vec<&str>> {
+        let key = parse_key(key).ok()?;
+        self.keywords
+            .get(&key)
+            .map(|t| t.iter().map(|v| v.as_str()).collect())
+    }
+
+    /// Returns `true` if the list contains the keyword.
+    pub fn has_keyword(&self, key: &str) -> bool {
+        match parse_key(key) {
+            Ok(key) => self.keywords.contains_key(&key),
+            Err(_) => false,
+        }
+    }
+
+    pub fn remove_keyword(&mut self, key: &str) -> bool {
+        match parse_key(key) {
+            Ok(key) => self.keywords.remove(&key).is_some(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the list contains a boolean keyword, which is either
+    /// given without a type, such as `-u-kn`, or with the `true` type.
+    pub fn get_keyword_flag(&self, key: &str) -> bool {
+        match parse_key(key) {
+            Ok(key) => matches!(self.keywords.get(&key), Some(t) if t.is_empty()),
+            Err(_) => false,
+        }
+    }
+
+    /// Sets the keyword to the given types.
+    ///
+    /// An empty list of types, or the `true` type, sets a boolean keyword.
     pub fn set_keyword(&mut self, key: &str, value: Vec<&str>) -> Result<(), LocaleError> {
         let key = parse_key(key)?;
 
@@ -63,8 +191,13 @@ impl UnicodeExtensionList {
         for val in value {
             t.push(parse_type(val)?);
         }
+        self.insert_keyword(key, t)?;
+        Ok(())
+    }
 
-        self.keywords.insert(key, t);
+    fn insert_keyword(&mut self, key: TinyStr4, types: Vec<TinyStr8>) -> Result<(), ParserError> {
+        validate_keyword(key, &types)?;
+        self.keywords.insert(key, canonicalize_types(types));
         Ok(())
     }
 
@@ -73,6 +206,17 @@ impl UnicodeExtensionList {
         Ok(())
     }
 
+    pub(crate) fn merge(&mut self, other: &Self) {
+        for (key, value) in &other.keywords {
+            self.keywords.entry(*key).or_insert_with(|| value.clone());
+        }
+        for attr in &other.attributes {
+            if !self.attributes.contains(attr) {
+                self.attributes.push(*attr);
+            }
+        }
+    }
+
     pub fn try_from_iter<'a>(
         iter: &mut Peekable<impl Iterator<Item = &'a str>>,
     ) -> Result<Self, ParserError> {
@@ -87,7 +231,7 @@ impl UnicodeExtensionList {
             let slen = subtag.len();
             if slen == 2 {
                 if let Some(current_keyword) = current_keyword {
-                    uext.keywords.insert(current_keyword, current_types);
+                    uext.insert_keyword(current_keyword, current_types)?;
                     current_types = vec![];
                 }
                 current_keyword = Some(parse_key(subtag)?);
@@ -98,14 +242,17 @@ impl UnicodeExtensionList {
             } else if is_attribute(subtag) {
                 uext.attributes.push(parse_attribute(subtag)?);
                 iter.next();
-            } else {
+            } else if slen <= 1 {
                 break;
+            } else {
+                // Over-long or non-ASCII subtags are not a new extension.
+                return Err(ParserError::InvalidSubtag);
             }
             st_peek = iter.peek();
         }
 
         if let Some(current_keyword) = current_keyword {
-            uext.keywords.insert(current_keyword, current_types);
+            uext.insert_keyword(current_keyword, current_types)?;
         }
 
         Ok(uext)
@@ -133,3 +280,197 @@ impl std::fmt::Display for UnicodeExtensionList {
         Ok(())
     }
 }
+
+/// Collation type of the `-u-co-` keyword, based on CLDR `bcp47/collation.xml`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::CollationType;
+///
+/// let collation: CollationType = "phonebk".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(collation, CollationType::Phonebk);
+/// assert_eq!(collation.to_string(), "phonebk");
+///
+/// assert!("phonebook".parse::<CollationType>().is_err());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum CollationType {
+    /// Pinyin ordering for Latin, big5 charset ordering for CJK characters.
+    Big5han,
+    /// A previous version of the ordering, for compatibility.
+    Compat,
+    /// Dictionary style ordering, such as in Sinhala.
+    Dict,
+    /// The default Unicode collation element table order.
+    Ducet,
+    /// Recommended ordering for emoji characters.
+    Emoji,
+    /// European ordering rules.
+    Eor,
+    /// Pinyin ordering for Latin, gb2312han charset ordering for CJK characters.
+    Gb2312,
+    /// Phonebook style ordering, such as in German.
+    Phonebk,
+    /// Phonetic ordering, sorting based on pronunciation.
+    Phonetic,
+    /// Pinyin ordering for Latin and for CJK characters.
+    Pinyin,
+    /// Special collation type for string search.
+    Search,
+    /// Special collation type for Korean initial consonant search.
+    Searchjl,
+    /// Default ordering for each language.
+    Standard,
+    /// Pinyin ordering for Latin, stroke order for CJK characters.
+    Stroke,
+    /// Traditional style ordering, such as in Spanish.
+    Trad,
+    /// Pinyin ordering for Latin, Unihan radical-stroke ordering for CJK characters.
+    Unihan,
+    /// Pinyin ordering for Latin, zhuyin order for Bopomofo and CJK characters.
+    Zhuyin,
+}
+
+impl CollationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CollationType::Big5han => "big5han",
+            CollationType::Compat => "compat",
+            CollationType::Dict => "dict",
+            CollationType::Ducet => "ducet",
+            CollationType::Emoji => "emoji",
+            CollationType::Eor => "eor",
+            CollationType::Gb2312 => "gb2312",
+            CollationType::Phonebk => "phonebk",
+            CollationType::Phonetic => "phonetic",
+            CollationType::Pinyin => "pinyin",
+            CollationType::Search => "search",
+            CollationType::Searchjl => "searchjl",
+            CollationType::Standard => "standard",
+            CollationType::Stroke => "stroke",
+            CollationType::Trad => "trad",
+            CollationType::Unihan => "unihan",
+            CollationType::Zhuyin => "zhuyin",
+        }
+    }
+}
+
+impl FromStr for CollationType {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source.to_ascii_lowercase().as_str() {
+            "big5han" => Ok(CollationType::Big5han),
+            "compat" => Ok(CollationType::Compat),
+            "dict" => Ok(CollationType::Dict),
+            "ducet" => Ok(CollationType::Ducet),
+            "emoji" => Ok(CollationType::Emoji),
+            "eor" => Ok(CollationType::Eor),
+            "gb2312" => Ok(CollationType::Gb2312),
+            "phonebk" => Ok(CollationType::Phonebk),
+            "phonetic" => Ok(CollationType::Phonetic),
+            "pinyin" => Ok(CollationType::Pinyin),
+            "search" => Ok(CollationType::Search),
+            "searchjl" => Ok(CollationType::Searchjl),
+            "standard" => Ok(CollationType::Standard),
+            "stroke" => Ok(CollationType::Stroke),
+            "trad" => Ok(CollationType::Trad),
+            "unihan" => Ok(CollationType::Unihan),
+            "zhuyin" => Ok(CollationType::Zhuyin),
+            _ => Err(ParserError::InvalidSubtag),
+        }
+    }
+}
+
+impl std::fmt::Display for CollationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Subdivision of the `-u-sd-` keyword, such as `usca` or `gbsct`.
+///
+/// A subdivision consists of an ISO 3166-1 alpha-2 region code followed by
+/// a suffix of one to four alphanumeric characters identifying the subdivision
+/// within that region, as defined in CLDR `subdivisions.xml`.
+///
+/// The region has to be a known ISO 3166-1 code. With `feature = "subdivision-data"`
+/// the subdivision has to be listed in CLDR `validity/subdivision.xml`, otherwise
+/// the suffix is only checked for its syntax.
+///
+/// Parsing a `Locale` only validates the `-u-sd-` keyword with
+/// `feature = "subdivision-data"`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::Subdivision;
+///
+/// let sd: Subdivision = "gbsct".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(sd.get_region(), "GB");
+/// assert_eq!(sd.get_suffix(), "sct");
+/// assert_eq!(sd.to_string(), "gbsct");
+///
+/// assert!("zzsct".parse::<Subdivision>().is_err());
+/// assert!("usca-1".parse::<Subdivision>().is_err());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct Subdivision {
+    region: TinyStr4,
+    suffix: TinyStr4,
+}
+
+impl Subdivision {
+    /// Returns the region of the subdivision, such as `US`.
+    pub fn get_region(&self) -> &str {
+        self.region.as_str()
+    }
+
+    /// Returns the suffix identifying the subdivision within its region, such as `ca`.
+    pub fn get_suffix(&self) -> &str {
+        self.suffix.as_str()
+    }
+}
+
+impl FromStr for Subdivision {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        if source.len() < 3
+            || source.len() > 6
+            || !source.bytes().all(|b| b.is_ascii_alphanumeric())
+            || !source.as_bytes()[..2].iter().all(u8::is_ascii_alphabetic)
+        {
+            return Err(ParserError::InvalidSubtag);
+        }
+        let region: Region = source[..2]
+            .parse()
+            .map_err(|_| ParserError::InvalidSubtag)?;
+        if region.to_alpha3().is_none() {
+            return Err(ParserError::InvalidSubtag);
+        }
+        let suffix: TinyStr4 = source[2..]
+            .parse()
+            .map_err(|_| ParserError::InvalidSubtag)?;
+        let subdivision = Self {
+            region: region.into(),
+            suffix: suffix.to_ascii_lowercase(),
+        };
+        #[cfg(feature = "subdivision-data")]
+        {
+            if !crate::data::subdivision::is_known_subdivision(&subdivision.to_string()) {
+                return Err(ParserError::InvalidSubtag);
+            }
+        }
+        Ok(subdivision)
+    }
+}
+
+impl std::fmt::Display for Subdivision {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.region.to_ascii_lowercase(), self.suffix)
+    }
+}
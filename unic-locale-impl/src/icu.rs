@@ -0,0 +1,173 @@
+//! ICU style `@key=value;key=value` keywords, such as `en_US@calendar=buddhist`.
+//!
+//! Keyword names and their values are mapped onto `-u-` keys and types
+//! using the aliases of CLDR `bcp47/*.xml`.
+
+use crate::extensions::{ExtensionType, Extensions};
+use crate::parser::ParserError;
+use crate::Locale;
+
+/// ICU keyword names with their `-u-` keys.
+pub(crate) const KEYWORDS: &[(&str, &str)] = &[
+    ("calendar", "ca"),
+    ("colalternate", "ka"),
+    ("colbackwards", "kb"),
+    ("colcasefirst", "kf"),
+    ("colcaselevel", "kc"),
+    ("colhiraganaquaternary", "kh"),
+    ("collation", "co"),
+    ("colnormalization", "kk"),
+    ("colnumeric", "kn"),
+    ("colreorder", "kr"),
+    ("colstrength", "ks"),
+    ("currency", "cu"),
+    ("hours", "hc"),
+    ("measure", "ms"),
+    ("numbers", "nu"),
+    ("timezone", "tz"),
+    ("variabletop", "vt"),
+];
+
+/// ICU keyword values with the `-u-` types they map to, per key.
+pub(crate) const TYPES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+    ("ka", "non-ignorable", "noignore"),
+    ("kb", "no", "false"),
+    ("kb", "yes", "true"),
+    ("kc", "no", "false"),
+    ("kc", "yes", "true"),
+    ("kf", "no", "false"),
+    ("kh", "no", "false"),
+    ("kh", "yes", "true"),
+    ("kk", "no", "false"),
+    ("kk", "yes", "true"),
+    ("kn", "no", "false"),
+    ("kn", "yes", "true"),
+    ("ks", "identical", "identic"),
+    ("ks", "primary", "level1"),
+    ("ks", "quaternary", "level4"),
+    ("ks", "secondary", "level2"),
+    ("ks", "tertiary", "level3"),
+    ("ms", "imperial", "uksystem"),
+    ("nu", "traditional", "traditio"),
+];
+
+fn keyword_to_key(keyword: &str) -> Option<&'static str> {
+    KEYWORDS
+        .iter()
+        .find(|(name, _)| *name == keyword)
+        .map(|(_, key)| *key)
+}
+
+fn value_to_type<'a>(key: &str, value: &'a str) -> &'a str {
+    TYPES
+        .iter()
+        .find(|(k, v, _)| *k == key && *v == value)
+        .map_or(value, |(_, _, t)| *t)
+}
+
+fn key_to_keyword(key: &str) -> &str {
+    KEYWORDS
+        .iter()
+        .find(|(_, k)| *k == key)
+        .map_or(key, |(name, _)| *name)
+}
+
+fn type_to_value<'a>(key: &str, t: &'a str) -> &'a str {
+    TYPES
+        .iter()
+        .find(|(k, _, ty)| *k == key && *ty == t)
+        .map_or(t, |(_, v, _)| *v)
+}
+
+/// Applies ICU keywords, such as `calendar=buddhist;collation=phonebook`,
+/// as `-u-` keywords of the `Locale`.
+///
+/// Keyword names which are already `-u-` keys, such as `ca`, are accepted as well.
+/// Keywords take precedence over the `-u-` extension of the `Locale`.
+pub(crate) fn apply_keywords(locale: &mut Locale, keywords: &str) -> Result<(), ParserError> {
+    for keyword in keywords.split(';') {
+        let mut parts = keyword.splitn(2, '=');
+        let (name, raw_value) = match (parts.next(), parts.next()) {
+            (Some(name), Some(value)) if !name.is_empty() && !value.is_empty() => (name, value),
+            _ => return Err(ParserError::InvalidExtension),
+        };
+        let name = name.to_ascii_lowercase();
+        let key = keyword_to_key(&name).unwrap_or(name.as_str());
+
+        let value = raw_value.to_ascii_lowercase();
+        // Time zones are given as IANA names, such as `America/Los_Angeles`.
+        #[cfg(feature = "timezone-data")]
+        let value = match key {
+            "tz" if raw_value.contains('/') => crate::data::timezone::get_timezone(raw_value)
+                .ok_or(ParserError::InvalidExtension)?
+                .to_string(),
+            _ => value,
+        };
+
+        let types: Vec<&str> = value_to_type(key, &value)
+            .split(&['-', '_'][..])
+            .collect();
+        locale
+            .extensions
+            .unicode
+            .set_keyword(key, types)
+            .map_err(|_| ParserError::InvalidExtension)?;
+    }
+    Ok(())
+}
+
+/// Returns the extensions as sorted ICU keywords.
+///
+/// `-u-` keywords are mapped onto ICU keyword names and values, `-u-` attributes
+/// are kept under `attribute`, and all other extensions under their singleton,
+/// such as `t=es-h0-hybrid` or `x=foo`.
+pub(crate) fn keywords(extensions: &Extensions) -> Vec<(String, String)> {
+    let mut result: Vec<(String, String)> = vec![];
+    for (ext_type, key, values) in extensions.iter() {
+        match ext_type {
+            ExtensionType::Unicode if key.is_empty() => {
+                result.push(("attribute".to_string(), values.join("-")));
+            }
+            ExtensionType::Unicode => {
+                // A keyword without a type stands for `true`.
+                let t = if values.is_empty() {
+                    "true".to_string()
+                } else {
+                    values.join("-")
+                };
+                let value = type_to_value(key, &t).to_string();
+                // Time zones are written as IANA names, such as `America/Los_Angeles`.
+                #[cfg(feature = "timezone-data")]
+                let value = match key {
+                    "tz" => crate::data::timezone::get_iana_name(&t).map_or(value, String::from),
+                    _ => value,
+                };
+                result.push((key_to_keyword(key).to_string(), value));
+            }
+            _ => {
+                let name = ext_type.to_string();
+                let mut subtags = vec![];
+                if !key.is_empty() {
+                    subtags.push(key);
+                }
+                subtags.extend(values);
+                let subtags = subtags.join("-").to_ascii_lowercase();
+                match result.iter_mut().find(|(n, _)| *n == name) {
+                    Some((_, value)) => {
+                        value.push('-');
+                        value.push_str(&subtags);
+                    }
+                    None => result.push((name, subtags)),
+                }
+            }
+        }
+    }
+    result.sort();
+    result
+}
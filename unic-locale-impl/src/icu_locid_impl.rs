@@ -0,0 +1,57 @@
+//! Conversions between `Locale` and `icu_locid::Locale`.
+//!
+//! The language identifiers are converted subtag by subtag, while the
+//! extensions are converted through their serialized form.
+use crate::errors::LocaleError;
+use crate::extensions::Extensions;
+use crate::Locale;
+use icu_locid::ParserError;
+use std::convert::TryFrom;
+use unic_langid_impl::LanguageIdentifier;
+
+impl TryFrom<&Locale> for icu_locid::Locale {
+    type Error = ParserError;
+
+    fn try_from(locale: &Locale) -> Result<Self, Self::Error> {
+        let id = icu_locid::LanguageIdentifier::try_from(&locale.langid)?;
+        let extensions = if locale.extensions.is_empty() {
+            icu_locid::extensions::Extensions::new()
+        } else {
+            let source = format!("und{}", locale.extensions);
+            icu_locid::Locale::try_from_bytes(source.as_bytes())?.extensions
+        };
+        Ok(icu_locid::Locale { id, extensions })
+    }
+}
+
+impl TryFrom<Locale> for icu_locid::Locale {
+    type Error = ParserError;
+
+    fn try_from(locale: Locale) -> Result<Self, Self::Error> {
+        icu_locid::Locale::try_from(&locale)
+    }
+}
+
+impl TryFrom<&icu_locid::Locale> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(locale: &icu_locid::Locale) -> Result<Self, Self::Error> {
+        let extensions = if locale.extensions.is_empty() {
+            Extensions::default()
+        } else {
+            locale.extensions.to_string().parse()?
+        };
+        Ok(Locale {
+            langid: LanguageIdentifier::from(&locale.id),
+            extensions,
+        })
+    }
+}
+
+impl TryFrom<icu_locid::Locale> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(locale: icu_locid::Locale) -> Result<Self, Self::Error> {
+        Locale::try_from(&locale)
+    }
+}
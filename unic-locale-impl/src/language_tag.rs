@@ -0,0 +1,343 @@
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+use crate::Locale;
+
+use std::str::FromStr;
+use unic_langid_impl::LanguageIdentifier;
+
+/// Grandfathered tags with their preferred values, if any, as listed in RFC 5646.
+const GRANDFATHERED: &[(&str, Option<&str>)] = &[
+    ("art-lojban", Some("jbo")),
+    ("cel-gaulish", None),
+    ("en-GB-oed", Some("en-GB-oxendict")),
+    ("i-ami", Some("ami")),
+    ("i-bnn", Some("bnn")),
+    ("i-default", None),
+    ("i-enochian", None),
+    ("i-hak", Some("hak")),
+    ("i-klingon", Some("tlh")),
+    ("i-lux", Some("lb")),
+    ("i-mingo", None),
+    ("i-navajo", Some("nv")),
+    ("i-pwn", Some("pwn")),
+    ("i-tao", Some("tao")),
+    ("i-tay", Some("tay")),
+    ("i-tsu", Some("tsu")),
+    ("no-bok", Some("nb")),
+    ("no-nyn", Some("nn")),
+    ("sgn-BE-FR", Some("sfb")),
+    ("sgn-BE-NL", Some("vgt")),
+    ("sgn-CH-DE", Some("sgg")),
+    ("zh-guoyu", Some("cmn")),
+    ("zh-hakka", Some("hak")),
+    ("zh-min", None),
+    ("zh-min-nan", Some("nan")),
+    ("zh-xiang", Some("hsn")),
+];
+
+/// `LanguageTag` represents a full BCP 47 language tag, as defined in RFC 5646.
+///
+/// Unlike `LanguageIdentifier` and `Locale`, which follow the Unicode subset of BCP 47
+/// defined in UTS #35, it supports extended language subtags, grandfathered tags,
+/// private use tags and all extensions, and preserves the order of subtags.
+///
+/// The tag is stored with the case conventions of RFC 5646.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::LanguageTag;
+///
+/// let tag: LanguageTag = "ZH-yue-hant-HK-a-foo-x-private".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(tag.get_language(), Some("zh"));
+/// assert_eq!(tag.get_extlangs(), vec!["yue"]);
+/// assert_eq!(tag.to_string(), "zh-yue-Hant-HK-a-foo-x-private");
+///
+/// let langid = tag.to_language_identifier()
+///     .expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "yue-Hant-HK");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct LanguageTag {
+    language: Option<String>,
+    extlangs: Vec<String>,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+    extensions: Vec<(char, Vec<String>)>,
+    private_use: Vec<String>,
+    grandfathered: Option<usize>,
+}
+
+fn is_alpha(subtag: &str, min: usize, max: usize) -> bool {
+    let slen = subtag.len();
+    (min..=max).contains(&slen) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_subtag(subtag: &str) -> bool {
+    let slen = subtag.len();
+    (1..=8).contains(&slen) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_script(subtag: &str) -> bool {
+    is_alpha(subtag, 4, 4)
+}
+
+fn is_region(subtag: &str) -> bool {
+    is_alpha(subtag, 2, 2) || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    match subtag.len() {
+        4 => subtag.as_bytes()[0].is_ascii_digit(),
+        5..=8 => true,
+        _ => false,
+    }
+}
+
+fn to_titlecase(subtag: &str) -> String {
+    let mut result = subtag.to_ascii_lowercase();
+    result[..1].make_ascii_uppercase();
+    result
+}
+
+impl LanguageTag {
+    /// Returns the primary language subtag.
+    ///
+    /// Grandfathered and private use tags have no language subtag.
+    pub fn get_language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Returns the extended language subtags.
+    pub fn get_extlangs(&self) -> Vec<&str> {
+        self.extlangs.iter().map(String::as_str).collect()
+    }
+
+    pub fn get_script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    pub fn get_region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// Returns the variant subtags in their original order.
+    pub fn get_variants(&self) -> Vec<&str> {
+        self.variants.iter().map(String::as_str).collect()
+    }
+
+    /// Returns the subtags of the extension with the given singleton.
+    pub fn get_extension(&self, singleton: char) -> Option<Vec<&str>> {
+        let singleton = singleton.to_ascii_lowercase();
+        self.extensions
+            .iter()
+            .find(|(s, _)| *s == singleton)
+            .map(|(_, subtags)| subtags.iter().map(String::as_str).collect())
+    }
+
+    /// Returns the private use subtags.
+    pub fn get_private_use(&self) -> Vec<&str> {
+        self.private_use.iter().map(String::as_str).collect()
+    }
+
+    /// Returns `true` if the tag is one of the grandfathered tags of RFC 5646.
+    pub fn is_grandfathered(&self) -> bool {
+        self.grandfathered.is_some()
+    }
+
+    /// Converts the tag into a UTS #35 `LanguageIdentifier`.
+    ///
+    /// Extended language subtags replace the primary language, and grandfathered tags
+    /// are replaced with their preferred values. Extensions and private use subtags are dropped.
+    ///
+    /// Fails for tags which have no UTS #35 equivalent, such as private use tags
+    /// and grandfathered tags without a preferred value.
+    pub fn to_language_identifier(&self) -> Result<LanguageIdentifier, LocaleError> {
+        if let Some(idx) = self.grandfathered {
+            return match GRANDFATHERED[idx].1 {
+                Some(preferred) => preferred.parse().map_err(Into::into),
+                None => Err(ParserError::InvalidLanguage.into()),
+            };
+        }
+        let language = self
+            .extlangs
+            .first()
+            .or(self.language.as_ref())
+            .ok_or(ParserError::InvalidLanguage)?;
+        let variants = self.get_variants();
+        LanguageIdentifier::from_parts(
+            Some(language.as_str()),
+            self.get_script(),
+            self.get_region(),
+            &variants,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Converts the tag into a UTS #35 `Locale`.
+    ///
+    /// The language identifier is converted as in `to_language_identifier`, and
+    /// the extensions and private use subtags are preserved.
+    pub fn to_locale(&self) -> Result<Locale, LocaleError> {
+        let mut locale = Locale::from(self.to_language_identifier()?);
+        let mut extensions = String::new();
+        for (singleton, subtags) in &self.extensions {
+            match singleton {
+                'u' | 't' => {
+                    extensions.push(*singleton);
+                    for subtag in subtags {
+                        extensions.push('-');
+                        extensions.push_str(subtag);
+                    }
+                    extensions.push('-');
+                }
+                _ => {
+                    let subtags = subtags
+                        .iter()
+                        .map(|s| s.parse().map_err(|_| ParserError::InvalidSubtag))
+                        .collect::<Result<_, _>>()?;
+                    locale.extensions.other.insert(*singleton, subtags);
+                }
+            }
+        }
+        if !self.private_use.is_empty() {
+            extensions.push('x');
+            for subtag in &self.private_use {
+                extensions.push('-');
+                extensions.push_str(subtag);
+            }
+        }
+        let parsed: crate::Extensions = extensions.trim_end_matches('-').parse()?;
+        locale.extensions.unicode = parsed.unicode;
+        locale.extensions.transform = parsed.transform;
+        locale.extensions.private = parsed.private;
+        Ok(locale)
+    }
+}
+
+impl FromStr for LanguageTag {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        if let Some(idx) = GRANDFATHERED
+            .iter()
+            .position(|(tag, _)| tag.eq_ignore_ascii_case(source))
+        {
+            return Ok(LanguageTag {
+                grandfathered: Some(idx),
+                ..LanguageTag::default()
+            });
+        }
+
+        let lower = source.to_ascii_lowercase();
+        let subtags: Vec<&str> = lower.split('-').collect();
+        if !subtags.iter().all(|s| is_subtag(s)) {
+            return Err(ParserError::InvalidSubtag);
+        }
+
+        let mut tag = LanguageTag::default();
+        let mut iter = subtags.into_iter().peekable();
+
+        if iter.peek() != Some(&"x") {
+            let language = iter.next().ok_or(ParserError::InvalidLanguage)?;
+            if !is_alpha(language, 2, 8) {
+                return Err(ParserError::InvalidLanguage);
+            }
+            if language.len() <= 3 {
+                while let Some(extlang) = iter.peek().copied().filter(|s| is_alpha(s, 3, 3)) {
+                    if tag.extlangs.len() == 3 {
+                        break;
+                    }
+                    iter.next();
+                    tag.extlangs.push(extlang.to_string());
+                }
+            }
+            tag.language = Some(language.to_string());
+
+            if let Some(script) = iter.peek().copied().filter(|s| is_script(s)) {
+                iter.next();
+                tag.script = Some(to_titlecase(script));
+            }
+            if let Some(region) = iter.peek().copied().filter(|s| is_region(s)) {
+                iter.next();
+                tag.region = Some(region.to_ascii_uppercase());
+            }
+            while let Some(variant) = iter.peek().copied().filter(|s| is_variant(s)) {
+                iter.next();
+                if tag.variants.iter().any(|v| v == variant) {
+                    return Err(ParserError::InvalidSubtag);
+                }
+                tag.variants.push(variant.to_string());
+            }
+
+            let is_singleton = |s: &&str| s.len() == 1 && *s != "x";
+            while let Some(singleton) = iter.peek().copied().filter(is_singleton) {
+                iter.next();
+                let singleton = singleton.as_bytes()[0] as char;
+                if tag.extensions.iter().any(|(s, _)| *s == singleton) {
+                    return Err(ParserError::InvalidExtension);
+                }
+                let mut subtags = vec![];
+                while let Some(subtag) = iter.peek().copied().filter(|s| s.len() >= 2) {
+                    iter.next();
+                    subtags.push(subtag.to_string());
+                }
+                if subtags.is_empty() {
+                    return Err(ParserError::InvalidExtension);
+                }
+                tag.extensions.push((singleton, subtags));
+            }
+        }
+
+        match iter.next() {
+            Some("x") => {
+                tag.private_use = iter.map(String::from).collect();
+                if tag.private_use.is_empty() {
+                    return Err(ParserError::InvalidExtension);
+                }
+            }
+            Some(_) => return Err(ParserError::InvalidSubtag),
+            None => {}
+        }
+
+        Ok(tag)
+    }
+}
+
+impl std::fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(idx) = self.grandfathered {
+            return f.write_str(GRANDFATHERED[idx].0);
+        }
+
+        let mut subtags: Vec<&str> = vec![];
+        if let Some(language) = &self.language {
+            subtags.push(language);
+        }
+        subtags.extend(self.extlangs.iter().map(String::as_str));
+        if let Some(script) = &self.script {
+            subtags.push(script);
+        }
+        if let Some(region) = &self.region {
+            subtags.push(region);
+        }
+        subtags.extend(self.variants.iter().map(String::as_str));
+        let singletons: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|(s, _)| s.to_string())
+            .collect();
+        for ((_, ext), singleton) in self.extensions.iter().zip(&singletons) {
+            subtags.push(singleton);
+            subtags.extend(ext.iter().map(String::as_str));
+        }
+        if !self.private_use.is_empty() {
+            subtags.push("x");
+            subtags.extend(self.private_use.iter().map(String::as_str));
+        }
+        f.write_str(&subtags.join("-"))
+    }
+}
@@ -1,20 +1,47 @@
+mod data;
+mod data_versions;
 pub mod errors;
 pub mod extensions;
+mod icu;
+#[cfg(feature = "icu_locid")]
+mod icu_locid_impl;
+mod language_tag;
+pub mod os;
 pub mod parser;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "web")]
+pub mod web;
 
+pub use crate::data_versions::{data_versions, DataVersions};
 use errors::LocaleError;
-pub use extensions::{ExtensionType, ExtensionsMap};
+use extensions::{CollationType, Subdivision};
+pub use extensions::{ExtensionType, Extensions, ExtensionsBuilder, RawExtensions};
+#[allow(deprecated)]
+pub use extensions::ExtensionsMap;
+pub use language_tag::LanguageTag;
+use std::borrow::Cow;
 use std::str::FromStr;
 use tinystr::{TinyStr4, TinyStr8};
+use unic_langid_impl::CanonicalLanguageIdentifier;
 pub use unic_langid_impl::CharacterDirection;
 pub use unic_langid_impl::LanguageIdentifier;
+pub use unic_langid_impl::LineOrientation;
+pub use unic_langid_impl::Region;
+use unic_langid_impl::{LanguageIdentifierError, MatchQuality, ParserOptions};
 
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct Locale {
     pub langid: LanguageIdentifier,
-    pub extensions: extensions::ExtensionsMap,
+    pub extensions: extensions::Extensions,
 }
 
+const COLLATION: &str = "co";
+const REGION_OVERRIDE: &str = "rg";
+const SUBDIVISION: &str = "sd";
+const POSIX: &str = "posix";
+const PSEUDO: &str = "pseudo";
+
 type RawPartsTuple = (
     Option<u64>,
     Option<u32>,
@@ -23,19 +50,78 @@ type RawPartsTuple = (
     String,
 );
 
+type TinyStrPartsTuple = (
+    Option<TinyStr8>,
+    Option<TinyStr4>,
+    Option<TinyStr4>,
+    Option<Box<[TinyStr8]>>,
+    extensions::Extensions,
+);
+
+/// A `Locale` decomposed into raw internal representations of its subtags.
+///
+/// `RawLocale` consists of primitive arrays only, so it can be constructed
+/// in a `const` context and embedded in generated code, which restores the
+/// `Locale` with `Locale::from_raw_parts_v2_unchecked` without parsing.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{Locale, RawExtensions, RawLocale};
+///
+/// let loc: Locale = "en-US-u-hc-h12".parse()
+///     .expect("Parsing failed.");
+///
+/// let loc2 = loc.with_raw_parts_v2(|raw| unsafe { Locale::from_raw_parts_v2_unchecked(&raw) });
+/// assert_eq!(loc2, loc);
+///
+/// static EN: RawLocale = RawLocale {
+///     langid: (Some(28261), None, None, &[]),
+///     extensions: RawExtensions {
+///         unicode_attributes: &[],
+///         unicode_keywords: &[],
+///         transform_language: None,
+///         transform_fields: &[],
+///         other: &[],
+///         private: &[],
+///     },
+/// };
+/// let en = unsafe { Locale::from_raw_parts_v2_unchecked(&EN) };
+/// assert_eq!(en.to_string(), "en");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RawLocale<'a> {
+    pub langid: extensions::RawLanguageIdentifier<'a>,
+    pub extensions: RawExtensions<'a>,
+}
+
 impl Locale {
+    /// A constructor which takes optional subtags as `&str` and typed `Extensions`,
+    /// parses the subtags and produces a well-formed `Locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Extensions, Locale};
+    ///
+    /// let extensions = Extensions::builder()
+    ///     .unicode_keyword("hc", &["h12"])
+    ///     .build()
+    ///     .expect("Building failed.");
+    /// let loc = Locale::from_parts(Some("fr"), None, Some("CA"), &[], extensions)
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "fr-CA-u-hc-h12");
+    /// ```
     pub fn from_parts<S: AsRef<str>>(
         language: Option<S>,
         script: Option<S>,
         region: Option<S>,
         variants: &[S],
-        extensions: Option<extensions::ExtensionsMap>,
+        extensions: extensions::Extensions,
     ) -> Result<Self, LocaleError> {
         let langid = LanguageIdentifier::from_parts(language, script, region, variants)?;
-        Ok(Locale {
-            langid,
-            extensions: extensions.unwrap_or_default(),
-        })
+        Ok(Locale { langid, extensions })
     }
 
     pub fn into_raw_parts(self) -> RawPartsTuple {
@@ -43,19 +129,86 @@ impl Locale {
         (lang, region, script, variants, self.extensions.to_string())
     }
 
+    /// Consumes `Locale` and produces its subtags as `TinyStr` values together
+    /// with the extensions, in the form accepted by `from_raw_parts_unchecked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let (lang, script, region, variants, extensions) = loc.into_tinystr_parts();
+    ///
+    /// let loc2 = unsafe {
+    ///     Locale::from_raw_parts_unchecked(lang, script, region, variants, extensions)
+    /// };
+    ///
+    /// assert_eq!(loc2.to_string(), "en-US-u-hc-h12");
+    /// ```
+    pub fn into_tinystr_parts(self) -> TinyStrPartsTuple {
+        let (lang, script, region, variants) = self.langid.into_tinystr_parts();
+        (lang, script, region, variants, self.extensions)
+    }
+
     #[inline(always)]
     pub unsafe fn from_raw_parts_unchecked(
         language: Option<TinyStr8>,
         script: Option<TinyStr4>,
         region: Option<TinyStr4>,
         variants: Option<Box<[TinyStr8]>>,
-        extensions: extensions::ExtensionsMap,
+        extensions: extensions::Extensions,
     ) -> Self {
         let langid =
             LanguageIdentifier::from_raw_parts_unchecked(language, script, region, variants);
         Self { langid, extensions }
     }
 
+    /// Calls `f` with the raw internal representation of the `Locale`.
+    ///
+    /// Unlike `into_raw_parts`, the extensions are decomposed into their subtags,
+    /// so they can be restored with `from_raw_parts_v2_unchecked` without parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12-x-testing".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.with_raw_parts_v2(|raw| {
+    ///     assert_eq!(raw.extensions.unicode_keywords.len(), 1);
+    ///     assert_eq!(raw.extensions.private.len(), 1);
+    /// });
+    /// ```
+    pub fn with_raw_parts_v2<R>(&self, f: impl FnOnce(RawLocale) -> R) -> R {
+        let (language, script, region, variants) = extensions::raw_langid(&self.langid);
+        self.extensions.with_raw(|extensions| {
+            f(RawLocale {
+                langid: (language, script, region, &variants),
+                extensions,
+            })
+        })
+    }
+
+    /// Restores a `Locale` from its raw internal representation without any checks.
+    ///
+    /// See `RawLocale` for an example.
+    ///
+    /// # Safety
+    ///
+    /// All the subtags have to be valid raw representations of well-formed,
+    /// canonical subtags, as produced by `with_raw_parts_v2`.
+    pub unsafe fn from_raw_parts_v2_unchecked(raw: &RawLocale) -> Self {
+        Self {
+            langid: extensions::langid_from_raw(raw.langid),
+            extensions: Extensions::from_raw_unchecked(&raw.extensions),
+        }
+    }
+
     pub fn matches<O: AsRef<Self>>(
         &self,
         other: &O,
@@ -70,6 +223,399 @@ impl Locale {
             .matches(&other.langid, self_as_range, other_as_range)
     }
 
+    /// Returns `true` if both locales share the same language.
+    ///
+    /// See `LanguageIdentifier::speaks_same_language` for details.
+    pub fn speaks_same_language<O: AsRef<LanguageIdentifier>>(&self, other: &O) -> bool {
+        self.langid.speaks_same_language(other)
+    }
+
+    /// Returns `true` if the language identifier of this locale subsumes `other`.
+    ///
+    /// See `LanguageIdentifier::subsumes` for details.
+    pub fn subsumes<O: AsRef<LanguageIdentifier>>(&self, other: &O) -> bool {
+        self.langid.subsumes(other)
+    }
+
+    /// Returns `true` if the language identifier of this locale matches the range.
+    ///
+    /// See `LanguageIdentifier::matches_str` for details.
+    pub fn matches_str(
+        &self,
+        range: &str,
+        options: &ParserOptions,
+    ) -> Result<bool, LanguageIdentifierError> {
+        self.langid.matches_str(range, options)
+    }
+
+    /// Returns how closely the language identifier of this locale matches `other`.
+    ///
+    /// See `LanguageIdentifier::match_quality` for details.
+    pub fn match_quality<O: AsRef<LanguageIdentifier>>(&self, other: &O) -> MatchQuality {
+        self.langid.match_quality(other)
+    }
+
+    /// Fills the missing script, region and variants from `other`.
+    ///
+    /// Extensions are left untouched.
+    /// See `LanguageIdentifier::fill_missing_from` for details.
+    pub fn fill_missing_from<O: AsRef<LanguageIdentifier>>(&mut self, other: &O) {
+        self.langid.fill_missing_from(other)
+    }
+
+    /// Merges another locale into this one.
+    ///
+    /// Missing language, script, region and variants are copied from `other`,
+    /// and extension keywords, fields and tags are combined.
+    ///
+    /// In case of a conflict, values of `self` are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "de-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    /// let other: Locale = "en-CH-u-ca-buddhist-hc-h23".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.merge(&other);
+    /// assert_eq!(loc.to_string(), "de-CH-u-ca-buddhist-hc-h12");
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        let langid = &mut self.langid;
+        if langid.get_language() == "und" {
+            langid
+                .set_language(Some(other.get_language()))
+                .expect("Language of a locale is valid.");
+        }
+        langid.fill_missing_from(&other.langid);
+        self.extensions.merge(&other.extensions);
+    }
+
+    /// Applies an extension fragment, such as `-u-nu-thai` or `und-u-ca-buddhist`,
+    /// onto the `Locale`.
+    ///
+    /// Extensions of the fragment take precedence over the ones of the `Locale`.
+    /// Fails if the fragment contains anything but extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "th-TH-u-ca-buddhist-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.apply_extensions("-u-nu-thai")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "th-TH-u-ca-buddhist-nu-thai");
+    ///
+    /// assert!(loc.apply_extensions("en-u-nu-latn").is_err());
+    /// ```
+    pub fn apply_extensions(&mut self, fragment: &str) -> Result<(), LocaleError> {
+        let fragment: Locale = fragment.parse()?;
+        if fragment.langid != LanguageIdentifier::default() {
+            return Err(parser::ParserError::InvalidExtension.into());
+        }
+        let mut extensions = fragment.extensions;
+        extensions.merge(&self.extensions);
+        self.extensions = extensions;
+        Ok(())
+    }
+
+    /// Returns an iterator over all extensions of the `Locale` as
+    /// `(type, key, values)` tuples, in canonical order.
+    ///
+    /// See `Extensions::iter` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// for (ext_type, key, values) in loc.extensions() {
+    ///     println!("{} {} {:?}", ext_type, key, values);
+    /// }
+    /// ```
+    pub fn extensions(&self) -> impl Iterator<Item = (ExtensionType, &str, Vec<&str>)> {
+        self.extensions.iter()
+    }
+
+    /// Returns the keywords of the unicode extension with their types, sorted by key.
+    ///
+    /// The order does not depend on the order of the keywords in the parsed input,
+    /// or the order in which they were set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-nu-thai-ca-buddhist-kn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let keywords: Vec<_> = loc.unicode_keywords().collect();
+    /// assert_eq!(keywords, vec![
+    ///     ("ca", vec!["buddhist"]),
+    ///     ("kn", vec![]),
+    ///     ("nu", vec!["thai"]),
+    /// ]);
+    /// ```
+    pub fn unicode_keywords(&self) -> impl Iterator<Item = (&str, Vec<&str>)> {
+        self.extensions.unicode.keywords()
+    }
+
+    /// Returns `true` if the `Locale` has any extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(!loc.has_extensions());
+    ///
+    /// let loc: Locale = "en-US-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(loc.has_extensions());
+    /// ```
+    pub fn has_extensions(&self) -> bool {
+        !self.extensions.is_empty()
+    }
+
+    /// Returns `true` if the unicode extension contains the keyword, such as `ca` in `-u-ca-buddhist`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.has_unicode_extension("ca"));
+    /// assert!(!loc.has_unicode_extension("hc"));
+    /// ```
+    pub fn has_unicode_extension(&self, key: &str) -> bool {
+        self.extensions.unicode.has_keyword(key)
+    }
+
+    /// Returns `true` if the unicode extension contains a boolean keyword, such as `kn` in `-u-kn`.
+    ///
+    /// A keyword without a type stands for `true`, and `-u-kn-true` is stored
+    /// in its canonical form `-u-kn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-u-kn-true-ks-level1".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.get_unicode_keyword_flag("kn"));
+    /// assert!(!loc.get_unicode_keyword_flag("ks"));
+    /// assert!(!loc.get_unicode_keyword_flag("kb"));
+    /// assert_eq!(loc.to_string(), "de-u-kn-ks-level1");
+    /// ```
+    pub fn get_unicode_keyword_flag(&self, key: &str) -> bool {
+        self.extensions.unicode.get_keyword_flag(key)
+    }
+
+    /// Removes a keyword of the unicode extension, such as `ca` in `-u-ca-buddhist`.
+    ///
+    /// Returns `true` if the keyword was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-ca-buddhist-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.remove_unicode_ext_keyword("ca"));
+    /// assert!(!loc.remove_unicode_ext_keyword("nu"));
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+    /// ```
+    pub fn remove_unicode_ext_keyword(&mut self, key: &str) -> bool {
+        self.extensions.unicode.remove_keyword(key)
+    }
+
+    /// Removes the extension of the given type, such as the `-x-` private use subtags.
+    ///
+    /// Returns `true` if the extension was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{ExtensionType, Locale};
+    ///
+    /// let mut loc: Locale = "en-US-u-hc-h12-x-user-12345".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.remove_extension(ExtensionType::Private));
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+    /// ```
+    pub fn remove_extension(&mut self, ext_type: ExtensionType) -> bool {
+        self.extensions.remove(ext_type)
+    }
+
+    /// Removes all extensions, leaving only the language identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-hc-h12-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.clear_extensions();
+    /// assert_eq!(loc.to_string(), "en-US");
+    /// ```
+    pub fn clear_extensions(&mut self) {
+        self.extensions.clear();
+    }
+
+    /// Returns the collation type of the `-u-co-` keyword.
+    ///
+    /// Returns `None` if the keyword is missing or its value is not a known `CollationType`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::CollationType;
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "de-DE-u-co-phonebk".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.collation(), Some(CollationType::Phonebk));
+    ///
+    /// loc.set_collation(Some(CollationType::Emoji));
+    /// assert_eq!(loc.to_string(), "de-DE-u-co-emoji");
+    ///
+    /// loc.set_collation(None);
+    /// assert_eq!(loc.to_string(), "de-DE");
+    /// ```
+    pub fn collation(&self) -> Option<CollationType> {
+        let co = self.extensions.unicode.get_keyword(COLLATION)?;
+        match co.as_slice() {
+            [co] => co.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Sets or removes the `-u-co-` keyword.
+    pub fn set_collation(&mut self, collation: Option<CollationType>) {
+        match collation {
+            Some(collation) => self
+                .extensions
+                .unicode
+                .set_keyword(COLLATION, vec![collation.as_str()])
+                .expect("Collation type is valid."),
+            None => {
+                self.extensions.unicode.remove_keyword(COLLATION);
+            }
+        }
+    }
+
+    /// Returns the subdivision of the `-u-sd-` keyword.
+    ///
+    /// Returns `None` if the keyword is not a valid subdivision. Parsing only
+    /// rejects such keywords with `feature = "subdivision-data"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-sd-usca".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let sd = loc.subdivision().expect("Missing subdivision.");
+    /// assert_eq!(sd.get_region(), "US");
+    /// assert_eq!(sd.get_suffix(), "ca");
+    ///
+    /// loc.set_subdivision(Some("usny".parse().expect("Parsing failed.")));
+    /// assert_eq!(loc.to_string(), "en-US-u-sd-usny");
+    /// ```
+    pub fn subdivision(&self) -> Option<Subdivision> {
+        let sd = self.extensions.unicode.get_keyword(SUBDIVISION)?;
+        match sd.as_slice() {
+            [sd] => sd.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Sets or removes the `-u-sd-` keyword.
+    pub fn set_subdivision(&mut self, subdivision: Option<Subdivision>) {
+        match subdivision {
+            Some(subdivision) => self
+                .extensions
+                .unicode
+                .set_keyword(SUBDIVISION, vec![&subdivision.to_string()])
+                .expect("Subdivision is valid."),
+            None => {
+                self.extensions.unicode.remove_keyword(SUBDIVISION);
+            }
+        }
+    }
+
+    /// Returns the `LanguageIdentifier` portion of the `Locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.langid().to_string(), "en-US");
+    ///
+    /// loc.set_langid("en-GB".parse().expect("Parsing failed."));
+    /// assert_eq!(loc.to_string(), "en-GB-u-hc-h12");
+    /// ```
+    pub fn langid(&self) -> &LanguageIdentifier {
+        &self.langid
+    }
+
+    /// Replaces the `LanguageIdentifier` portion of the `Locale`, keeping the extensions.
+    pub fn set_langid(&mut self, langid: LanguageIdentifier) {
+        self.langid = langid;
+    }
+
+    /// Returns the length in bytes of the serialized `Locale`.
+    ///
+    /// The length is computed from the subtags, so it can be used to
+    /// preallocate a buffer before serializing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12-x-testing".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.byte_len(), 24);
+    ///
+    /// let mut s = String::with_capacity(loc.byte_len());
+    /// write!(s, "{}", loc).expect("Writing failed.");
+    /// assert_eq!(s.len(), loc.byte_len());
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.langid.byte_len() + self.extensions.byte_len()
+    }
+
     pub fn get_language(&self) -> &str {
         self.langid.get_language()
     }
@@ -110,19 +656,442 @@ impl Locale {
             .map_err(std::convert::Into::into)
     }
 
-    #[cfg(feature = "likelysubtags")]
+    #[cfg(feature = "likelysubtags-add")]
     pub fn add_likely_subtags(&mut self) -> bool {
         self.langid.add_likely_subtags()
     }
 
-    #[cfg(feature = "likelysubtags")]
+    #[cfg(feature = "likelysubtags-remove")]
     pub fn remove_likely_subtags(&mut self) -> bool {
         self.langid.remove_likely_subtags()
     }
 
+    /// Returns the script of the `Locale`, or its likely script if the script is not specified.
+    ///
+    /// See `LanguageIdentifier::likely_script` for details.
+    #[cfg(feature = "likelysubtags-add")]
+    pub fn likely_script(&self) -> Option<TinyStr4> {
+        self.langid.likely_script()
+    }
+
+    /// Returns the region of the `Locale`, or its likely region if the region is not specified.
+    ///
+    /// See `LanguageIdentifier::likely_region` for details.
+    #[cfg(feature = "likelysubtags-add")]
+    pub fn likely_region(&self) -> Option<TinyStr4> {
+        self.langid.likely_region()
+    }
+
+    #[cfg(feature = "binary-data")]
+    pub fn add_likely_subtags_with(
+        &mut self,
+        data: &unic_langid_impl::likelysubtags::runtime::LikelySubtagsData,
+    ) -> bool {
+        self.langid.add_likely_subtags_with(data)
+    }
+
+    #[cfg(feature = "binary-data")]
+    pub fn remove_likely_subtags_with(
+        &mut self,
+        data: &unic_langid_impl::likelysubtags::runtime::LikelySubtagsData,
+    ) -> bool {
+        self.langid.remove_likely_subtags_with(data)
+    }
+
+    /// Returns `true` if the `Locale` is a pseudo-locale used for testing.
+    ///
+    /// In addition to the pseudo-locales recognized by
+    /// `LanguageIdentifier::is_pseudo_locale`, such as `en-XA`, this recognizes
+    /// the `-x-pseudo` private use convention, such as `en-US-x-pseudo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "ar-XB".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-US-x-pseudo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.is_pseudo_locale(), true);
+    /// assert_eq!(loc2.is_pseudo_locale(), true);
+    /// ```
+    pub fn is_pseudo_locale(&self) -> bool {
+        self.langid.is_pseudo_locale() || self.extensions.private.has_tag(PSEUDO)
+    }
+
+    #[cfg(feature = "layout")]
     pub fn get_character_direction(&self) -> CharacterDirection {
         self.langid.get_character_direction()
     }
+
+    #[cfg(feature = "layout")]
+    pub fn try_get_character_direction(&self) -> Option<CharacterDirection> {
+        self.langid.try_get_character_direction()
+    }
+
+    #[cfg(feature = "layout")]
+    pub fn get_line_orientation(&self) -> LineOrientation {
+        self.langid.get_line_orientation()
+    }
+
+    /// Returns the default numbering system of the locale.
+    ///
+    /// An explicit `-u-nu-` keyword takes precedence over the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "ar-EG".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "ar-EG-u-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.default_numbering_system(), "arab");
+    /// assert_eq!(loc2.default_numbering_system(), "latn");
+    /// ```
+    #[cfg(feature = "numbering-data")]
+    pub fn default_numbering_system(&self) -> &str {
+        if let Some(nu) = self
+            .extensions
+            .unicode
+            .get_keyword("nu")
+            .and_then(|nu| nu.first().copied())
+        {
+            return nu;
+        }
+        data::numbering::get_numbering_system(
+            self.get_language(),
+            self.get_script(),
+            self.get_region(),
+        )
+    }
+
+    /// Returns the default hour cycle of the locale, either `h12` or `h23`.
+    ///
+    /// An explicit `-u-hc-` keyword takes precedence over the data.
+    /// If the region is not specified and `feature = "likelysubtags-add"` is enabled,
+    /// the likely region is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc3: Locale = "en-US-u-hc-h23".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.default_hour_cycle(), "h12");
+    /// assert_eq!(loc2.default_hour_cycle(), "h23");
+    /// assert_eq!(loc3.default_hour_cycle(), "h23");
+    /// ```
+    #[cfg(feature = "time-data")]
+    pub fn default_hour_cycle(&self) -> &str {
+        if let Some(hc) = self
+            .extensions
+            .unicode
+            .get_keyword("hc")
+            .and_then(|hc| hc.first().copied())
+        {
+            return hc;
+        }
+        data::time::get_hour_cycle(self.data_region().as_ref().map(|r| r.as_str()))
+    }
+
+    /// Returns the IANA time zone name of the `-u-tz-` keyword.
+    ///
+    /// With `feature = "timezone-data"` the keyword is validated and
+    /// canonicalized when parsing, so an unknown time zone is a parser error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-tz-uslax".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.get_timezone(), Some("America/Los_Angeles"));
+    /// assert!("en-US-u-tz-xxxxx".parse::<Locale>().is_err());
+    /// ```
+    #[cfg(feature = "timezone-data")]
+    pub fn get_timezone(&self) -> Option<&'static str> {
+        let tz = self.extensions.unicode.get_keyword("tz")?;
+        data::timezone::get_iana_name(tz.first()?)
+    }
+
+    /// Returns the first day of the week of the locale,
+    /// as one of `mon`, `tue`, `wed`, `thu`, `fri`, `sat`, `sun`.
+    ///
+    /// An explicit `-u-fw-` keyword takes precedence over the data.
+    /// If the region is not specified and `feature = "likelysubtags-add"` is enabled,
+    /// the likely region is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "fr-FR".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc3: Locale = "ar-EG-u-fw-mon".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.first_day_of_week(), "sun");
+    /// assert_eq!(loc2.first_day_of_week(), "mon");
+    /// assert_eq!(loc3.first_day_of_week(), "mon");
+    /// ```
+    #[cfg(feature = "week-data")]
+    pub fn first_day_of_week(&self) -> &str {
+        if let Some(fw) = self
+            .extensions
+            .unicode
+            .get_keyword("fw")
+            .and_then(|fw| fw.first().copied())
+        {
+            return fw;
+        }
+        data::week::get_first_day(self.data_region().as_ref().map(|r| r.as_str()))
+    }
+
+    /// Checks the `-u-` keywords against the keys and values defined by CLDR.
+    ///
+    /// Parsing only checks that the keywords are well-formed, so a misspelled
+    /// value such as `gregorin` is accepted. Values which CLDR does not
+    /// enumerate, such as currency codes, are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-ca-islamic-civil-kn".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(loc.validate_keywords().is_ok());
+    ///
+    /// let loc: Locale = "en-US-u-ca-gregorin".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(
+    ///     loc.validate_keywords().unwrap_err().to_string(),
+    ///     "Unknown keyword: ca-gregorin"
+    /// );
+    /// ```
+    #[cfg(feature = "keyword-data")]
+    pub fn validate_keywords(&self) -> Result<(), parser::ParserError> {
+        for (key, values) in self.extensions.unicode.keywords() {
+            let value = values.join("-");
+            if !data::keyword::is_known_keyword(key, &value) {
+                let keyword = if value.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{}-{}", key, value)
+                };
+                return Err(parser::ParserError::UnknownKeyword(keyword));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the likely currency of the locale, based on its region.
+    ///
+    /// If the region is not specified, the likely region is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "ja".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-IE".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.likely_currency(), Some("JPY"));
+    /// assert_eq!(loc2.likely_currency(), Some("EUR"));
+    /// ```
+    #[cfg(feature = "currency-data")]
+    pub fn likely_currency(&self) -> Option<&'static str> {
+        self.data_region()
+            .and_then(|region| data::currency::get_region_currency(&region))
+    }
+
+    /// Returns the region used for region based data lookups.
+    #[cfg(any(
+        feature = "time-data",
+        feature = "currency-data",
+        feature = "week-data"
+    ))]
+    fn data_region(&self) -> Option<TinyStr4> {
+        self.effective_region().map(Into::into)
+    }
+
+    /// Returns the region which should be used for regional preferences of the locale.
+    ///
+    /// The region of a `-u-rg-` override, such as `uszzzz`, takes precedence.
+    /// Otherwise the region subtag is used, and if the region is not specified and
+    /// `feature = "likelysubtags-add"` is enabled, the likely region, which may be
+    /// a UN M.49 region such as `001`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-GB-u-rg-uszzzz".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.effective_region().map(|r| r.to_string()), Some("US".to_string()));
+    /// assert_eq!(loc2.effective_region().map(|r| r.to_string()), Some("GB".to_string()));
+    /// ```
+    pub fn effective_region(&self) -> Option<Region> {
+        let rg = self
+            .extensions
+            .unicode
+            .get_keyword(REGION_OVERRIDE)
+            .and_then(|rg| rg.first().copied());
+        if let Some(region) = rg.and_then(|rg| rg.get(..2)).and_then(|r| r.parse().ok()) {
+            return Some(region);
+        }
+
+        #[cfg(feature = "likelysubtags-add")]
+        let region = self.likely_region();
+        #[cfg(not(feature = "likelysubtags-add"))]
+        let region = self.get_region();
+        region.and_then(|r| r.parse().ok())
+    }
+
+    /// Replaces the `posix` variant with the canonical `-u-va-posix` keyword.
+    ///
+    /// Returns `true` if the locale has been modified, and an error if
+    /// the locale already has a different `-u-va-` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc = Locale::default();
+    /// loc.set_language(Some("en")).expect("Setting language failed.");
+    /// loc.set_variants(&["posix"]).expect("Setting variants failed.");
+    ///
+    /// assert_eq!(loc.posix_variant_to_keyword().expect("Setting keyword failed."), true);
+    /// assert_eq!(loc.to_string(), "en-u-va-posix");
+    /// ```
+    pub fn posix_variant_to_keyword(&mut self) -> Result<bool, parser::ParserError> {
+        let variants = self.langid.get_variants();
+        if !variants.contains(&POSIX) {
+            return Ok(false);
+        }
+        match self.extensions.unicode.get_keyword("va") {
+            Some(va) if va != [POSIX] => {
+                return Err(parser::ParserError::ConflictingKeyword("va".to_string()));
+            }
+            _ => {}
+        }
+        let variants: Vec<String> = variants
+            .into_iter()
+            .filter(|v| *v != POSIX)
+            .map(String::from)
+            .collect();
+        let variants: Vec<&str> = variants.iter().map(String::as_str).collect();
+        Ok(self.langid.set_variants(&variants).is_ok()
+            && self.extensions.unicode.set_keyword("va", vec![POSIX]).is_ok())
+    }
+
+    /// Replaces the `-u-va-posix` keyword with the `posix` variant.
+    ///
+    /// Returns `true` if the locale has been modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US-posix".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "en-US-u-va-posix");
+    ///
+    /// assert_eq!(loc.keyword_to_posix_variant(), true);
+    /// assert_eq!(loc.to_string(), "en-US-posix");
+    /// ```
+    pub fn keyword_to_posix_variant(&mut self) -> bool {
+        if self.extensions.unicode.get_keyword("va") != Some(vec![POSIX]) {
+            return false;
+        }
+        let mut variants = self.langid.get_variants();
+        variants.push(POSIX);
+        let variants: Vec<String> = variants.into_iter().map(String::from).collect();
+        let variants: Vec<&str> = variants.iter().map(String::as_str).collect();
+        self.langid.set_variants(&variants).is_ok() && self.extensions.unicode.remove_keyword("va")
+    }
+
+    /// Serializes the `Locale` into the ICU `language_Script_REGION@key=value` form.
+    ///
+    /// `-u-` keywords are written with their ICU names and values, and the
+    /// `-u-va-posix` keyword as the `POSIX` variant.
+    /// An undetermined language is written as an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-ca-buddhist-co-phonebk".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_icu_string(), "en_US@calendar=buddhist;collation=phonebook");
+    ///
+    /// let loc: Locale = "en-US-posix".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_icu_string(), "en_US_POSIX");
+    /// ```
+    pub fn to_icu_string(&self) -> String {
+        let mut locale = self.clone();
+        locale.keyword_to_posix_variant();
+
+        let mut result = String::new();
+        if locale.get_language() != "und" {
+            result.push_str(locale.get_language());
+        }
+        if let Some(script) = locale.get_script() {
+            result.push('_');
+            result.push_str(script);
+        }
+        if let Some(region) = locale.get_region() {
+            result.push('_');
+            result.push_str(region);
+        }
+        let variants = locale.get_variants();
+        if !variants.is_empty() {
+            // Variants are separated from the language by an empty region.
+            if locale.get_region().is_none() {
+                result.push('_');
+            }
+            for variant in variants {
+                result.push('_');
+                result.push_str(&variant.to_ascii_uppercase());
+            }
+        }
+
+        let keywords = icu::keywords(&locale.extensions);
+        for (idx, (name, value)) in keywords.iter().enumerate() {
+            result.push(if idx == 0 { '@' } else { ';' });
+            result.push_str(name);
+            result.push('=');
+            result.push_str(value);
+        }
+        result
+    }
 }
 
 impl FromStr for Locale {
@@ -137,7 +1106,7 @@ impl From<LanguageIdentifier> for Locale {
     fn from(langid: LanguageIdentifier) -> Self {
         Locale {
             langid,
-            extensions: ExtensionsMap::default(),
+            extensions: Extensions::default(),
         }
     }
 }
@@ -154,6 +1123,22 @@ impl AsRef<LanguageIdentifier> for Locale {
     }
 }
 
+/// A `Locale` is equal to a `LanguageIdentifier` if it has no extensions
+/// and its subtags are the same.
+impl PartialEq<LanguageIdentifier> for Locale {
+    fn eq(&self, other: &LanguageIdentifier) -> bool {
+        !self.has_extensions() && self.langid == *other
+    }
+}
+
+impl PartialEq<Locale> for LanguageIdentifier {
+    fn eq(&self, other: &Locale) -> bool {
+        other == self
+    }
+}
+
+/// Gives access to the methods of the `LanguageIdentifier` portion of the `Locale`
+/// which are not available on the `Locale` itself.
 impl AsRef<Locale> for Locale {
     #[inline(always)]
     fn as_ref(&self) -> &Locale {
@@ -163,11 +1148,148 @@ impl AsRef<Locale> for Locale {
 
 impl std::fmt::Display for Locale {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}", self.langid, self.extensions)
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_subtags(f);
+        }
+
+        let mut s = String::with_capacity(self.byte_len());
+        self.write_subtags(&mut s)?;
+        f.pad(&s)
+    }
+}
+
+impl Locale {
+    fn write_subtags<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}{}", self.langid, self.extensions)
     }
 }
 
+/// Returns the current currency of the region, as an ISO 4217 code.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::region_currency;
+///
+/// assert_eq!(region_currency("JP"), Some("JPY"));
+/// assert_eq!(region_currency("de"), Some("EUR"));
+/// assert_eq!(region_currency("ZZ"), None);
+/// ```
+#[cfg(feature = "currency-data")]
+pub fn region_currency(region: &str) -> Option<&'static str> {
+    data::currency::get_region_currency(&region.to_ascii_uppercase())
+}
+
+/// Returns the IANA time zone name of a CLDR short time zone identifier,
+/// as used in the `-u-tz-` keyword.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::timezone_to_iana;
+///
+/// assert_eq!(timezone_to_iana("uslax"), Some("America/Los_Angeles"));
+/// assert_eq!(timezone_to_iana("cnckg"), Some("Asia/Shanghai"));
+/// assert_eq!(timezone_to_iana("xxxxx"), None);
+/// ```
+#[cfg(feature = "timezone-data")]
+pub fn timezone_to_iana(tz: &str) -> Option<&'static str> {
+    data::timezone::get_iana_name(&tz.to_ascii_lowercase())
+}
+
+/// Returns the CLDR short time zone identifier of an IANA time zone name.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::timezone_from_iana;
+///
+/// assert_eq!(timezone_from_iana("America/Los_Angeles"), Some("uslax"));
+/// assert_eq!(timezone_from_iana("Asia/Calcutta"), Some("inccu"));
+/// assert_eq!(timezone_from_iana("Mars/Olympus_Mons"), None);
+/// ```
+#[cfg(feature = "timezone-data")]
+pub fn timezone_from_iana(name: &str) -> Option<&'static str> {
+    data::timezone::get_timezone(name)
+}
+
+#[cfg(feature = "default-locale")]
+static DEFAULT_LOCALE: once_cell::sync::OnceCell<Locale> = once_cell::sync::OnceCell::new();
+
+/// Sets the default locale of the process.
+///
+/// The default locale can be set only once, so that every part of the
+/// program observes the same value. If it has already been set, the given
+/// locale is returned back as an error.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{default_locale, set_default_locale, Locale};
+///
+/// let loc: Locale = "de-CH".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(default_locale(), None);
+/// assert!(set_default_locale(loc.clone()).is_ok());
+/// assert_eq!(default_locale(), Some(&loc));
+///
+/// assert!(set_default_locale(Locale::default()).is_err());
+/// assert_eq!(default_locale(), Some(&loc));
+/// ```
+#[cfg(feature = "default-locale")]
+#[allow(clippy::result_large_err)]
+pub fn set_default_locale(locale: Locale) -> Result<(), Locale> {
+    DEFAULT_LOCALE.set(locale)
+}
+
+/// Returns the default locale of the process, if it has been set with `set_default_locale`.
+#[cfg(feature = "default-locale")]
+pub fn default_locale() -> Option<&'static Locale> {
+    DEFAULT_LOCALE.get()
+}
+
 pub fn canonicalize(input: &str) -> Result<String, LocaleError> {
-    let locale: Locale = input.parse()?;
+    let mut locale: Locale = input.parse()?;
+    locale.langid = CanonicalLanguageIdentifier::from(locale.langid).into_inner();
     Ok(locale.to_string())
 }
+
+/// Canonicalizes the input like `canonicalize`, borrowing the input if it is
+/// already canonical.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use unic_locale_impl::canonicalize_cow;
+///
+/// let canonical = canonicalize_cow("en-US-u-hc-h12")
+///     .expect("Parsing failed.");
+/// assert!(matches!(canonical, Cow::Borrowed("en-US-u-hc-h12")));
+///
+/// let canonical = canonicalize_cow("EN_us-U-HC-h12")
+///     .expect("Parsing failed.");
+/// assert!(matches!(canonical, Cow::Owned(_)));
+/// assert_eq!(canonical, "en-US-u-hc-h12");
+/// ```
+pub fn canonicalize_cow(input: &str) -> Result<Cow<'_, str>, LocaleError> {
+    let mut locale: Locale = input.parse()?;
+    locale.langid = CanonicalLanguageIdentifier::from(locale.langid).into_inner();
+    let mut matcher = StrMatcher(input);
+    if locale.write_subtags(&mut matcher).is_ok() && matcher.0.is_empty() {
+        Ok(Cow::Borrowed(input))
+    } else {
+        Ok(Cow::Owned(locale.to_string()))
+    }
+}
+
+/// A writer which only accepts output equal to the remainder of the string.
+struct StrMatcher<'a>(&'a str);
+
+impl std::fmt::Write for StrMatcher<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(std::fmt::Error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+//! Retrieval of the locales requested by the user in the operating system settings.
+//!
+//! The list is taken from:
+//!
+//!  * Windows - the user preferred UI languages,
+//!  * macOS - the `AppleLanguages` preference,
+//!  * other Unix systems - the `LANGUAGE` colon-separated list,
+//!    followed by `LC_ALL`, `LC_MESSAGES` or `LANG`.
+use crate::Locale;
+
+/// Returns the ordered list of locales requested by the user.
+///
+/// The list is ready to be used for language negotiation.
+/// Entries which cannot be parsed are skipped, and duplicates are removed.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::os::requested_locales;
+///
+/// for locale in requested_locales() {
+///     println!("{}", locale);
+/// }
+/// ```
+pub fn requested_locales() -> Vec<Locale> {
+    let mut result: Vec<Locale> = vec![];
+    for name in system::preferred_languages() {
+        if let Some(locale) = parse_system_locale(&name) {
+            if !result.contains(&locale) {
+                result.push(locale);
+            }
+        }
+    }
+    result
+}
+
+/// Parses a system locale name, such as `en-US` or POSIX `en_US.UTF-8@euro`.
+///
+/// The POSIX codeset is ignored, the `@latin` and `@cyrillic` modifiers
+/// are turned into the script subtag and other modifiers are ignored.
+/// The `C` and `POSIX` locales are treated as no preference.
+fn parse_system_locale(name: &str) -> Option<Locale> {
+    let modifier = name.split_once('@').map(|(_, modifier)| modifier);
+    let name = name.split(['.', '@']).next()?;
+    if name.is_empty() || name == "C" || name == "POSIX" {
+        return None;
+    }
+    let mut locale: Locale = name.parse().ok()?;
+    let script = match modifier {
+        Some("latin") => "Latn",
+        Some("cyrillic") => "Cyrl",
+        _ => return Some(locale),
+    };
+    locale.set_script(Some(script)).ok()?;
+    Some(locale)
+}
+
+#[cfg(windows)]
+mod system {
+    use std::ptr;
+
+    const MUI_LANGUAGE_NAME: u32 = 0x8;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetUserPreferredUILanguages(
+            flags: u32,
+            num_languages: *mut u32,
+            buffer: *mut u16,
+            buffer_size: *mut u32,
+        ) -> i32;
+    }
+
+    pub fn preferred_languages() -> Vec<String> {
+        let mut num_languages = 0;
+        let mut buffer_size = 0;
+        unsafe {
+            if GetUserPreferredUILanguages(
+                MUI_LANGUAGE_NAME,
+                &mut num_languages,
+                ptr::null_mut(),
+                &mut buffer_size,
+            ) == 0
+            {
+                return vec![];
+            }
+            let mut buffer = vec![0u16; buffer_size as usize];
+            if GetUserPreferredUILanguages(
+                MUI_LANGUAGE_NAME,
+                &mut num_languages,
+                buffer.as_mut_ptr(),
+                &mut buffer_size,
+            ) == 0
+            {
+                return vec![];
+            }
+            String::from_utf16_lossy(&buffer)
+                .split('\0')
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod system {
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_void};
+
+    type CFIndex = isize;
+
+    const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFLocaleCopyPreferredLanguages() -> *const c_void;
+        fn CFArrayGetCount(array: *const c_void) -> CFIndex;
+        fn CFArrayGetValueAtIndex(array: *const c_void, idx: CFIndex) -> *const c_void;
+        fn CFStringGetCString(
+            string: *const c_void,
+            buffer: *mut c_char,
+            buffer_size: CFIndex,
+            encoding: u32,
+        ) -> u8;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    pub fn preferred_languages() -> Vec<String> {
+        let mut result = vec![];
+        unsafe {
+            let languages = CFLocaleCopyPreferredLanguages();
+            if languages.is_null() {
+                return result;
+            }
+            for idx in 0..CFArrayGetCount(languages) {
+                let language = CFArrayGetValueAtIndex(languages, idx);
+                let mut buffer = [0 as c_char; 64];
+                if CFStringGetCString(
+                    language,
+                    buffer.as_mut_ptr(),
+                    buffer.len() as CFIndex,
+                    CF_STRING_ENCODING_UTF8,
+                ) != 0
+                {
+                    result.push(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned());
+                }
+            }
+            CFRelease(languages);
+        }
+        result
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod system {
+    use std::env;
+
+    pub fn preferred_languages() -> Vec<String> {
+        let mut result = vec![];
+        if let Ok(languages) = env::var("LANGUAGE") {
+            result.extend(
+                languages
+                    .split(':')
+                    .filter(|s| !s.is_empty())
+                    .map(String::from),
+            );
+        }
+        if let Some(locale) = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+        {
+            result.push(locale);
+        }
+        result
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod system {
+    pub fn preferred_languages() -> Vec<String> {
+        vec![]
+    }
+}
@@ -7,6 +7,16 @@ pub enum ParserError {
     InvalidLanguage,
     InvalidSubtag,
     InvalidExtension,
+    /// A singleton, such as `u`, introduces more than one extension.
+    DuplicateSingleton(char),
+    /// Two separators follow each other, or the input starts with one.
+    EmptySubtag,
+    /// The input ends with a separator.
+    TrailingSeparator,
+    /// A `-u-` keyword, such as `ca-gregorin`, is not defined by CLDR.
+    UnknownKeyword(String),
+    /// A variant, such as `posix`, conflicts with an existing `-u-` keyword, such as `va`.
+    ConflictingKeyword(String),
     LangIdError(LangIdParserError),
 }
 
@@ -16,19 +26,24 @@ impl From<LangIdParserError> for ParserError {
     }
 }
 
-impl Error for ParserError {
-    fn description(&self) -> &str {
-        match &self {
-            ParserError::InvalidLanguage => "The given language subtag is invalid",
-            ParserError::InvalidSubtag => "Invalid subtag",
-            ParserError::InvalidExtension => "Invalid extension",
-            ParserError::LangIdError(_) => "Language Identifier Parser Error",
-        }
-    }
-}
+impl Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(self.description())
+        match self {
+            ParserError::InvalidLanguage => f.write_str("The given language subtag is invalid"),
+            ParserError::InvalidSubtag => f.write_str("Invalid subtag"),
+            ParserError::InvalidExtension => f.write_str("Invalid extension"),
+            ParserError::DuplicateSingleton(singleton) => {
+                write!(f, "Duplicate extension singleton: {}", singleton)
+            }
+            ParserError::EmptySubtag => f.write_str("Empty subtag"),
+            ParserError::TrailingSeparator => f.write_str("The input ends with a separator"),
+            ParserError::UnknownKeyword(keyword) => write!(f, "Unknown keyword: {}", keyword),
+            ParserError::ConflictingKeyword(keyword) => {
+                write!(f, "Variant conflicts with keyword: {}", keyword)
+            }
+            ParserError::LangIdError(_) => f.write_str("Language Identifier Parser Error"),
+        }
     }
 }
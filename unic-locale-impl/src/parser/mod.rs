@@ -1,18 +1,88 @@
 pub mod errors;
 
 pub use self::errors::ParserError;
-use super::extensions::ExtensionsMap;
+use super::extensions::Extensions;
 use super::Locale;
 use unic_langid_impl::LanguageIdentifier;
 
 static SEPARATORS: &[char] = &['-', '_'];
 
+/// Parses a locale string.
+///
+/// Extension fragments, which start with a separator, such as `-u-nu-thai`,
+/// are parsed into a `Locale` with an undetermined language identifier, the
+/// same as `und-u-nu-thai`.
+///
+/// ICU style keywords, such as `en_US@calendar=buddhist;collation=phonebook`,
+/// are converted into `-u-` keywords, the same as `en-US-u-ca-buddhist-co-phonebk`.
 pub fn parse_locale(t: &str) -> Result<Locale, ParserError> {
+    let (t, keywords) = match t.find('@') {
+        Some(idx) => (&t[..idx], Some(&t[idx + 1..])),
+        None => (t, None),
+    };
+    check_empty_subtags(t)?;
     let mut iter = t.split(|c| SEPARATORS.contains(&c)).peekable();
 
-    let langid = LanguageIdentifier::try_from_iter(&mut iter, true)
-        .map_err(|_| ParserError::InvalidLanguage)?;
+    // Only a leading separator marks an extension fragment, so that
+    // a mistyped language such as `e-US` is still rejected.
+    let langid = if iter.peek() == Some(&"") {
+        iter.next();
+        LanguageIdentifier::default()
+    } else {
+        LanguageIdentifier::try_from_iter(&mut iter, true)
+            .map_err(|_| ParserError::InvalidLanguage)?
+    };
 
-    let extensions = ExtensionsMap::try_from_iter(&mut iter)?;
-    Ok(Locale { langid, extensions })
+    let extensions = Extensions::try_from_iter(&mut iter)?;
+    let mut locale = Locale { langid, extensions };
+    if let Some(keywords) = keywords {
+        crate::icu::apply_keywords(&mut locale, keywords)?;
+    }
+    locale.posix_variant_to_keyword()?;
+    #[cfg(feature = "timezone-data")]
+    canonicalize_timezone_keyword(&mut locale)?;
+    Ok(locale)
+}
+
+/// Rejects empty subtags, other than the one before the leading separator
+/// of an extension fragment such as `-u-nu-thai`.
+fn check_empty_subtags(t: &str) -> Result<(), ParserError> {
+    // An empty input is reported as an invalid language.
+    if t.is_empty() {
+        return Ok(());
+    }
+
+    let mut offset = 0;
+    for subtag in t.split(SEPARATORS) {
+        if subtag.is_empty() {
+            if offset == t.len() {
+                return Err(ParserError::TrailingSeparator);
+            }
+            let fragment = offset == 0 && t[1..].split(SEPARATORS).next().map(str::len) == Some(1);
+            if !fragment {
+                return Err(ParserError::EmptySubtag);
+            }
+        }
+        offset += subtag.len() + 1;
+    }
+    Ok(())
+}
+
+/// Validates the `-u-tz-` keyword against the CLDR time zones and replaces
+/// deprecated time zone identifiers.
+#[cfg(feature = "timezone-data")]
+fn canonicalize_timezone_keyword(locale: &mut Locale) -> Result<(), ParserError> {
+    let tz = match locale.extensions.unicode.get_keyword("tz") {
+        Some(tz) => match tz.as_slice() {
+            [tz] => crate::data::timezone::canonicalize_timezone(tz)
+                .ok_or(ParserError::InvalidExtension)?,
+            _ => return Err(ParserError::InvalidExtension),
+        },
+        None => return Ok(()),
+    };
+    locale
+        .extensions
+        .unicode
+        .set_keyword("tz", vec![tz])
+        .map_err(|_| ParserError::InvalidExtension)
 }
@@ -0,0 +1,82 @@
+use crate::Locale;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+const FIELDS: &[&str] = &["language", "script", "region", "variants", "extensions"];
+
+/// Serializes the `Locale` as a string, such as `"en-US-u-ca-buddhist"`.
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes the `Locale` from either a string, such as `"en-US-u-ca-buddhist"`,
+/// or a map with optional `language`, `script`, `region`, `variants` and `extensions` fields,
+/// such as `{ "language": "en", "region": "US", "extensions": "u-ca-buddhist" }`.
+///
+/// Formats which are not human readable, such as `bincode`, are not self-describing,
+/// so only the string form is accepted from them.
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(LocaleVisitor)
+        } else {
+            deserializer.deserialize_str(LocaleVisitor)
+        }
+    }
+}
+
+struct LocaleVisitor;
+
+impl<'de> Visitor<'de> for LocaleVisitor {
+    type Value = Locale;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a locale string or a map of locale parts")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut language: Option<String> = None;
+        let mut script: Option<String> = None;
+        let mut region: Option<String> = None;
+        let mut variants: Option<Vec<String>> = None;
+        let mut extensions: Option<String> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "language" => language = map.next_value()?,
+                "script" => script = map.next_value()?,
+                "region" => region = map.next_value()?,
+                "variants" => variants = map.next_value()?,
+                "extensions" => extensions = map.next_value()?,
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+
+        let variants = variants.unwrap_or_default();
+        let variants: Vec<&str> = variants.iter().map(String::as_str).collect();
+        let extensions = match extensions {
+            Some(ext) => ext
+                .trim_start_matches(['-', '_'])
+                .parse()
+                .map_err(de::Error::custom)?,
+            None => Default::default(),
+        };
+
+        Locale::from_parts(
+            language.as_deref(),
+            script.as_deref(),
+            region.as_deref(),
+            &variants,
+            extensions,
+        )
+        .map_err(de::Error::custom)
+    }
+}
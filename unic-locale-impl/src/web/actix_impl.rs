@@ -0,0 +1,30 @@
+use super::{PreferredLocale, SupportedLocales};
+
+use actix_web::dev::Payload;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::web::Data;
+use actix_web::{Error, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+
+impl FromRequest for PreferredLocale {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let supported = match req.app_data::<Data<SupportedLocales>>() {
+            Some(supported) => supported,
+            None => {
+                return ready(Err(ErrorInternalServerError(
+                    "SupportedLocales app data is missing",
+                )))
+            }
+        };
+        let header = req
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("");
+        ready(Ok(PreferredLocale(supported.resolve(header))))
+    }
+}
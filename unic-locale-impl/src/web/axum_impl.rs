@@ -0,0 +1,23 @@
+use super::{PreferredLocale, SupportedLocales};
+
+use axum::extract::FromRequestParts;
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+
+impl<S: Send + Sync> FromRequestParts<S> for PreferredLocale {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let supported = parts.extensions.get::<SupportedLocales>().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "SupportedLocales extension is missing",
+        ))?;
+        let header = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("");
+        Ok(PreferredLocale(supported.resolve(header)))
+    }
+}
@@ -0,0 +1,311 @@
+//! HTTP content negotiation based on the `Accept-Language` header.
+//!
+//! The header is parsed as defined in RFC 7231, section 5.3.5, and the
+//! requested locales are matched against the available ones in order of
+//! their quality values.
+//!
+//! With `feature = "axum"` or `feature = "actix-web"`, `PreferredLocale` can be
+//! used as an extractor which resolves the locale of the request against the
+//! `SupportedLocales` configured by the application.
+#[cfg(feature = "actix-web")]
+mod actix_impl;
+#[cfg(feature = "axum")]
+mod axum_impl;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::Locale;
+
+/// A single entry of the `Accept-Language` header.
+///
+/// `None` stands for the `*` wildcard. The quality value is kept in thousandths.
+type Entry = (Option<Locale>, u16);
+
+fn parse_quality(value: &str) -> Option<u16> {
+    let value: f32 = value.trim().parse().ok()?;
+    if !(0.0..=1.0).contains(&value) {
+        return None;
+    }
+    Some((value * 1000.0).round() as u16)
+}
+
+fn parse_entry(entry: &str) -> Option<Entry> {
+    let mut parts = entry.split(';');
+    let range = parts.next()?.trim();
+    let mut quality = 1000;
+    for param in parts {
+        let mut param = param.splitn(2, '=');
+        let name = param.next()?.trim();
+        if name.eq_ignore_ascii_case("q") {
+            quality = parse_quality(param.next()?)?;
+        }
+    }
+    match range {
+        "" => None,
+        "*" => Some((None, quality)),
+        range => Some((Some(range.parse().ok()?), quality)),
+    }
+}
+
+/// Parses the entries of the header, ordered by their quality values.
+fn parse_entries(header: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = header.split(',').filter_map(parse_entry).collect();
+    // The sort is stable, so entries with the same quality keep the order of the header.
+    entries.sort_by(|(_, q1), (_, q2)| q2.cmp(q1));
+    entries
+}
+
+/// Parses the value of an `Accept-Language` header into a list of locales,
+/// ordered by their quality values.
+///
+/// Entries which cannot be parsed, the `*` wildcard and locales with
+/// a quality value of `0`, which marks them as not acceptable, are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::web::parse_accept_language;
+///
+/// let locales = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+/// let locales: Vec<String> = locales.iter().map(ToString::to_string).collect();
+///
+/// assert_eq!(locales, vec!["fr-CH", "fr", "en", "de"]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<Locale> {
+    parse_entries(header)
+        .into_iter()
+        .filter(|(_, quality)| *quality > 0)
+        .filter_map(|(locale, _)| locale)
+        .collect()
+}
+
+/// Returns the best available locale for the value of an `Accept-Language` header.
+///
+/// The requested locales are tried in order of their quality values. For each
+/// of them the available locales are searched for:
+///
+///  * an exact match,
+///  * a more generic locale, such as `en` for `en-US`,
+///  * a more specific locale, such as `en-US` for `en`,
+///  * a locale with the same language, such as `en-GB` for `en-US`.
+///
+/// The `*` wildcard matches the first available locale. Available locales
+/// matched by a requested locale with a quality value of `0` are never returned.
+/// If nothing matches, `default` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::web::resolve_locale;
+///
+/// let available: Vec<Locale> = vec!["en-US".parse().unwrap(), "de".parse().unwrap()];
+/// let default: Locale = "en-US".parse().unwrap();
+///
+/// let locale = resolve_locale("de-AT, en;q=0.5", &available, &default);
+/// assert_eq!(locale.to_string(), "de");
+///
+/// let locale = resolve_locale("pl", &available, &default);
+/// assert_eq!(locale.to_string(), "en-US");
+/// ```
+pub fn resolve_locale(header: &str, available: &[Locale], default: &Locale) -> Locale {
+    match negotiate(header, available) {
+        Some(idx) => available[idx].clone(),
+        None => default.clone(),
+    }
+}
+
+/// Returns the index of the best available locale, or `None` if nothing matches.
+fn negotiate(header: &str, available: &[Locale]) -> Option<usize> {
+    let entries = parse_entries(header);
+    let is_excluded = |locale: &Locale| {
+        entries.iter().any(|(requested, quality)| {
+            *quality == 0
+                && requested
+                    .as_ref()
+                    .map(|r| r.langid.matches(&locale.langid, true, false))
+                    == Some(true)
+        })
+    };
+    let acceptable: Vec<&Locale> = available.iter().filter(|l| !is_excluded(l)).collect();
+
+    for (requested, quality) in &entries {
+        if *quality == 0 {
+            continue;
+        }
+        let found = match requested {
+            Some(requested) => find_match(requested, &acceptable),
+            None => acceptable.first().copied(),
+        };
+        if let Some(locale) = found {
+            return available.iter().position(|l| std::ptr::eq(l, locale));
+        }
+    }
+    None
+}
+
+fn find_match<'a>(requested: &Locale, available: &[&'a Locale]) -> Option<&'a Locale> {
+    let requested = &requested.langid;
+    let iter = available.iter().copied();
+    iter.clone()
+        .find(|a| a.langid == *requested)
+        .or_else(|| {
+            iter.clone()
+                .find(|a| a.langid.matches(requested, true, false))
+        })
+        .or_else(|| {
+            iter.clone()
+                .find(|a| requested.matches(&a.langid, true, false))
+        })
+        .or_else(|| {
+            iter.clone()
+                .find(|a| a.get_language() == requested.get_language())
+        })
+}
+
+/// A cache of the results of `resolve_locale`.
+///
+/// Servers tend to receive the same handful of `Accept-Language` headers over and over,
+/// so the cache memoizes the resolution for each pair of a header and a list of
+/// available locales. The cache holds at most `capacity` results and is emptied
+/// once it is full.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::web::NegotiationCache;
+///
+/// let available: Vec<Locale> = vec!["en-US".parse().unwrap(), "de".parse().unwrap()];
+/// let default: Locale = "en-US".parse().unwrap();
+///
+/// let cache = NegotiationCache::new(100);
+///
+/// let locale = cache.resolve("de-AT, en;q=0.5", &available, &default);
+/// assert_eq!(locale.to_string(), "de");
+/// assert_eq!(cache.len(), 1);
+///
+/// let locale = cache.resolve("de-AT, en;q=0.5", &available, &default);
+/// assert_eq!(locale.to_string(), "de");
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct NegotiationCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+/// The results for each header, as the index of the resolved locale
+/// in the list of available locales, or `None` for the default.
+type Results = HashMap<String, Option<usize>>;
+
+/// The cached results grouped by the list of available locales they were resolved against.
+#[derive(Debug, Default)]
+struct CacheEntries {
+    len: usize,
+    sets: Vec<(Vec<Locale>, Results)>,
+}
+
+impl NegotiationCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Returns the best available locale for the value of an `Accept-Language` header,
+    /// reusing the result of a previous call with the same arguments if there is one.
+    ///
+    /// See `resolve_locale` for details.
+    pub fn resolve(&self, header: &str, available: &[Locale], default: &Locale) -> Locale {
+        let result = match self.entries.lock() {
+            Ok(mut entries) => entries.get_or_insert(header, available, self.capacity),
+            Err(_) => negotiate(header, available),
+        };
+        match result {
+            Some(idx) => available[idx].clone(),
+            None => default.clone(),
+        }
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            *entries = CacheEntries::default();
+        }
+    }
+}
+
+impl CacheEntries {
+    fn get_or_insert(
+        &mut self,
+        header: &str,
+        available: &[Locale],
+        capacity: usize,
+    ) -> Option<usize> {
+        if let Some((_, results)) = self.sets.iter().find(|(set, _)| set == available) {
+            if let Some(result) = results.get(header) {
+                return *result;
+            }
+        }
+
+        let result = negotiate(header, available);
+        if capacity == 0 {
+            return result;
+        }
+        if self.len >= capacity {
+            self.len = 0;
+            self.sets.clear();
+        }
+        let idx = match self.sets.iter().position(|(set, _)| set == available) {
+            Some(idx) => idx,
+            None => {
+                self.sets.push((available.to_vec(), HashMap::new()));
+                self.sets.len() - 1
+            }
+        };
+        self.sets[idx].1.insert(header.to_string(), result);
+        self.len += 1;
+        result
+    }
+}
+
+/// The locales supported by an application, used by the `PreferredLocale` extractor.
+#[derive(Debug, Clone)]
+pub struct SupportedLocales {
+    available: Vec<Locale>,
+    default: Locale,
+}
+
+impl SupportedLocales {
+    pub fn new(available: Vec<Locale>, default: Locale) -> Self {
+        Self { available, default }
+    }
+
+    /// Returns the best supported locale for the value of an `Accept-Language` header.
+    ///
+    /// See `resolve_locale` for details.
+    pub fn resolve(&self, header: &str) -> Locale {
+        resolve_locale(header, &self.available, &self.default)
+    }
+}
+
+/// The locale of a request, resolved from its `Accept-Language` header
+/// against the `SupportedLocales` of the application.
+///
+/// With `feature = "axum"` the `SupportedLocales` are taken from the request
+/// extensions, for example added with `axum::Extension`. With `feature = "actix-web"`
+/// they are taken from the application data, added with `App::app_data(web::Data::new(...))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreferredLocale(pub Locale);
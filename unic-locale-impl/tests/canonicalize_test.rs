@@ -21,3 +21,32 @@ fn test_canonicalize_locale() {
         "en-US-u-ca-buddhist-t-es-AR-x-foo",
     );
 }
+
+#[test]
+fn test_canonicalize_iso639() {
+    assert_canonicalize("fre-CA-u-ca-gregory", "fr-CA-u-ca-gregory");
+}
+
+#[test]
+fn test_canonicalize_cow() {
+    use std::borrow::Cow;
+    use unic_locale_impl::canonicalize_cow;
+
+    for input in &[
+        "en-US",
+        "en-US-u-hc-h12",
+        "und-t-es-h0-hybrid",
+        "pl-x-testing",
+    ] {
+        assert!(
+            matches!(canonicalize_cow(input).unwrap(), Cow::Borrowed(s) if s == *input),
+            "{}",
+            input
+        );
+    }
+    for input in &["EN_us-u-hc-h12", "ger-DE-u-ca-buddhist", "en-x-b-a"] {
+        let result = canonicalize_cow(input).unwrap();
+        assert!(matches!(result, Cow::Owned(_)), "{}", input);
+        assert_eq!(result, canonicalize(input).unwrap());
+    }
+}
@@ -0,0 +1,87 @@
+//! The data tables are looked up with `binary_search`, so they must stay sorted.
+
+#[allow(dead_code)]
+fn assert_sorted<T: Ord + std::fmt::Debug>(name: &str, keys: &[T]) {
+    for pair in keys.windows(2) {
+        assert!(pair[0] < pair[1], "{} is not sorted at {:?}", name, pair);
+    }
+}
+
+#[cfg(feature = "currency-data")]
+#[test]
+fn currency_table_is_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/currency.rs"]
+    mod currency;
+
+    let keys: Vec<_> = currency::REGION_CURRENCIES.iter().map(|(r, _)| r).collect();
+    assert_sorted("REGION_CURRENCIES", &keys);
+}
+
+#[cfg(feature = "keyword-data")]
+#[test]
+fn keyword_table_is_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/keyword.rs"]
+    mod keyword;
+
+    let keys: Vec<_> = keyword::KEYWORDS.iter().map(|(k, _)| k).collect();
+    assert_sorted("KEYWORDS", &keys);
+    for (key, values) in keyword::KEYWORDS.iter() {
+        if let Some(values) = values {
+            assert_sorted(key, values);
+        }
+    }
+}
+
+#[cfg(feature = "subdivision-data")]
+#[test]
+fn subdivision_table_is_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/subdivision.rs"]
+    mod subdivision;
+
+    assert_sorted("SUBDIVISIONS", &subdivision::SUBDIVISIONS);
+}
+
+#[cfg(feature = "time-data")]
+#[test]
+fn time_table_is_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/time.rs"]
+    mod time;
+
+    assert_sorted("HOUR_CYCLE_H12", &time::HOUR_CYCLE_H12);
+}
+
+#[cfg(feature = "timezone-data")]
+#[test]
+fn timezone_tables_are_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/timezone.rs"]
+    mod timezone;
+
+    let keys: Vec<_> = timezone::TIMEZONES.iter().map(|(id, _)| id).collect();
+    assert_sorted("TIMEZONES", &keys);
+    let keys: Vec<_> = timezone::TIMEZONE_ALIASES
+        .iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_sorted("TIMEZONE_ALIASES", &keys);
+    let keys: Vec<_> = timezone::IANA_ALIASES
+        .iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_sorted("IANA_ALIASES", &keys);
+}
+
+#[cfg(feature = "week-data")]
+#[test]
+fn week_table_is_sorted() {
+    #[allow(dead_code)]
+    #[path = "../src/data/week.rs"]
+    mod week;
+
+    let keys: Vec<_> = week::FIRST_DAYS.iter().map(|(r, _)| r).collect();
+    assert_sorted("FIRST_DAYS", &keys);
+}
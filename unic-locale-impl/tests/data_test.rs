@@ -0,0 +1,186 @@
+#[cfg(feature = "numbering-data")]
+#[test]
+fn test_default_numbering_system() {
+    use unic_locale_impl::Locale;
+
+    let assert_nu = |input: &str, nu: &str| {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.default_numbering_system(), nu, "{}", input);
+    };
+    assert_nu("en-US", "latn");
+    assert_nu("und", "latn");
+    assert_nu("ar", "arab");
+    assert_nu("ar-EG", "arab");
+    assert_nu("ar-MA", "latn");
+    assert_nu("fa", "arabext");
+    assert_nu("fa-IR", "arabext");
+    assert_nu("ur", "latn");
+    assert_nu("ur-IN", "arabext");
+    assert_nu("pa", "latn");
+    assert_nu("pa-Arab-PK", "arabext");
+    assert_nu("ar-EG-u-nu-latn", "latn");
+    assert_nu("en-u-nu-thai", "thai");
+}
+
+#[cfg(feature = "time-data")]
+#[test]
+fn test_default_hour_cycle() {
+    use unic_locale_impl::Locale;
+
+    let assert_hc = |input: &str, hc: &str| {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.default_hour_cycle(), hc, "{}", input);
+    };
+    assert_hc("en-US", "h12");
+    assert_hc("en-GB", "h23");
+    assert_hc("de-DE", "h23");
+    assert_hc("ko-KR", "h12");
+    assert_hc("hi-IN", "h12");
+    assert_hc("ja-JP", "h23");
+    assert_hc("en-US-u-hc-h23", "h23");
+    assert_hc("de-u-hc-h12", "h12");
+    assert_hc("en-GB-u-rg-uszzzz", "h12");
+    assert_hc("en-US-u-hc-h23-rg-uszzzz", "h23");
+
+    #[cfg(feature = "likelysubtags-add")]
+    assert_hc("en", "h12");
+    #[cfg(not(feature = "likelysubtags-add"))]
+    assert_hc("en", "h23");
+}
+
+#[cfg(feature = "currency-data")]
+#[test]
+fn test_currency() {
+    use unic_locale_impl::{region_currency, Locale};
+
+    assert_eq!(region_currency("JP"), Some("JPY"));
+    assert_eq!(region_currency("US"), Some("USD"));
+    assert_eq!(region_currency("gb"), Some("GBP"));
+    assert_eq!(region_currency("HR"), Some("EUR"));
+    assert_eq!(region_currency("SL"), Some("SLE"));
+    assert_eq!(region_currency("ZZ"), None);
+    assert_eq!(region_currency("419"), None);
+
+    let assert_currency = |input: &str, currency| {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.likely_currency(), currency, "{}", input);
+    };
+    assert_currency("en-US", Some("USD"));
+    assert_currency("en", Some("USD"));
+    assert_currency("de-CH", Some("CHF"));
+    assert_currency("de", Some("EUR"));
+    assert_currency("pt", Some("BRL"));
+    assert_currency("es-419", None);
+    assert_currency("en-US-u-rg-gbzzzz", Some("GBP"));
+}
+
+#[cfg(feature = "week-data")]
+#[test]
+fn test_first_day_of_week() {
+    use unic_locale_impl::Locale;
+
+    let assert_fw = |input: &str, fw: &str| {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.first_day_of_week(), fw, "{}", input);
+    };
+    assert_fw("en-US", "sun");
+    assert_fw("en-GB", "mon");
+    assert_fw("ar-EG", "sat");
+    assert_fw("dv-MV", "fri");
+    assert_fw("und", "mon");
+    assert_fw("en-US-u-fw-mon", "mon");
+
+    #[cfg(feature = "likelysubtags-add")]
+    assert_fw("ja", "sun");
+    #[cfg(not(feature = "likelysubtags-add"))]
+    assert_fw("ja", "mon");
+}
+
+#[cfg(feature = "timezone-data")]
+#[test]
+fn test_timezone() {
+    use unic_locale_impl::{timezone_from_iana, timezone_to_iana, Locale};
+
+    assert_eq!(timezone_to_iana("uslax"), Some("America/Los_Angeles"));
+    assert_eq!(timezone_to_iana("USLAX"), Some("America/Los_Angeles"));
+    assert_eq!(timezone_to_iana("utc"), Some("Etc/UTC"));
+    assert_eq!(timezone_to_iana("usnavajo"), Some("America/Denver"));
+    assert_eq!(timezone_to_iana("xxxxx"), None);
+
+    assert_eq!(timezone_from_iana("Europe/Berlin"), Some("deber"));
+    assert_eq!(timezone_from_iana("US/Pacific"), Some("uslax"));
+    assert_eq!(timezone_from_iana("America/Boise"), Some("usboi"));
+    assert_eq!(timezone_from_iana("Asia/Calcutta"), Some("inccu"));
+    assert_eq!(timezone_from_iana("Europe/Atlantis"), None);
+
+    let loc: Locale = "en-US-u-tz-usnyc".parse().unwrap();
+    assert_eq!(loc.get_timezone(), Some("America/New_York"));
+
+    let loc: Locale = "zh-CN-u-tz-cnckg".parse().unwrap();
+    assert_eq!(loc.to_string(), "zh-CN-u-tz-cnsha");
+    assert_eq!(loc.get_timezone(), Some("Asia/Shanghai"));
+
+    let loc: Locale = "fr-CA-u-tz-camtr".parse().unwrap();
+    assert_eq!(loc.to_string(), "fr-CA-u-tz-cator");
+
+    for tz in &["usboi", "usind", "usjnu"] {
+        let loc: Locale = format!("en-US-u-tz-{}", tz).parse().unwrap();
+        assert!(loc.get_timezone().is_some(), "{}", tz);
+    }
+
+    let loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.get_timezone(), None);
+
+    assert!("en-US-u-tz-xxxxx".parse::<Locale>().is_err());
+    assert!("en-US-u-tz-uslax-usnyc".parse::<Locale>().is_err());
+
+    let loc: Locale = "en_US@timezone=America/Los_Angeles".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-tz-uslax");
+    assert!("en_US@timezone=Europe/Atlantis".parse::<Locale>().is_err());
+}
+
+#[cfg(feature = "timezone-data")]
+#[test]
+fn test_timezone_cldr_ids() {
+    use std::collections::BTreeMap;
+    use std::fs::File;
+    use unic_locale_impl::{timezone_from_iana, timezone_to_iana, Locale};
+
+    let file = File::open("./tests/fixtures/timezones.json").unwrap();
+    let timezones: BTreeMap<String, String> = serde_json::from_reader(file).unwrap();
+
+    for (tz, name) in &timezones {
+        let loc: Locale = format!("und-u-tz-{}", tz)
+            .parse()
+            .unwrap_or_else(|_| panic!("Failed to parse time zone: {}", tz));
+        assert_eq!(loc.get_timezone(), Some(name.as_str()), "{}", tz);
+        assert_eq!(timezone_to_iana(tz), Some(name.as_str()), "{}", tz);
+
+        let canonical = loc.extensions.unicode.get_keyword("tz").unwrap();
+        assert_eq!(timezone_from_iana(name), canonical.first().copied(), "{}", tz);
+    }
+}
+
+#[test]
+fn test_data_versions() {
+    use unic_locale_impl::data_versions;
+
+    let versions = data_versions();
+    assert_eq!(versions.langid, unic_langid_impl::data_versions());
+    #[cfg(feature = "numbering-data")]
+    assert_eq!(versions.numbering, Some("44"));
+    #[cfg(not(feature = "numbering-data"))]
+    assert_eq!(versions.numbering, None);
+    #[cfg(feature = "currency-data")]
+    assert_eq!(versions.currency, Some("44"));
+    #[cfg(not(feature = "currency-data"))]
+    assert_eq!(versions.currency, None);
+    #[cfg(feature = "timezone-data")]
+    assert_eq!(versions.timezone, Some("42"));
+    #[cfg(not(feature = "timezone-data"))]
+    assert_eq!(versions.timezone, None);
+    #[cfg(feature = "subdivision-data")]
+    assert_eq!(versions.subdivision, Some("42"));
+    #[cfg(not(feature = "subdivision-data"))]
+    assert_eq!(versions.subdivision, None);
+}
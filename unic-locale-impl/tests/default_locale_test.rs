@@ -0,0 +1,20 @@
+#![cfg(feature = "default-locale")]
+
+use std::thread;
+
+use unic_locale_impl::{default_locale, set_default_locale, Locale};
+
+#[test]
+fn test_default_locale() {
+    assert_eq!(default_locale(), None);
+
+    let loc: Locale = "fr-CA-u-hc-h23".parse().unwrap();
+    set_default_locale(loc.clone()).unwrap();
+
+    let handle = thread::spawn(|| default_locale().map(|loc| loc.to_string()));
+    assert_eq!(handle.join().unwrap(), Some("fr-CA-u-hc-h23".to_string()));
+
+    let other: Locale = "en-US".parse().unwrap();
+    assert_eq!(set_default_locale(other.clone()), Err(other));
+    assert_eq!(default_locale(), Some(&loc));
+}
@@ -4,7 +4,7 @@ use std::fs::File;
 use std::path::Path;
 
 use unic_langid_impl::LanguageIdentifier;
-use unic_locale_impl::{ExtensionType, ExtensionsMap, Locale};
+use unic_locale_impl::{ExtensionType, Extensions, Locale};
 
 use serde::{Deserialize, Serialize};
 
@@ -43,8 +43,8 @@ fn read_locale_testsets<P: AsRef<Path>>(path: P) -> Result<Vec<LocaleTestSet>, B
     Ok(sets)
 }
 
-fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> ExtensionsMap {
-    let mut result = ExtensionsMap::default();
+fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> Extensions {
+    let mut result = Extensions::default();
     for (key, map) in map {
         let t: ExtensionType = ExtensionType::from_char(key.chars().nth(0).unwrap())
             .expect("Failed to format extension type.");
@@ -112,7 +112,7 @@ fn test_locale_fixtures(path: &str) {
                         .map(|s| s.as_str())
                         .collect::<Vec<_>>()
                         .as_ref(),
-                    o.extensions.map(create_extensions_map),
+                    o.extensions.map(create_extensions_map).unwrap_or_default(),
                 )
                 .expect("Parsing failed.");
                 assert_eq!(locale, expected);
@@ -0,0 +1,32 @@
+#![cfg(feature = "icu_locid")]
+
+use std::convert::TryFrom;
+
+use unic_locale_impl::Locale;
+
+#[test]
+fn locale_to_icu_test() {
+    for input in &[
+        "en-US",
+        "und",
+        "de-CH-1996-u-co-phonebk",
+        "en-u-ca-buddhist-hc-h12-t-ja-x-foo",
+        "zh-Hant-TW-a-bar-x-private",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        let icu = icu_locid::Locale::try_from(&loc).unwrap();
+        assert_eq!(icu, input.parse::<icu_locid::Locale>().unwrap());
+        assert_eq!(Locale::try_from(&icu).unwrap(), loc);
+        assert_eq!(Locale::try_from(icu).unwrap(), loc);
+    }
+
+    let loc: Locale = "abcdef-u-ca-buddhist".parse().unwrap();
+    assert!(icu_locid::Locale::try_from(loc).is_err());
+}
+
+#[test]
+fn icu_to_locale_test() {
+    let icu: icu_locid::Locale = "EN_us-u-HC-h12".parse().unwrap();
+    let loc = Locale::try_from(&icu).unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+}
@@ -0,0 +1,88 @@
+use unic_locale_impl::LanguageTag;
+
+fn assert_roundtrip(input: &str, expected: &str) {
+    let tag: LanguageTag = input.parse().unwrap();
+    assert_eq!(tag.to_string(), expected);
+    let tag2: LanguageTag = expected.parse().unwrap();
+    assert_eq!(tag, tag2);
+}
+
+#[test]
+fn test_parse() {
+    assert_roundtrip("en", "en");
+    assert_roundtrip("EN-us", "en-US");
+    assert_roundtrip("zh-cmn-hans-cn", "zh-cmn-Hans-CN");
+    assert_roundtrip("sl-rozaj-biske", "sl-rozaj-biske");
+    assert_roundtrip("de-CH-1996", "de-CH-1996");
+    assert_roundtrip("es-419", "es-419");
+    assert_roundtrip("en-a-bbb-x-a-ccc", "en-a-bbb-x-a-ccc");
+    assert_roundtrip("en-US-u-islamcal", "en-US-u-islamcal");
+    assert_roundtrip("x-whatever", "x-whatever");
+    assert_roundtrip("qaa-Qaaa-QM-x-southern", "qaa-Qaaa-QM-x-southern");
+    assert_roundtrip("I-KLINGON", "i-klingon");
+    assert_roundtrip("sgn-be-fr", "sgn-BE-FR");
+
+    let tag: LanguageTag = "zh-yue-HK-b-foo-a-bar-baz".parse().unwrap();
+    assert_eq!(tag.get_language(), Some("zh"));
+    assert_eq!(tag.get_extlangs(), vec!["yue"]);
+    assert_eq!(tag.get_region(), Some("HK"));
+    assert_eq!(tag.get_extension('a'), Some(vec!["bar", "baz"]));
+    assert_eq!(tag.get_extension('B'), Some(vec!["foo"]));
+    assert_eq!(tag.get_extension('c'), None);
+    assert_eq!(tag.to_string(), "zh-yue-HK-b-foo-a-bar-baz");
+
+    let tag: LanguageTag = "de-nedis-1996".parse().unwrap();
+    assert_eq!(tag.get_variants(), vec!["nedis", "1996"]);
+
+    let tag: LanguageTag = "i-default".parse().unwrap();
+    assert!(tag.is_grandfathered());
+    assert_eq!(tag.get_language(), None);
+}
+
+#[test]
+fn test_parse_invalid() {
+    for input in &[
+        "",
+        "de-419-DE",
+        "a-DE",
+        "ar-a-aaa-b-bbb-a-ccc",
+        "de-DE-1901-1901",
+        "en-x",
+        "en-a",
+        "en--US",
+        "abcdefghi",
+        "en-US-é",
+    ] {
+        assert!(input.parse::<LanguageTag>().is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_to_language_identifier() {
+    let assert_langid = |input: &str, expected: &str| {
+        let tag: LanguageTag = input.parse().unwrap();
+        assert_eq!(tag.to_language_identifier().unwrap().to_string(), expected);
+    };
+    assert_langid("en-US-a-foo-x-bar", "en-US");
+    assert_langid("zh-yue-Hant-HK", "yue-Hant-HK");
+    assert_langid("i-klingon", "tlh");
+    assert_langid("en-GB-oed", "en-GB-oxendict");
+    assert_langid("de-nedis-1996", "de-1996-nedis");
+
+    for input in &["x-private", "i-default", "zh-min"] {
+        let tag: LanguageTag = input.parse().unwrap();
+        assert!(tag.to_language_identifier().is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_to_locale() {
+    let tag: LanguageTag = "en-US-u-ca-buddhist-a-foo-bar-x-private".parse().unwrap();
+    let locale = tag.to_locale().unwrap();
+    assert_eq!(locale.to_string(), "en-US-u-ca-buddhist-a-foo-bar-x-private");
+    assert_eq!(locale.extensions.other.len(), 1);
+
+    let tag: LanguageTag = "und-t-h0-hybrid".parse().unwrap();
+    let locale = tag.to_locale().unwrap();
+    assert_eq!(locale.to_string(), "und-t-h0-hybrid");
+}
@@ -1,14 +1,17 @@
-use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::{LanguageIdentifier, MatchQuality};
+use unic_locale_impl::extensions::{CollationType, Subdivision, TransformMechanism};
 use unic_locale_impl::parser::parse_locale;
-use unic_locale_impl::{CharacterDirection, ExtensionsMap, Locale};
+#[cfg(feature = "layout")]
+use unic_locale_impl::CharacterDirection;
+use unic_locale_impl::{ExtensionType, Extensions, Locale};
 
 use tinystr::{TinyStr4, TinyStr8};
 
-fn assert_locale_extensions(loc: &Locale, extensions: &ExtensionsMap) {
+fn assert_locale_extensions(loc: &Locale, extensions: &Extensions) {
     assert_eq!(&loc.extensions, extensions);
 }
 
-fn assert_parsed_locale_identifier(input: &str, extensions: &ExtensionsMap) {
+fn assert_parsed_locale_identifier(input: &str, extensions: &Extensions) {
     let loc = parse_locale(input).unwrap();
     assert_locale_extensions(&loc, extensions);
 }
@@ -18,29 +21,29 @@ fn test_basic() {
     let loc: Locale = "en-US".parse().unwrap();
     let loc2 = Locale {
         langid: LanguageIdentifier::from_parts(Some("en"), None, Some("US"), &[]).unwrap(),
-        extensions: ExtensionsMap::default(),
+        extensions: Extensions::default(),
     };
     assert_eq!(loc, loc2);
 }
 
 #[test]
 fn test_from_parts() {
-    let extensions = ExtensionsMap::default();
-    let loc = Locale::from_parts(Some("en"), None, None, &[], Some(extensions)).unwrap();
+    let extensions = Extensions::default();
+    let loc = Locale::from_parts(Some("en"), None, None, &[], extensions).unwrap();
     let loc2 = Locale {
         langid: LanguageIdentifier::from_parts(Some("en"), None, None, &[]).unwrap(),
-        extensions: ExtensionsMap::default(),
+        extensions: Extensions::default(),
     };
     assert_eq!(loc, loc2);
 }
 
 #[test]
 fn test_locale_identifier() {
-    let mut extensions = ExtensionsMap::default();
+    let mut extensions = Extensions::default();
     extensions.unicode.set_keyword("hc", vec!["h12"]).unwrap();
     assert_parsed_locale_identifier("pl-u-hc-h12", &extensions);
 
-    let mut extensions = ExtensionsMap::default();
+    let mut extensions = Extensions::default();
     extensions.private.add_tag("testing").unwrap();
     assert_parsed_locale_identifier("und-x-testing", &extensions);
 }
@@ -81,6 +84,34 @@ fn test_from_parts_unchecked() {
     assert_eq!(&loc.to_string(), "en-US");
 }
 
+#[test]
+fn test_into_tinystr_parts() {
+    let loc: Locale = "sr-Cyrl-RS-ekavsk-u-hc-h12".parse().unwrap();
+    let (lang, script, region, variants, extensions) = loc.clone().into_tinystr_parts();
+    assert_eq!(lang, "sr".parse().ok());
+    assert_eq!(extensions.to_string(), "-u-hc-h12");
+    let loc2 =
+        unsafe { Locale::from_raw_parts_unchecked(lang, script, region, variants, extensions) };
+    assert_eq!(loc2, loc);
+}
+
+#[test]
+fn test_raw_parts_v2() {
+    for input in &[
+        "und",
+        "en-US",
+        "sr-Cyrl-RS-1996-ekavsk",
+        "de-u-attr-co-phonebk-ka-shifted",
+        "ja-Kana-t-it-h0-hybrid-m0-ungegn",
+        "und-t-en-a-bar-b-foo-baz-x-a-b",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        let loc2 =
+            loc.with_raw_parts_v2(|raw| unsafe { Locale::from_raw_parts_v2_unchecked(&raw) });
+        assert_eq!(loc2, loc);
+    }
+}
+
 #[test]
 fn test_matches() {
     let loc_en: Locale = "en-u-hc-h12".parse().unwrap();
@@ -150,6 +181,7 @@ fn test_likelysubtags() {
     assert_eq!(loc_zh_hant.to_string(), "zh-TW-u-hc-h12");
 }
 
+#[cfg(feature = "layout")]
 #[test]
 fn test_get_character_direction() {
     let loc_en: Locale = "en-u-hc-h12".parse().unwrap();
@@ -158,3 +190,735 @@ fn test_get_character_direction() {
     let loc_ar: Locale = "ar-AF-u-hc-h12".parse().unwrap();
     assert_eq!(loc_ar.get_character_direction(), CharacterDirection::RTL);
 }
+
+#[test]
+fn test_posix_variant() {
+    use unic_locale_impl::errors::LocaleError;
+    use unic_locale_impl::parser::ParserError;
+
+    let mut loc: Locale = "en-US-posix".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-va-posix");
+    assert_eq!(loc.get_variants(), Vec::<&str>::new());
+    assert_eq!(
+        loc.extensions.unicode.get_keyword("va"),
+        Some(vec!["posix"])
+    );
+
+    assert_eq!(loc.keyword_to_posix_variant(), true);
+    assert_eq!(loc.to_string(), "en-US-posix");
+    assert_eq!(loc.keyword_to_posix_variant(), false);
+
+    assert_eq!(loc.posix_variant_to_keyword().unwrap(), true);
+    assert_eq!(loc.to_string(), "en-US-u-va-posix");
+    assert_eq!(loc.posix_variant_to_keyword().unwrap(), false);
+
+    let loc: Locale = "de-POSIX-nedis-u-hc-h12".parse().unwrap();
+    assert_eq!(loc.to_string(), "de-nedis-u-hc-h12-va-posix");
+
+    let mut loc: Locale = "en-u-va-foobar".parse().unwrap();
+    assert_eq!(loc.keyword_to_posix_variant(), false);
+
+    let loc: Locale = "en-posix-u-va-posix".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-va-posix");
+
+    match "en-posix-u-va-foo".parse::<Locale>() {
+        Err(LocaleError::ParserError(ParserError::ConflictingKeyword(keyword))) => {
+            assert_eq!(keyword, "va")
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_merge() {
+    let assert_merge = |input: &str, other: &str, expected: &str| {
+        let mut loc: Locale = input.parse().unwrap();
+        let other: Locale = other.parse().unwrap();
+        loc.merge(&other);
+        assert_eq!(loc.to_string(), expected);
+    };
+    assert_merge("und", "en-US", "en-US");
+    assert_merge("sr", "en-Cyrl-RS-macos", "sr-Cyrl-RS-macos");
+    assert_merge("de-AT-nedis", "en-US-macos", "de-AT-nedis");
+    assert_merge(
+        "pl-u-hc-h12",
+        "en-u-ca-buddhist-hc-h23",
+        "pl-u-ca-buddhist-hc-h12",
+    );
+    assert_merge("pl-x-foo", "pl-x-bar-foo", "pl-x-bar-foo");
+    assert_merge("pl", "en-u-hc-h23-x-foo", "pl-u-hc-h23-x-foo");
+}
+
+#[test]
+fn test_display_padding() {
+    let loc: Locale = "en-US-u-hc-h12".parse().unwrap();
+    assert_eq!(format!("{:<16}|", loc), "en-US-u-hc-h12  |");
+    assert_eq!(format!("{:>16}|", loc), "  en-US-u-hc-h12|");
+    assert_eq!(format!("{}|", loc), "en-US-u-hc-h12|");
+}
+
+#[test]
+fn test_display_all_subtags() {
+    let input = "sr-Cyrl-RS-ekavsk-1996-a-bar-t-en-h0-hybrid-u-ca-buddhist-x-testing";
+    let loc: Locale = input.parse().unwrap();
+    assert_eq!(
+        loc.to_string(),
+        "sr-Cyrl-RS-1996-ekavsk-u-ca-buddhist-t-en-h0-hybrid-a-bar-x-testing"
+    );
+    assert_eq!(format!("{:.5}", loc), "sr-Cy");
+}
+
+#[test]
+fn test_transform_fields() {
+    let loc: Locale = "und-t-h0-hybrid-m0-ungegn".parse().unwrap();
+    assert_eq!(loc.to_string(), "und-t-h0-hybrid-m0-ungegn");
+
+    let loc: Locale = "en-t-h0-hybrid-u-hc-h12".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-hc-h12-t-h0-hybrid");
+}
+
+#[test]
+fn test_transform_field_validation() {
+    let loc: Locale = "und-Latn-t-und-cyrl-m0-ungegn-2007".parse().unwrap();
+    assert_eq!(
+        loc.extensions.transform.get_tfield("m0"),
+        Some(vec!["ungegn", "2007"])
+    );
+    assert_eq!(
+        loc.extensions.transform.get_mechanism(),
+        Some(TransformMechanism::Ungegn)
+    );
+
+    assert!("und-t-a0-foo".parse::<Locale>().is_err());
+    assert!("und-t-m0-foobar".parse::<Locale>().is_err());
+    assert!("und-t-h0-foo".parse::<Locale>().is_err());
+    assert!("und-t-m0-h0-hybrid".parse::<Locale>().is_err());
+    assert!("und-t-h0".parse::<Locale>().is_err());
+
+    let mut loc: Locale = "ru-t-en".parse().unwrap();
+    loc.extensions
+        .transform
+        .set_mechanism(TransformMechanism::Bgn)
+        .unwrap();
+    assert_eq!(loc.to_string(), "ru-t-en-m0-bgn");
+
+    assert!(loc
+        .extensions
+        .transform
+        .set_tfield("z0", vec!["foo"])
+        .is_err());
+    assert!(loc.extensions.transform.set_tfield("s0", vec![]).is_err());
+    assert!(loc
+        .extensions
+        .transform
+        .set_tfield("m0", vec!["foo"])
+        .is_err());
+    assert!(Extensions::builder()
+        .transform_field("h0", &["other"])
+        .build()
+        .is_err());
+    assert_eq!(loc.to_string(), "ru-t-en-m0-bgn");
+}
+
+#[test]
+fn test_collation() {
+    let loc: Locale = "zh-u-co-pinyin".parse().unwrap();
+    assert_eq!(loc.collation(), Some(CollationType::Pinyin));
+
+    let loc: Locale = "zh-u-co-foobar".parse().unwrap();
+    assert_eq!(loc.collation(), None);
+
+    let mut loc: Locale = "es-u-hc-h23".parse().unwrap();
+    assert_eq!(loc.collation(), None);
+    loc.set_collation(Some(CollationType::Trad));
+    assert_eq!(loc.to_string(), "es-u-co-trad-hc-h23");
+    loc.set_collation(None);
+    assert_eq!(loc.to_string(), "es-u-hc-h23");
+
+    assert_eq!(
+        "STROKE".parse::<CollationType>().unwrap(),
+        CollationType::Stroke
+    );
+    assert!("phonebook".parse::<CollationType>().is_err());
+}
+
+#[test]
+fn test_effective_region() {
+    let assert_region = |input: &str, region: Option<&str>| {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(
+            loc.effective_region().as_ref().map(|r| r.as_str()),
+            region,
+            "{}",
+            input
+        );
+    };
+    assert_region("en-GB", Some("GB"));
+    assert_region("en-GB-u-rg-uszzzz", Some("US"));
+    assert_region("en-u-rg-USZZZZ", Some("US"));
+    assert_region("en-u-rg-gbsct", Some("GB"));
+    assert_region("en-GB-u-rg-123456", Some("GB"));
+
+    #[cfg(feature = "likelysubtags-add")]
+    {
+        assert_region("en", Some("US"));
+        assert_region("sr-Latn", Some("RS"));
+        assert_region("eo", Some("001"));
+        assert_region("eo-u-rg-plzzzz", Some("PL"));
+    }
+    #[cfg(not(feature = "likelysubtags-add"))]
+    {
+        assert_region("en", None);
+        assert_region("eo", None);
+    }
+}
+
+#[test]
+fn test_subdivision() {
+    let loc: Locale = "en-GB-u-sd-gbsct".parse().unwrap();
+    let sd = loc.subdivision().unwrap();
+    assert_eq!(sd.get_region(), "GB");
+    assert_eq!(sd.get_suffix(), "sct");
+
+    let loc: Locale = "de-u-sd-DEBY".parse().unwrap();
+    assert_eq!(loc.to_string(), "de-u-sd-deby");
+    assert_eq!(loc.subdivision().unwrap().to_string(), "deby");
+
+    for input in &[
+        "en-u-sd-zzzz",
+        "en-u-sd-us",
+        "en-u-sd-uscalif",
+        "en-u-sd-12ca",
+        "en-u-sd",
+        "en-u-sd-usca-usny",
+        "en-US-u-sd-usxx",
+    ] {
+        #[cfg(feature = "subdivision-data")]
+        assert!(input.parse::<Locale>().is_err(), "{}", input);
+        #[cfg(not(feature = "subdivision-data"))]
+        assert!(input.parse::<Locale>().is_ok(), "{}", input);
+    }
+
+    #[cfg(not(feature = "subdivision-data"))]
+    {
+        let loc: Locale = "en-u-sd-zzzz".parse().unwrap();
+        assert_eq!(loc.subdivision(), None);
+        let loc: Locale = "en-US-u-sd-usxx".parse().unwrap();
+        assert_eq!(
+            loc.subdivision().map(|sd| sd.to_string()),
+            Some("usxx".to_string())
+        );
+    }
+
+    let mut loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.subdivision(), None);
+    #[cfg(feature = "subdivision-data")]
+    assert!(loc
+        .extensions
+        .unicode
+        .set_keyword("sd", vec!["xxca"])
+        .is_err());
+    loc.set_subdivision(Some("ustx".parse::<Subdivision>().unwrap()));
+    assert_eq!(loc.to_string(), "en-US-u-sd-ustx");
+    loc.set_subdivision(None);
+    assert_eq!(loc.to_string(), "en-US");
+}
+
+#[test]
+fn test_icu_keywords() {
+    let loc: Locale = "en_US@calendar=buddhist;collation=phonebook"
+        .parse()
+        .unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-ca-buddhist-co-phonebk");
+
+    let loc: Locale = "de@collation=traditional;colStrength=primary;hc=h23"
+        .parse()
+        .unwrap();
+    assert_eq!(loc.to_string(), "de-u-co-trad-hc-h23-ks-level1");
+
+    let loc: Locale = "ar_SA@calendar=islamic-umalqura;numbers=latn"
+        .parse()
+        .unwrap();
+    assert_eq!(loc.to_string(), "ar-SA-u-ca-islamic-umalqura-nu-latn");
+
+    let loc: Locale = "en-u-ca-buddhist@calendar=gregorian".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-ca-gregory");
+
+    assert!("en@".parse::<Locale>().is_err());
+    assert!("en@calendar".parse::<Locale>().is_err());
+    assert!("en@calendar=buddhist;".parse::<Locale>().is_err());
+    assert!("en@foobar=baz".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_to_icu_string() {
+    let loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.to_icu_string(), "en_US");
+
+    let loc: Locale = "zh-Hant-TW-u-co-stroke-nu-traditio".parse().unwrap();
+    assert_eq!(
+        loc.to_icu_string(),
+        "zh_Hant_TW@collation=stroke;numbers=traditional"
+    );
+
+    let loc: Locale = "de-1901-u-kn-ks-level1".parse().unwrap();
+    assert_eq!(
+        loc.to_icu_string(),
+        "de__1901@colnumeric=yes;colstrength=primary"
+    );
+
+    let loc: Locale = "und-Latn-u-attr-fw-mon-t-und-cyrl-m0-bgn-x-foo"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        loc.to_icu_string(),
+        "_Latn@attribute=attr;fw=mon;t=und-cyrl-m0-bgn;x=foo"
+    );
+
+    let input = "en_US_POSIX@calendar=gregorian;collation=phonebook";
+    let loc: Locale = input.parse().unwrap();
+    assert_eq!(loc.to_icu_string(), input);
+}
+
+#[test]
+fn test_is_pseudo_locale() {
+    let loc: Locale = "en-XA".parse().unwrap();
+    assert!(loc.is_pseudo_locale());
+
+    let loc: Locale = "en-US-x-pseudo".parse().unwrap();
+    assert!(loc.is_pseudo_locale());
+
+    let loc: Locale = "en-US-x-foo".parse().unwrap();
+    assert!(!loc.is_pseudo_locale());
+}
+
+#[test]
+fn test_extension_only() {
+    let loc: Locale = "-u-nu-thai".parse().unwrap();
+    assert_eq!(loc.langid, LanguageIdentifier::default());
+    assert_eq!(loc.to_string(), "und-u-nu-thai");
+
+    let loc: Locale = "_x_testing".parse().unwrap();
+    assert_eq!(loc.to_string(), "und-x-testing");
+
+    let loc: Locale = "und-u-ca-buddhist".parse().unwrap();
+    assert_eq!(loc.to_string(), "und-u-ca-buddhist");
+
+    assert!("-en-US".parse::<Locale>().is_err());
+    assert!("-".parse::<Locale>().is_err());
+    assert!("u-ca-buddhist".parse::<Locale>().is_err());
+    assert!("e-US".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_apply_extensions() {
+    let mut loc: Locale = "en-US-u-hc-h12".parse().unwrap();
+    loc.apply_extensions("-u-nu-thai").unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12-nu-thai");
+
+    loc.apply_extensions("und-u-hc-h23-x-foo").unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-hc-h23-nu-thai-x-foo");
+
+    assert!(loc.apply_extensions("de-u-hc-h12").is_err());
+    assert_eq!(loc.to_string(), "en-US-u-hc-h23-nu-thai-x-foo");
+}
+
+#[test]
+fn test_extensions_builder() {
+    let extensions = Extensions::builder()
+        .unicode_keyword("hc", &["h12"])
+        .unicode_attribute("foo")
+        .transform_lang("es-AR".parse().unwrap())
+        .transform_field("h0", &["hybrid"])
+        .other('a', &["bar", "baz"])
+        .private_tag("testing")
+        .build()
+        .unwrap();
+    assert_eq!(
+        extensions.to_string(),
+        "-u-hc-h12-foo-t-es-AR-h0-hybrid-a-bar-baz-x-testing"
+    );
+    assert!(!extensions.is_empty());
+
+    let result = Extensions::builder()
+        .unicode_keyword("hc", &["h12"])
+        .other('x', &["foo"])
+        .private_tag("testing")
+        .build();
+    assert!(result.is_err());
+
+    assert!(Extensions::builder().build().unwrap().is_empty());
+}
+
+#[test]
+fn test_other_extensions() {
+    let loc: Locale = "en-US-a-Foo-bar-u-hc-h12".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12-a-foo-bar");
+    assert_eq!(loc.extensions.other.get(&'a').map(|v| v.len()), Some(2));
+
+    let mut extensions = Extensions::default();
+    extensions.set_other('b', &["baz"]).unwrap();
+    assert!(!extensions.is_empty());
+    assert_eq!(extensions.to_string(), "-b-baz");
+
+    assert!(extensions.set_other('u', &["baz"]).is_err());
+    assert!(extensions.set_other('c', &[]).is_err());
+    assert!(extensions.set_other('c', &["a"]).is_err());
+
+    assert!("en-a".parse::<Locale>().is_err());
+    assert!("en-a-b-foo".parse::<Locale>().is_err());
+    assert!("en-a-$$".parse::<Locale>().is_err());
+}
+
+#[test]
+fn test_from_parts_with_extensions() {
+    let extensions = Extensions::builder()
+        .unicode_keyword("ca", &["buddhist"])
+        .private_tag("foo")
+        .build()
+        .unwrap();
+    let loc = Locale::from_parts(Some("th"), None, Some("TH"), &[], extensions).unwrap();
+    assert_eq!(loc.to_string(), "th-TH-u-ca-buddhist-x-foo");
+    assert_eq!(loc, "th-TH-u-ca-buddhist-x-foo".parse::<Locale>().unwrap());
+}
+
+#[test]
+fn test_extensions_iter() {
+    let loc: Locale = "en-US-u-attr-ca-buddhist-hc-h12-b-other-t-es-AR-a-first-x-foo-bar"
+        .parse()
+        .unwrap();
+    let items: Vec<_> = loc.extensions().collect();
+    assert_eq!(
+        items,
+        vec![
+            (ExtensionType::Other('a'), "", vec!["first"]),
+            (ExtensionType::Other('b'), "", vec!["other"]),
+            (ExtensionType::Transform, "", vec!["es", "AR"]),
+            (ExtensionType::Unicode, "", vec!["attr"]),
+            (ExtensionType::Unicode, "ca", vec!["buddhist"]),
+            (ExtensionType::Unicode, "hc", vec!["h12"]),
+            (ExtensionType::Private, "", vec!["bar", "foo"]),
+        ]
+    );
+
+    let loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.extensions().count(), 0);
+}
+
+#[test]
+fn test_remove_extensions() {
+    let mut loc: Locale = "en-US-u-ca-buddhist-hc-h12-a-foo-x-user-12345"
+        .parse()
+        .unwrap();
+
+    assert!(loc.remove_unicode_ext_keyword("ca"));
+    assert!(!loc.remove_unicode_ext_keyword("ca"));
+    assert!(!loc.remove_unicode_ext_keyword("invalid"));
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12-a-foo-x-12345-user");
+
+    assert!(loc.remove_extension(ExtensionType::Private));
+    assert!(!loc.remove_extension(ExtensionType::Private));
+    assert!(!loc.remove_extension(ExtensionType::Transform));
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12-a-foo");
+
+    assert!(loc.remove_extension(ExtensionType::Other('A')));
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+
+    loc.clear_extensions();
+    assert_eq!(loc.to_string(), "en-US");
+    assert!(loc.extensions.is_empty());
+}
+
+#[test]
+fn test_has_extensions() {
+    let loc: Locale = "en-US".parse().unwrap();
+    assert!(!loc.has_extensions());
+    assert!(!loc.has_unicode_extension("ca"));
+
+    let loc: Locale = "en-US-u-ca-buddhist-t-es".parse().unwrap();
+    assert!(loc.has_extensions());
+    assert!(loc.has_unicode_extension("ca"));
+    assert!(loc.has_unicode_extension("CA"));
+    assert!(!loc.has_unicode_extension("hc"));
+    assert!(!loc.has_unicode_extension("invalid"));
+
+    let loc: Locale = "en-US-a-foo".parse().unwrap();
+    assert!(loc.has_extensions());
+}
+
+#[test]
+fn test_extension_type() {
+    assert_eq!(
+        "u".parse::<ExtensionType>().unwrap(),
+        ExtensionType::Unicode
+    );
+    assert_eq!(
+        "T".parse::<ExtensionType>().unwrap(),
+        ExtensionType::Transform
+    );
+    assert_eq!(
+        "x".parse::<ExtensionType>().unwrap(),
+        ExtensionType::Private
+    );
+    assert_eq!(
+        "a".parse::<ExtensionType>().unwrap(),
+        ExtensionType::Other('a')
+    );
+    assert_eq!(
+        "5".parse::<ExtensionType>().unwrap(),
+        ExtensionType::Other('5')
+    );
+    assert!("".parse::<ExtensionType>().is_err());
+    assert!("ab".parse::<ExtensionType>().is_err());
+    assert!("-".parse::<ExtensionType>().is_err());
+
+    for s in &["u", "t", "x", "a", "5"] {
+        let ext_type: ExtensionType = s.parse().unwrap();
+        assert_eq!(&ext_type.to_string(), s);
+    }
+}
+
+#[test]
+fn test_langid_delegation() {
+    let mut loc: Locale = "en-GB-u-hc-h12".parse().unwrap();
+    let range: LanguageIdentifier = "en".parse().unwrap();
+
+    assert!(loc.subsumes(&"en-GB".parse::<LanguageIdentifier>().unwrap()));
+    assert!(range.matches(&loc, true, false));
+    assert_eq!(loc.matches_str("en-*", &Default::default()), Ok(true));
+    assert_eq!(
+        loc.match_quality(&"en-US".parse::<LanguageIdentifier>().unwrap()),
+        MatchQuality::RegionDiffers
+    );
+
+    loc.fill_missing_from(&"und-Latn".parse::<LanguageIdentifier>().unwrap());
+    assert_eq!(loc.to_string(), "en-Latn-GB-u-hc-h12");
+}
+
+#[test]
+fn test_as_ref_langid() {
+    let loc: Locale = "sr-Latn-RS-u-ca-gregory".parse().unwrap();
+    let langid: LanguageIdentifier = "sr-Latn-RS".parse().unwrap();
+    let range: LanguageIdentifier = "sr".parse().unwrap();
+
+    let as_ref: &LanguageIdentifier = loc.as_ref();
+    assert_eq!(as_ref, &langid);
+    assert!(langid.matches(&loc, false, false));
+    assert!(range.matches(&loc, true, false));
+    assert!(range.subsumes(&loc));
+    assert!(langid.eq_canonical(&loc));
+}
+
+#[test]
+fn test_langid_accessors() {
+    let mut loc: Locale = "de-CH-1996-u-co-phonebk-x-foo".parse().unwrap();
+    assert_eq!(loc.langid().to_string(), "de-CH-1996");
+
+    loc.set_langid("de-AT".parse().unwrap());
+    assert_eq!(loc.to_string(), "de-AT-u-co-phonebk-x-foo");
+
+    loc.set_langid(LanguageIdentifier::default());
+    assert_eq!(loc.to_string(), "und-u-co-phonebk-x-foo");
+}
+
+#[test]
+fn test_eq_langid() {
+    let langid: LanguageIdentifier = "en-US".parse().unwrap();
+    let loc: Locale = "en-US".parse().unwrap();
+    let loc_ext: Locale = "en-US-u-hc-h12".parse().unwrap();
+    let other: Locale = "en-GB".parse().unwrap();
+
+    assert_eq!(loc, langid);
+    assert_eq!(langid, loc);
+    assert_ne!(loc_ext, langid);
+    assert_ne!(langid, loc_ext);
+    assert_ne!(other, langid);
+    assert!([other, loc].iter().any(|l| *l == langid));
+}
+
+#[test]
+fn test_unicode_keyword_flags() {
+    for (input, output) in &[
+        ("en-u-kn", "en-u-kn"),
+        ("en-u-kn-true", "en-u-kn"),
+        ("en-u-kn-ca-buddhist", "en-u-ca-buddhist-kn"),
+        ("en-u-kb-true-kn-false", "en-u-kb-kn-false"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_string(), output);
+        let reparsed: Locale = output.parse().unwrap();
+        assert_eq!(reparsed, loc);
+    }
+
+    let mut loc: Locale = "en-u-kb-true-kn-false".parse().unwrap();
+    assert!(loc.get_unicode_keyword_flag("kb"));
+    assert!(!loc.get_unicode_keyword_flag("kn"));
+    assert!(!loc.get_unicode_keyword_flag("kc"));
+    assert!(!loc.get_unicode_keyword_flag("$$"));
+    assert_eq!(loc.extensions.unicode.get_keyword("kb"), Some(vec![]));
+
+    loc.extensions
+        .unicode
+        .set_keyword("kn", vec!["true"])
+        .unwrap();
+    loc.extensions.unicode.set_keyword("kc", vec![]).unwrap();
+    assert!(loc.get_unicode_keyword_flag("kn"));
+    assert!(loc.get_unicode_keyword_flag("kc"));
+    assert_eq!(loc.to_string(), "en-u-kb-kc-kn");
+}
+
+#[test]
+fn test_unicode_keywords_sorted() {
+    let loc1: Locale = "en-u-nu-thai-hc-h12-ca-buddhist".parse().unwrap();
+    let loc2: Locale = "en-u-ca-buddhist-nu-thai-hc-h12".parse().unwrap();
+    let mut loc3: Locale = "en".parse().unwrap();
+    for (key, value) in &[("hc", "h12"), ("nu", "thai"), ("ca", "buddhist")] {
+        loc3.extensions
+            .unicode
+            .set_keyword(key, vec![value])
+            .unwrap();
+    }
+
+    let expected = vec![
+        ("ca", vec!["buddhist"]),
+        ("hc", vec!["h12"]),
+        ("nu", vec!["thai"]),
+    ];
+    for loc in &[&loc1, &loc2, &loc3] {
+        assert_eq!(loc.unicode_keywords().collect::<Vec<_>>(), expected);
+        assert_eq!(loc.to_string(), "en-u-ca-buddhist-hc-h12-nu-thai");
+    }
+    assert_eq!(loc1, loc2);
+    assert_eq!(loc1, loc3);
+}
+
+#[test]
+fn test_byte_len() {
+    for input in &[
+        "und",
+        "en-US",
+        "sr-Cyrl-RS-1996-ekavsk",
+        "en-US-u-hc-h12-x-testing",
+        "de-u-attr-co-phonebk-ka",
+        "ja-Kana-t-it-h0-hybrid",
+        "und-t-en-a-bar-b-foo-baz-x-a-b",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.byte_len(), loc.to_string().len(), "{}", input);
+        assert_eq!(loc.langid().byte_len(), loc.langid().to_string().len());
+    }
+}
+
+#[test]
+fn test_duplicate_singleton() {
+    use unic_locale_impl::parser::ParserError;
+
+    for (input, singleton) in &[
+        ("en-u-ca-buddhist-u-nu-thai", 'u'),
+        ("en-t-es-t-m0-ungegn", 't'),
+        ("en-a-foo-b-bar-A-baz", 'a'),
+        ("-u-ca-buddhist-u-nu-thai", 'u'),
+    ] {
+        match parse_locale(input) {
+            Err(ParserError::DuplicateSingleton(s)) => assert_eq!(s, *singleton),
+            result => panic!("Unexpected result for {}: {:?}", input, result),
+        }
+    }
+    assert_eq!(
+        parse_locale("en-u-ca-buddhist-u-nu-thai")
+            .unwrap_err()
+            .to_string(),
+        "Duplicate extension singleton: u"
+    );
+
+    // Singletons within the private use extension are subtags.
+    let loc: Locale = "en-u-ca-buddhist-x-foo-u".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-ca-buddhist-x-foo-u");
+}
+
+#[test]
+fn test_invalid_extension_subtags() {
+    use unic_locale_impl::parser::ParserError;
+
+    for input in &[
+        "en-u-ca-gregorianxx",
+        "en-u-ca-gré",
+        "en-u-abcdefghij",
+        "en-u-ca-buddhist-ab-c$",
+        "en-t-419",
+        "en-t-é",
+        "en-t-m0-abcdefghij",
+        "en-a-abcdefghij",
+        "en-a-é1",
+        "en-x-abcdefghij",
+    ] {
+        match parse_locale(input) {
+            Err(ParserError::InvalidSubtag) => {}
+            result => panic!("Unexpected result for {}: {:?}", input, result),
+        }
+    }
+
+    // A singleton still ends the extension.
+    let loc: Locale = "en-u-ca-buddhist-a-foo".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-u-ca-buddhist-a-foo");
+}
+
+#[test]
+fn test_empty_subtags() {
+    use unic_locale_impl::parser::ParserError;
+
+    for input in &[
+        "-en-US",
+        "-",
+        "en--US",
+        "en-u-ca-buddhist--nu-thai",
+        "-u--nu-thai",
+    ] {
+        match parse_locale(input) {
+            Err(ParserError::EmptySubtag) => {}
+            result => panic!("Unexpected result for {}: {:?}", input, result),
+        }
+    }
+    for input in &["en-US-", "en-u-ca-buddhist-", "-u-nu-thai_"] {
+        match parse_locale(input) {
+            Err(ParserError::TrailingSeparator) => {}
+            result => panic!("Unexpected result for {}: {:?}", input, result),
+        }
+    }
+    assert_eq!(
+        parse_locale("en-US-").unwrap_err().to_string(),
+        "The input ends with a separator"
+    );
+}
+
+#[cfg(feature = "keyword-data")]
+#[test]
+fn test_validate_keywords() {
+    use unic_locale_impl::parser::ParserError;
+
+    for input in &[
+        "en",
+        "en-u-ca-gregory-co-phonebk-hc-h23",
+        "th-u-nu-thai-ca-buddhist",
+        "en-u-kn-kb-false-ka-shifted",
+        "ar-u-ca-islamic-umalqura",
+        "en-u-cu-usd-rg-gbzzzz-sd-gbsct",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert!(loc.validate_keywords().is_ok(), "{}", input);
+    }
+
+    for (input, keyword) in &[
+        ("en-US-u-ca-gregorin", "ca-gregorin"),
+        ("en-u-hc-h13", "hc-h13"),
+        ("en-u-ca", "ca"),
+        ("en-u-ca-islamic-civil-extra", "ca-islamic-civil-extra"),
+        ("en-u-zz-foo", "zz-foo"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        match loc.validate_keywords() {
+            Err(ParserError::UnknownKeyword(k)) => assert_eq!(&k, keyword),
+            result => panic!("Unexpected result for {}: {:?}", input, result),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(all(unix, not(target_os = "macos")))]
+#[test]
+fn test_requested_locales_from_env() {
+    use std::env;
+    use unic_locale_impl::os::requested_locales;
+    use unic_locale_impl::Locale;
+
+    env::set_var("LANGUAGE", "sr_RS@latin:de::en_US:C");
+    env::set_var("LC_ALL", "");
+    env::set_var("LC_MESSAGES", "pl_PL.UTF-8");
+    env::set_var("LANG", "fr_FR.UTF-8");
+
+    let locales: Vec<String> = requested_locales().iter().map(Locale::to_string).collect();
+    assert_eq!(locales, vec!["sr-Latn-RS", "de", "en-US", "pl-PL"]);
+
+    env::remove_var("LANGUAGE");
+    env::remove_var("LC_MESSAGES");
+    env::set_var("LANG", "en_US.UTF-8");
+
+    let locales: Vec<String> = requested_locales().iter().map(Locale::to_string).collect();
+    assert_eq!(locales, vec!["en-US"]);
+
+    env::set_var("LANGUAGE", "uz_UZ.UTF-8@cyrillic:de_DE@euro");
+    env::set_var("LANG", "POSIX");
+
+    let locales: Vec<String> = requested_locales().iter().map(Locale::to_string).collect();
+    assert_eq!(locales, vec!["uz-Cyrl-UZ", "de-DE"]);
+
+    env::set_var("LANGUAGE", "en_US");
+
+    let locales: Vec<String> = requested_locales().iter().map(Locale::to_string).collect();
+    assert_eq!(locales, vec!["en-US"]);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_serialize() {
+    let loc: Locale = "en-US-u-ca-buddhist".parse().unwrap();
+    assert_eq!(
+        serde_json::to_string(&loc).unwrap(),
+        "\"en-US-u-ca-buddhist\""
+    );
+}
+
+#[test]
+fn test_deserialize_string() {
+    let loc: Locale = serde_json::from_str("\"en_US-u-ca-buddhist\"").unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-ca-buddhist");
+
+    assert!(serde_json::from_str::<Locale>("\"e-US\"").is_err());
+}
+
+#[test]
+fn test_deserialize_map() {
+    let loc: Locale = serde_json::from_str(
+        r#"{ "language": "en", "region": "US", "extensions": "u-ca-buddhist" }"#,
+    )
+    .unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-ca-buddhist");
+
+    let loc: Locale = serde_json::from_str(
+        r#"{ "language": "sr", "script": "Cyrl", "variants": ["ekavsk"], "region": null }"#,
+    )
+    .unwrap();
+    assert_eq!(loc.to_string(), "sr-Cyrl-ekavsk");
+
+    let loc: Locale = serde_json::from_str("{}").unwrap();
+    assert_eq!(loc, Locale::default());
+
+    assert!(serde_json::from_str::<Locale>(r#"{ "lang": "en" }"#).is_err());
+    assert!(serde_json::from_str::<Locale>(r#"{ "region": "USA1" }"#).is_err());
+}
+
+#[test]
+fn test_roundtrip() {
+    let locales: Vec<Locale> = vec!["en-US".parse().unwrap(), "pl-u-hc-h12".parse().unwrap()];
+    let json = serde_json::to_string(&locales).unwrap();
+    assert_eq!(json, r#"["en-US","pl-u-hc-h12"]"#);
+    let result: Vec<Locale> = serde_json::from_str(&json).unwrap();
+    assert_eq!(result, locales);
+}
+
+#[test]
+fn test_bincode_roundtrip() {
+    let loc: Locale = "sr-Cyrl-RS-u-ca-buddhist-x-private".parse().unwrap();
+    let bytes = bincode::serialize(&loc).unwrap();
+    let loc2: Locale = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(loc, loc2);
+
+    let bytes = bincode::serialize("e-US").unwrap();
+    assert!(bincode::deserialize::<Locale>(&bytes).is_err());
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "web")]
+
+use unic_locale_impl::web::{parse_accept_language, resolve_locale, NegotiationCache};
+use unic_locale_impl::Locale;
+
+fn locales(input: &[&str]) -> Vec<Locale> {
+    input.iter().map(|s| s.parse().unwrap()).collect()
+}
+
+fn assert_resolve(header: &str, available: &[&str], default: &str, expected: &str) {
+    let available = locales(available);
+    let default: Locale = default.parse().unwrap();
+    let result = resolve_locale(header, &available, &default);
+    assert_eq!(result.to_string(), expected, "{}", header);
+}
+
+#[test]
+fn test_parse_accept_language() {
+    let assert_parse = |header: &str, expected: &[&str]| {
+        let result: Vec<String> = parse_accept_language(header)
+            .iter()
+            .map(Locale::to_string)
+            .collect();
+        assert_eq!(result, expected, "{}", header);
+    };
+    assert_parse("", &[]);
+    assert_parse("en-US", &["en-US"]);
+    assert_parse("de;q=0.5, fr, en;q=0.7", &["fr", "en", "de"]);
+    assert_parse("da, en-gb;q=0.8, en;q=0.7", &["da", "en-GB", "en"]);
+    assert_parse("en;q=0.5, de;q=0.5", &["en", "de"]);
+    assert_parse("pl, en;q=0", &["pl"]);
+    assert_parse("pl, *;q=0.1", &["pl"]);
+    assert_parse("pl;level=1;q=0.3, de", &["de", "pl"]);
+    assert_parse("pl;q=abc, de;q=2, $$$, fr", &["fr"]);
+}
+
+#[test]
+fn test_resolve_locale() {
+    let available = &["en-US", "en-GB", "de", "fr-CA"];
+
+    assert_resolve("de-DE", available, "en-US", "de");
+    assert_resolve("en-gb", available, "en-US", "en-GB");
+    assert_resolve("en", available, "de", "en-US");
+    assert_resolve("en-AU", available, "de", "en-US");
+    assert_resolve("fr", available, "en-US", "fr-CA");
+    assert_resolve("pl, fr-FR;q=0.5", available, "en-US", "fr-CA");
+    assert_resolve("pl", available, "en-US", "en-US");
+    assert_resolve("", available, "en-US", "en-US");
+    assert_resolve("pl, de;q=0.1, en-GB;q=0.9", available, "en-US", "en-GB");
+}
+
+#[test]
+fn test_resolve_locale_wildcard() {
+    let available = &["en-US", "de"];
+
+    assert_resolve("pl, *;q=0.5", available, "fr", "en-US");
+    assert_resolve("pl, en;q=0, *;q=0.5", available, "fr", "de");
+    assert_resolve("pl, *;q=0", available, "fr", "fr");
+}
+
+#[test]
+fn test_resolve_locale_excluded() {
+    assert_resolve("en-US, en-GB;q=0", &["en-GB"], "de", "de");
+    assert_resolve("de, en", &[], "fr", "fr");
+}
+
+#[test]
+fn test_supported_locales() {
+    use unic_locale_impl::web::SupportedLocales;
+
+    let supported = SupportedLocales::new(locales(&["en-US", "de"]), "en-US".parse().unwrap());
+    assert_eq!(supported.resolve("de-CH, en;q=0.5").to_string(), "de");
+    assert_eq!(supported.resolve("pl").to_string(), "en-US");
+}
+
+#[test]
+fn test_negotiation_cache() {
+    let cache = NegotiationCache::new(2);
+    let available = locales(&["en-US", "de"]);
+    let other = locales(&["fr", "de-AT"]);
+    let default: Locale = "en-US".parse().unwrap();
+    let fallback: Locale = "pl".parse().unwrap();
+
+    let resolve = |cache: &NegotiationCache, header, available: &[Locale], default| {
+        cache.resolve(header, available, default).to_string()
+    };
+
+    assert!(cache.is_empty());
+    assert_eq!(resolve(&cache, "de-CH", &available, &default), "de");
+    assert_eq!(resolve(&cache, "de-CH", &available, &default), "de");
+    assert_eq!(cache.len(), 1);
+
+    assert_eq!(resolve(&cache, "de-CH", &other, &default), "de-AT");
+    assert_eq!(cache.len(), 2);
+
+    assert_eq!(resolve(&cache, "it", &available, &fallback), "pl");
+    assert_eq!(resolve(&cache, "it", &available, &default), "en-US");
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert!(cache.is_empty());
+
+    let cache = NegotiationCache::new(0);
+    assert_eq!(resolve(&cache, "de-CH", &available, &default), "de");
+    assert!(cache.is_empty());
+}
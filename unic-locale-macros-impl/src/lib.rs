@@ -3,49 +3,75 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 
 use proc_macro_hack::proc_macro_hack;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{parse_macro_input, LitStr};
 
 use unic_locale_impl::Locale;
 
+fn quote_option<T: ToTokens>(value: Option<T>) -> impl ToTokens {
+    if let Some(value) = value {
+        quote!(Some(#value))
+    } else {
+        quote!(None)
+    }
+}
+
+fn quote_keywords(keywords: &[(u32, &[u64])]) -> impl ToTokens {
+    let keywords = keywords
+        .iter()
+        .map(|(key, values)| quote!((#key, &[#(#values,)*])));
+    quote!(&[#(#keywords,)*])
+}
+
 #[proc_macro_hack]
 pub fn locale(input: TokenStream) -> TokenStream {
     let id = parse_macro_input!(input as LitStr);
     let parsed: Locale = id.value().parse().expect("Malformed Locale Identifier");
+    #[cfg(feature = "strict")]
+    parsed
+        .validate_keywords()
+        .expect("Unknown Unicode Extension Keyword");
 
-    let (lang, script, region, variants, extensions) = parsed.into_raw_parts();
-    let lang = if let Some(lang) = lang {
-        quote!(Some($crate::TinyStr8::new_unchecked(#lang)))
-    } else {
-        quote!(None)
-    };
-    let script = if let Some(script) = script {
-        quote!(Some($crate::TinyStr4::new_unchecked(#script)))
-    } else {
-        quote!(None)
-    };
-    let region = if let Some(region) = region {
-        quote!(Some($crate::TinyStr4::new_unchecked(#region)))
-    } else {
-        quote!(None)
-    };
-    let variants = if let Some(variants) = variants {
-        let v: Vec<_> = variants
+    let raw = parsed.with_raw_parts_v2(|raw| {
+        let (lang, script, region, variants) = raw.langid;
+        let lang = quote_option(lang);
+        let script = quote_option(script);
+        let region = quote_option(region);
+
+        let ext = raw.extensions;
+        let unicode_attributes = ext.unicode_attributes;
+        let unicode_keywords = quote_keywords(ext.unicode_keywords);
+        let transform_language = quote_option(ext.transform_language.map(
+            |(lang, script, region, variants)| {
+                let lang = quote_option(lang);
+                let script = quote_option(script);
+                let region = quote_option(region);
+                quote!((#lang, #script, #region, &[#(#variants,)*]))
+            },
+        ));
+        let transform_fields = quote_keywords(ext.transform_fields);
+        let other = ext
+            .other
             .iter()
-            .map(|v| quote!($crate::TinyStr8::new_unchecked(#v)))
-            .collect();
-        quote!(Some(Box::new([#(#v,)*])))
-    } else {
-        quote!(None)
-    };
+            .map(|(singleton, subtags)| quote!((#singleton, &[#(#subtags,)*])));
+        let private = ext.private;
+
+        quote! {
+            $crate::RawLocale {
+                langid: (#lang, #script, #region, &[#(#variants,)*]),
+                extensions: $crate::RawExtensions {
+                    unicode_attributes: &[#(#unicode_attributes,)*],
+                    unicode_keywords: #unicode_keywords,
+                    transform_language: #transform_language,
+                    transform_fields: #transform_fields,
+                    other: &[#(#other,)*],
+                    private: &[#(#private,)*],
+                },
+            }
+        }
+    });
 
     TokenStream::from(quote! {
-        unsafe { $crate::Locale::from_raw_parts_unchecked(
-            #lang,
-            #script,
-            #region,
-            #variants,
-            #extensions.parse().expect("must parse")
-        ) }
+        unsafe { $crate::Locale::from_raw_parts_v2_unchecked(&#raw) }
     })
 }
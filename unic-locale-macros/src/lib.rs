@@ -1,6 +1,6 @@
 use proc_macro_hack::proc_macro_hack;
 pub use tinystr::{TinyStr4, TinyStr8};
-pub use unic_locale_impl::Locale;
+pub use unic_locale_impl::{Locale, RawExtensions, RawLocale};
 
 /// Add one to an expression.
 ///
@@ -0,0 +1,6 @@
+#[test]
+#[cfg(feature = "macros-strict")]
+fn locale_macro_unknown_keyword() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/unknown_keyword.rs");
+}
@@ -22,3 +22,13 @@ fn locales_macro_test() {
     assert_eq!(locales.len(), 4);
     assert_eq!(locales.get(3).unwrap().get_language(), "pl");
 }
+
+#[test]
+#[cfg(feature = "unic-locale-macros")]
+fn locale_macro_extensions_test() {
+    let loc = locale!("sr-Cyrl-RS-1996-ekavsk-u-ca-buddhist-t-en-h0-hybrid-a-bar-x-testing");
+    assert_eq!(
+        &loc.to_string(),
+        "sr-Cyrl-RS-1996-ekavsk-u-ca-buddhist-t-en-h0-hybrid-a-bar-x-testing"
+    );
+}
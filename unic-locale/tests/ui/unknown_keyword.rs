@@ -0,0 +1,5 @@
+use unic_locale::locale;
+
+fn main() {
+    let _ = locale!("en-u-ca-gregorin");
+}